@@ -0,0 +1,139 @@
+//! Decodes every FIT file in the tests directory several times over and reports how long it took.
+//! Run with `cargo bench`. Timings vary from run to run, so each decoder is timed over several samples and the median
+//! and range are reported. To compare two versions of the code, run `cargo bench --bench decode -- --save-baseline <name>`
+//! on the first and `cargo bench --bench decode -- --baseline <name>` on the second.
+
+extern crate fit_file;
+
+use fit_file::fit_file::{Fit, FitDataMessage, FitDefinitionMessage, FitMessage, FitMessageRefHandler};
+use std::path::PathBuf;
+use std::time::Instant;
+
+const NUM_SAMPLES: usize = 15;
+const PASSES_PER_SAMPLE: u32 = 5;
+
+/// Decodes a file, returning the number of messages.
+type DecodeFn = dyn Fn(&[u8]) -> usize;
+
+/// Counts the messages passed to it by Fit::read_slice_ref().
+struct MessageCounter(usize);
+
+impl FitMessageRefHandler<'_> for MessageCounter {
+    fn on_definition(&mut self, _msg: &FitDefinitionMessage) {
+        self.0 += 1;
+    }
+
+    fn on_data(&mut self, _msg: &FitDataMessage) {
+        self.0 += 1;
+    }
+}
+
+/// Milliseconds per pass over the files: the median, fastest and slowest of the samples.
+#[derive(Clone, Copy, Debug)]
+struct Timing {
+    median: f64,
+    min: f64,
+    max: f64
+}
+
+/// Decodes the files PASSES_PER_SAMPLE times for each sample, returning the number of messages in a pass and the timing.
+fn time_decode(files: &[Vec<u8>], decode: &DecodeFn) -> (usize, Timing) {
+    let mut num_messages = 0;
+    let mut samples = Vec::with_capacity(NUM_SAMPLES);
+    for _ in 0..NUM_SAMPLES {
+        num_messages = 0;
+        let start = Instant::now();
+        for _ in 0..PASSES_PER_SAMPLE {
+            for bytes in files {
+                num_messages += decode(bytes);
+            }
+        }
+        samples.push(start.elapsed().as_secs_f64() * 1000.0 / PASSES_PER_SAMPLE as f64);
+    }
+    samples.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    (num_messages / PASSES_PER_SAMPLE as usize, Timing { median: samples[NUM_SAMPLES / 2], min: samples[0], max: samples[NUM_SAMPLES - 1] })
+}
+
+/// Where the timings saved with --save-baseline are kept.
+fn baseline_path(name: &str) -> PathBuf {
+    let target_dir = std::env::var_os("CARGO_TARGET_DIR").map_or_else(|| PathBuf::from("target"), PathBuf::from);
+    target_dir.join("decode-bench").join(format!("{}.txt", name))
+}
+
+/// Reads the timings saved with --save-baseline, one "name median min max" line per decoder.
+fn load_baseline(name: &str) -> Vec<(String, Timing)> {
+    let path = baseline_path(name);
+    let text = std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("Failed to read the baseline {:?}: {}", path, e));
+    text.lines().filter_map(|line| {
+        let mut parts = line.split_whitespace();
+        let name = parts.next()?.to_string();
+        let mut values = parts.map(|value| value.parse::<f64>().ok());
+        Some((name, Timing { median: values.next()??, min: values.next()??, max: values.next()?? }))
+    }).collect()
+}
+
+/// Describes the change from the baseline. Changes where the ranges of the samples overlap are within the noise.
+fn compare(timing: &Timing, baseline: &Timing) -> String {
+    let change = (timing.median / baseline.median - 1.0) * 100.0;
+    if timing.max < baseline.min || timing.min > baseline.max {
+        format!("{:+.1}% ({:.2}x)", change, baseline.median / timing.median)
+    }
+    else {
+        format!("{:+.1}%, within noise", change)
+    }
+}
+
+fn main() {
+    // cargo bench passes --bench, anything else is ours.
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let option = |name: &str| args.iter().position(|arg| arg == name).and_then(|index| args.get(index + 1)).cloned();
+    let save_baseline = option("--save-baseline");
+    let baseline = option("--baseline").map(|name| load_baseline(&name));
+
+    let mut paths: Vec<_> = std::fs::read_dir("tests").unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "fit"))
+        .collect();
+    paths.sort();
+    let files: Vec<Vec<u8>> = paths.iter().map(|path| std::fs::read(path).unwrap()).collect();
+    let num_bytes: usize = files.iter().map(|bytes| bytes.len()).sum();
+
+    let read = |bytes: &[u8]| {
+        let mut num_messages: usize = 0;
+        let mut reader = std::io::BufReader::new(bytes);
+        Fit::new().read(&mut reader, &mut |_msg: &FitMessage| num_messages += 1).unwrap();
+        num_messages
+    };
+    let read_slice = |bytes: &[u8]| {
+        let mut num_messages: usize = 0;
+        Fit::new().read_slice(bytes, &mut |_msg: &FitMessage| num_messages += 1).unwrap();
+        num_messages
+    };
+    let read_slice_ref = |bytes: &[u8]| {
+        let mut counter = MessageCounter(0);
+        Fit::new().read_slice_ref(bytes, &mut counter).unwrap();
+        counter.0
+    };
+
+    println!("{} files, {} bytes, {} samples of {} passes", files.len(), num_bytes, NUM_SAMPLES, PASSES_PER_SAMPLE);
+    let mut saved = String::new();
+    for (name, decode) in [("read", &read as &DecodeFn), ("read_slice", &read_slice), ("read_slice_ref", &read_slice_ref)] {
+        let (num_messages, timing) = time_decode(&files, decode);
+        let mut line = format!("{:<14} {:>8} messages {:>8.3} ms per pass [{:.3} {:.3}] {:>8.2} MB/s", name, num_messages, timing.median, timing.min, timing.max, num_bytes as f64 / timing.median / 1.0e3);
+        if let Some(baseline) = &baseline {
+            match baseline.iter().find(|(baseline_name, _)| baseline_name == name) {
+                Some((_, baseline_timing)) => line += &format!("  {:.3} ms in the baseline, {}", baseline_timing.median, compare(&timing, baseline_timing)),
+                None => line += "  not in the baseline"
+            }
+        }
+        println!("{}", line);
+        saved += &format!("{} {} {} {}\n", name, timing.median, timing.min, timing.max);
+    }
+
+    if let Some(name) = save_baseline {
+        let path = baseline_path(&name);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, saved).unwrap();
+        println!("Saved as {:?}", path);
+    }
+}
@@ -18,20 +18,32 @@
 // LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
- #![allow(dead_code)]
 
-use std::io::Result;
-use std::io::Read;
+use crate::fit_io::{self as io, Read, BufRead, Error};
+#[cfg(feature = "std")]
 use std::io::BufReader;
-use std::cmp::Ordering;
+use core::cmp::Ordering;
+#[cfg(feature = "std")]
 use std::collections::HashMap;
-use std::io::{Error};
-use std::convert::TryInto;
+use alloc::borrow::Cow;
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::vec;
+use core::fmt;
+use core::convert::TryInto;
+use alloc::sync::Arc;
+#[cfg(feature = "std")]
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use crate::fit_profile;
+#[cfg(feature = "async")]
+use std::pin::Pin;
+#[cfg(feature = "async")]
+use std::task::{Context, Poll};
+#[cfg(feature = "async")]
+use tokio::io::{AsyncRead, AsyncReadExt, ReadBuf};
 
 const HEADER_FILE_SIZE_OFFSET: usize = 0;
-const HEADER_PROTOCOL_VERSION_OFFSET: usize = 1;
-const HEADER_PROFILE_VERSION_LSB_OFFSET: usize = 2;
-const HEADER_PROFILE_VERSION_MSB_OFFSET: usize = 3;
 const HEADER_DATA_SIZE_LSB_OFFSET: usize = 4;
 const HEADER_DATA_SIZE_1_OFFSET: usize = 5;
 const HEADER_DATA_SIZE_2_OFFSET: usize = 6;
@@ -41,7 +53,6 @@ const HEADER_DATA_TYPE_1_OFFSET: usize = 9;  // F
 const HEADER_DATA_TYPE_2_OFFSET: usize = 10;  // I
 const HEADER_DATA_TYPE_3_OFFSET: usize = 11; // T
 const HEADER_CRC_1_OFFSET: usize = 12;
-const HEADER_CRC_2_OFFSET: usize = 13;
 
 const DEF_MSG_RESERVED: usize = 0;
 const DEF_MSG_ARCHITECTURE: usize = 1; // 1 = Definition and Data Message are Big Endian
@@ -53,6 +64,17 @@ const FIELD_MSG_INDEX: u8 = 254;
 const FIELD_TIMESTAMP: u8 = 253;
 const FIELD_PART_INDEX: u8 = 250;
 
+// Seconds between the UNIX epoch and the FIT epoch, UTC 00:00:00 Dec 31 1989.
+const FIT_TIMESTAMP_OFFSET: u32 = 631065600;
+
+// date_time values below this are system time, seconds since the device was powered on, instead of UTC.
+pub const FIT_DATE_TIME_MIN: u32 = 0x10000000;
+
+// Message index bits.
+pub const MESSAGE_INDEX_SELECTED: u16 = 0x8000;
+pub const MESSAGE_INDEX_RESERVED: u16 = 0x7000;
+pub const MESSAGE_INDEX_MASK: u16 = 0x0FFF;
+
 // Record message header byte offsets.
 const RECORD_HDR_NORMAL: u8 = 0x80;
 const RECORD_HDR_MSG_TYPE: u8 = 0x40;
@@ -439,8 +461,98 @@ pub const DISPLAY_MEASURE_METRIC : u8 = 0;
 pub const DISPLAY_MEASURE_STATUTE : u8 = 1;
 pub const DISPLAY_MEASURE_NAUTICAL : u8 = 2;
 
-type Callback<T> = fn(timestamp: u32, global_message_num: u16, local_message_type: u8, message_index: u16, data: Vec<FitFieldValue>, context: &mut T);
+#[cfg(feature = "std")]
+type Callback<T> = fn(timestamp: Option<FitDateTime>, global_message_num: u16, local_message_type: u8, message_index: MessageIndex, segment: usize, data: Vec<FitFieldValue>, context: &mut T);
+
+/// Errors that can occur while parsing a FIT file. Offsets are the number of bytes from the start of the stream.
+/// Errors that occur outside of a record (header and CRC problems) do not have a local message type.
+#[derive(Debug)]
+pub enum FitError {
+    /// The file header is too short or does not contain the ".FIT" signature.
+    BadHeader { offset: u64 },
+    /// A data message referred to a local message type that has not been defined.
+    MissingDefinition { offset: u64, local_msg_type: u8 },
+    /// The stream ended in the middle of something.
+    TruncatedData { offset: u64, local_msg_type: Option<u8> },
+    /// The CRC at the end of the file does not match the header and data bytes, or the CRC in a 14 byte header does not
+    /// match the header bytes. Only returned when strict_crc is set.
+    CrcMismatch { offset: u64, expected: u16, computed: u16 },
+    /// A field definition uses a base type that we don't know how to decode.
+    UnsupportedBaseType { offset: u64, local_msg_type: u8, field_def: u8, base_type: u8 },
+    /// A record header has the reserved bit set.
+    InvalidRecordHeader { offset: u64, local_msg_type: u8 },
+    /// Any other error from the underlying reader.
+    Io(Error)
+}
+
+impl FitError {
+    /// Converts an error from the underlying reader. Running out of data is reported as truncation.
+    fn from_io(e: Error, offset: u64, local_msg_type: Option<u8>) -> Self {
+        match e.kind() {
+            io::ErrorKind::UnexpectedEof => FitError::TruncatedData { offset, local_msg_type },
+            _ => FitError::Io(e)
+        }
+    }
+
+    /// Byte offset at which the error was detected, if known.
+    pub fn offset(&self) -> Option<u64> {
+        match self {
+            FitError::BadHeader { offset } => Some(*offset),
+            FitError::MissingDefinition { offset, .. } => Some(*offset),
+            FitError::TruncatedData { offset, .. } => Some(*offset),
+            FitError::CrcMismatch { offset, .. } => Some(*offset),
+            FitError::UnsupportedBaseType { offset, .. } => Some(*offset),
+            FitError::InvalidRecordHeader { offset, .. } => Some(*offset),
+            FitError::Io(_) => None
+        }
+    }
+
+    /// Local message type of the record being read when the error was detected, if any.
+    pub fn local_msg_type(&self) -> Option<u8> {
+        match self {
+            FitError::MissingDefinition { local_msg_type, .. } => Some(*local_msg_type),
+            FitError::TruncatedData { local_msg_type, .. } => *local_msg_type,
+            FitError::UnsupportedBaseType { local_msg_type, .. } => Some(*local_msg_type),
+            FitError::InvalidRecordHeader { local_msg_type, .. } => Some(*local_msg_type),
+            _ => None
+        }
+    }
+}
+
+impl fmt::Display for FitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FitError::BadHeader { offset } => write!(f, "Invalid FIT header at offset {}.", offset),
+            FitError::MissingDefinition { offset, local_msg_type } => write!(f, "Field definition not found for local message type {} at offset {}.", local_msg_type, offset),
+            FitError::TruncatedData { offset, local_msg_type: Some(local_msg_type) } => write!(f, "Data truncated in local message type {} at offset {}.", local_msg_type, offset),
+            FitError::TruncatedData { offset, local_msg_type: None } => write!(f, "Data truncated at offset {}.", offset),
+            FitError::CrcMismatch { offset, expected, computed } => write!(f, "CRC mismatch at offset {}, expected {:#06x} but computed {:#06x}.", offset, expected, computed),
+            FitError::UnsupportedBaseType { offset, local_msg_type, field_def, base_type } => write!(f, "Base type {:#x} not implemented for field {:#x} and local message type {} at offset {}.", base_type, field_def, local_msg_type, offset),
+            FitError::InvalidRecordHeader { offset, local_msg_type } => write!(f, "Reserved bit set in the record header for local message type {} at offset {}.", local_msg_type, offset),
+            FitError::Io(e) => write!(f, "{}", e)
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FitError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FitError::Io(e) => Some(e),
+            _ => None
+        }
+    }
+}
+
+impl From<Error> for FitError {
+    fn from(e: Error) -> Self {
+        FitError::Io(e)
+    }
+}
+
+pub type Result<T> = core::result::Result<T, FitError>;
 
+#[cfg(feature = "std")]
 pub fn init_global_msg_name_map() -> HashMap<u16, String> {
     let mut global_msg_name_map = HashMap::<u16, String>::new();
 
@@ -536,6 +648,7 @@ pub fn init_global_msg_name_map() -> HashMap<u16, String> {
 }
 
 /// Builds a hash map that maps Sports IDs to human-readable strings.
+#[cfg(feature = "std")]
 pub fn init_sport_name_map() -> HashMap<u8, String> {
     let mut sport_name_map = HashMap::<u8, String>::new();
 
@@ -593,149 +706,383 @@ pub fn init_sport_name_map() -> HashMap<u8, String> {
     sport_name_map
 }
 
-/// Utility function for reading a given number of bytes from a BufReader into a vec.
-fn read_n<R: Read>(reader: &mut BufReader<R>, bytes_to_read: u64) -> Result< Vec<u8> >
+/// Utility function for reading a given number of bytes from a reader into a vec.
+fn read_n<R: Read>(reader: &mut R, bytes_to_read: u64) -> io::Result< Vec<u8> >
 {
-    let mut buf = vec![];
-    let mut chunk = reader.take(bytes_to_read);
-    let _n = chunk.read_to_end(&mut buf).expect("Didn't read enough");
+    let mut buf = vec![0; bytes_to_read as usize];
+    reader.read_exact(&mut buf)?;
 
     Ok(buf)
 }
 
-/// Utility function for reading a 32-bit unsigned integer from a BufReader.
-fn read_u32<R: Read>(reader: &mut BufReader<R>, is_big_endian: bool) -> Result<u32>
-{
-    let bytes = read_n(reader, 4)?;
-    let num = byte_array_to_uint32(bytes, is_big_endian);
-
-    Ok(num)
+/// Utility function for reading as many bytes as will fit in the buffer, or as many as are left. Returns the number read.
+fn read_up_to<R: BufRead>(reader: &mut R, buf: &mut [u8]) -> io::Result<usize> {
+    let mut num_read = 0;
+    while num_read < buf.len() {
+        let available = reader.fill_buf()?;
+        if available.is_empty() {
+            break;
+        }
+        let num_bytes = available.len().min(buf.len() - num_read);
+        buf[num_read..num_read + num_bytes].copy_from_slice(&available[..num_bytes]);
+        reader.consume(num_bytes);
+        num_read += num_bytes;
+    }
+    Ok(num_read)
 }
 
-/// Utility function for reading a byte from a BufReader.
-fn read_byte<R: Read>(reader: &mut BufReader<R>) -> Result<u8>
-{
-    let mut byte: [u8; 1] = [0; 1];
-    reader.read_exact(&mut byte)?;
+/// Utility function for skipping everything left in a reader. Returns the number of bytes skipped.
+fn skip_to_end<R: BufRead>(reader: &mut R) -> io::Result<u64> {
+    let mut num_skipped = 0;
+    loop {
+        let num_bytes = reader.fill_buf()?.len();
+        if num_bytes == 0 {
+            return Ok(num_skipped);
+        }
+        reader.consume(num_bytes);
+        num_skipped += num_bytes as u64;
+    }
+}
 
-    Ok(byte[0])
+/// Utility function for decoding a string field, which may hold several null terminated UTF-8 strings.
+/// Invalid UTF-8 sequences are replaced rather than rejected. Empty strings, such as the padding at the end of the field, are dropped.
+fn byte_array_to_strings(bytes: &[u8]) -> impl Iterator<Item = Cow<'_, str>> {
+    bytes.split(|byte| *byte == 0).filter(|string| !string.is_empty()).map(String::from_utf8_lossy)
 }
 
-/// Utility function for reading a null-terminated string from the BufReader.
-fn read_string<R: Read>(reader: &mut BufReader<R>) -> Result<String>
-{
-    let mut result = String::new();
-    let mut done = false;
+/// Utility function for converting a byte array to an unsigned int of the given size.
+fn byte_array_to_num(bytes: &[u8], num_bytes: usize, is_big_endian: bool) -> u64 {
 
-    while !done {
-        let buf = read_n(reader, 1)?;
+    let mut num: u64 = 0;
+    let num_bytes = num_bytes.min(bytes.len());
 
-        if buf[0] == 0 {
-            done = true;
+    if is_big_endian {
+        for byte in bytes.iter().take(num_bytes) {
+            num = (num << 8) | (*byte as u64);
         }
-        else {
-            result.push(buf[0] as char);
+    }
+    else {
+        for byte in bytes.iter().take(num_bytes).rev() {
+            num = (num << 8) | (*byte as u64);
         }
     }
 
-    Ok(result)
+    num
 }
 
-/// Utility function for converting a byte array into a string of the specified number of bytes.
-fn byte_array_to_string(bytes: Vec<u8>, num_bytes: usize) -> String {
-    let mut result = String::new();
-
-    for i in 0..num_bytes {
-        result.push(bytes[i] as char);
-    }
-    result.trim_end_matches('\0').to_string()
+/// Utility function for converting a byte array to an u32
+fn byte_array_to_uint32(bytes: &[u8], is_big_endian: bool) -> u32 {
+    byte_array_to_num(bytes, 4, is_big_endian) as u32
 }
 
-/// Utility function for converting a byte array to an unsigned int of the given size.
-fn byte_array_to_num(bytes: Vec<u8>, num_bytes: usize, is_big_endian: bool) -> u64 {
-
-    let mut num: u64 = 0;
+/// Utility function for converting a byte array to an u16
+fn byte_array_to_uint16(bytes: &[u8], is_big_endian: bool) -> u16 {
+    byte_array_to_num(bytes, 2, is_big_endian) as u16
+}
 
+/// Utility function for converting a byte array to either a 32 or 64-bit float.
+fn byte_array_to_float(bytes: &[u8], num_bytes: usize, is_big_endian: bool) -> f64 {
     if bytes.len() < num_bytes {
-        panic!("Unexpected length; got {} when expecting {}", bytes.len(), num_bytes);
+        return 0.0;
     }
 
-    if is_big_endian {
-        for i in 0..num_bytes {
-            num = (num << 8) | (bytes[i] as u64);
+    if num_bytes == 1 {
+        return bytes[0] as f64;
+    }
+    else if num_bytes == 4 {
+        let byte_array: [u8; 4] = bytes[..4].try_into().unwrap();
+        if is_big_endian {
+            return f32::from_be_bytes(byte_array) as f64;
         }
+        return f32::from_le_bytes(byte_array) as f64;
     }
-    else {
-        for i in 0..num_bytes {
-            num = (num << 8) | (bytes[num_bytes - i - 1] as u64);
+    else if num_bytes == 8 {
+        let byte_array: [u8; 8] = bytes[..8].try_into().unwrap();
+        if is_big_endian {
+            return f64::from_be_bytes(byte_array);
         }
+        return f64::from_le_bytes(byte_array);
     }
 
-    num
+    0.0
 }
 
-/// Utility function for converting a byte array to an u64
-fn byte_array_to_uint64(bytes: Vec<u8>, is_big_endian: bool) -> u64 {
-    let temp = byte_array_to_num(bytes, 8, is_big_endian);
-    temp
+/// Returns the size, in bytes, of a single value of the given base type.
+fn base_type_size(base_type: u8) -> usize {
+    match base_type {
+        0x83 | 0x84 | 0x8B => 2,
+        0x85 | 0x86 | 0x88 | 0x8C => 4,
+        0x89 | 0x8E | 0x8F | 0x90 => 8,
+        _ => 1
+    }
 }
 
-/// Utility function for converting a byte array to an u32
-fn byte_array_to_uint32(bytes: Vec<u8>, is_big_endian: bool) -> u32 {
-    let temp = byte_array_to_num(bytes, 4, is_big_endian) as u32;
-    temp
+/// Returns the value used to indicate that a field of the given (numeric) base type is invalid.
+fn base_type_invalid_value(base_type: u8) -> u64 {
+    match base_type {
+        0x01 => 0x7F,
+        0x83 => 0x7FFF,
+        0x85 => 0x7FFFFFFF,
+        0x8E => 0x7FFFFFFFFFFFFFFF,
+        0x0A | 0x8B | 0x8C | 0x90 => 0, // uint8z, uint16z, uint32z, uint64z
+        _ => u64::MAX >> (64 - 8 * base_type_size(base_type))
+    }
 }
 
-/// Utility function for converting a byte array to an u16
-fn byte_array_to_uint16(bytes: Vec<u8>, is_big_endian: bool) -> u16 {
-    let temp = byte_array_to_num(bytes, 2, is_big_endian) as u16;
-    temp
+/// Utility function for checking whether or not the field data is the base type's invalid value.
+/// Arrays are only invalid if every element is invalid.
+fn byte_array_is_invalid(bytes: &[u8], base_type: u8, is_big_endian: bool) -> bool {
+    match base_type {
+        0x07 => bytes.first().map_or(true, |byte| *byte == 0),
+        0x0D => bytes.iter().all(|byte| *byte == 0xFF),
+        _ => {
+            let width = base_type_size(base_type);
+            let invalid_value = base_type_invalid_value(base_type);
+            bytes.chunks(width).all(|chunk| byte_array_to_num(chunk, width, is_big_endian) == invalid_value)
+        }
+    }
 }
 
-/// Utility function for converting a byte array to an u8
-fn byte_array_to_uint8(bytes: Vec<u8>) -> u8 {
-    bytes[0]
+/// Utility function for converting a raw value, as read by byte_array_to_num(), into a value of the given numeric base type.
+fn raw_to_value(raw_value: u64, base_type: u8) -> FitValue<'static> {
+    match base_type {
+        0x01 => FitValue::SInt(raw_value as i8 as i64),
+        0x83 => FitValue::SInt(raw_value as i16 as i64),
+        0x85 => FitValue::SInt(raw_value as i32 as i64),
+        0x8E => FitValue::SInt(raw_value as i64),
+        0x88 => FitValue::Float(f32::from_bits(raw_value as u32) as f64),
+        0x89 => FitValue::Float(f64::from_bits(raw_value)),
+        _ => FitValue::UInt(raw_value)
+    }
 }
 
-/// Utility function for converting a byte array to an i64
-fn byte_array_to_sint64(bytes: Vec<u8>, is_big_endian: bool) -> i64 {
-    let temp = byte_array_to_num(bytes, 8, is_big_endian) as i64;
-    temp
+/// Utility function for decoding a value of the given numeric base type.
+fn byte_array_to_value(bytes: &[u8], base_type: u8, is_big_endian: bool) -> FitValue<'static> {
+    let width = base_type_size(base_type);
+
+    match base_type {
+        0x88 | 0x89 => FitValue::Float(byte_array_to_float(bytes, width, is_big_endian)),
+        _ => raw_to_value(byte_array_to_num(bytes, width, is_big_endian), base_type)
+    }
 }
 
-/// Utility function for converting a byte array to an i32
-fn byte_array_to_sint32(bytes: Vec<u8>, is_big_endian: bool) -> i32 {
-    let temp = byte_array_to_num(bytes, 4, is_big_endian) as i32;
-    temp
+/// Utility function for decoding every element of an array field of the given numeric base type.
+fn byte_array_to_array(bytes: &[u8], base_type: u8, is_big_endian: bool) -> FitValue<'static> {
+    let width = base_type_size(base_type);
+    let chunks = bytes.chunks_exact(width);
+
+    match base_type_to_field_type(base_type) {
+        FieldType::FieldTypeSInt => FitValue::SIntArray(chunks.map(|chunk| {
+            let value = byte_array_to_num(chunk, width, is_big_endian);
+            match width {
+                1 => value as i8 as i64,
+                2 => value as i16 as i64,
+                4 => value as i32 as i64,
+                _ => value as i64
+            }
+        }).collect()),
+        FieldType::FieldTypeFloat => FitValue::FloatArray(chunks.map(|chunk| byte_array_to_float(chunk, width, is_big_endian)).collect()),
+        _ => FitValue::UIntArray(chunks.map(|chunk| byte_array_to_num(chunk, width, is_big_endian)).collect())
+    }
 }
 
-/// Utility function for converting a byte array to an i16
-fn byte_array_to_sint16(bytes: Vec<u8>, is_big_endian: bool) -> i16 {
-    let temp = byte_array_to_num(bytes, 2, is_big_endian) as i16;
-    temp
+/// Utility function for converting a raw value from one of the message structs into the units listed in the FIT profile.
+fn scale_field(global_message_num: u16, field_num: u8, value: f64) -> f64 {
+    match fit_profile::find_field(global_message_num, field_num) {
+        Some(profile) => profile.apply_scale(value),
+        None => value
+    }
 }
 
-/// Utility function for converting a byte array to an i8
-fn byte_array_to_sint8(bytes: Vec<u8>) -> i8 {
-    let temp = bytes[0] as i8;
-    temp
+/// Returns which of the FitFieldValue values is used for the given base type.
+fn base_type_to_field_type(base_type: u8) -> FieldType {
+    match base_type {
+        0x01 | 0x83 | 0x85 | 0x8E => FieldType::FieldTypeSInt,
+        0x88 | 0x89 => FieldType::FieldTypeFloat,
+        0x07 => FieldType::FieldTypeStr,
+        0x0D => FieldType::FieldTypeByteArray,
+        _ => FieldType::FieldTypeUInt
+    }
 }
 
-/// Utility function for converting a byte array to either a 32 or 64-bit float.
-fn byte_array_to_float(bytes: Vec<u8>, num_bytes: usize, _is_big_endian: bool) -> f64 {
-    if num_bytes == 1 {
-        return bytes[0] as f64;
+/// Utility function for reading a number that is packed into a little endian byte array, starting at the given bit.
+fn bits_to_num(bytes: &[u8], bit_offset: usize, num_bits: usize) -> u64 {
+    let mut value: u128 = 0;
+
+    for (i, byte) in bytes[bit_offset / 8..(bit_offset + num_bits).div_ceil(8)].iter().enumerate() {
+        value |= (*byte as u128) << (8 * i);
     }
-    else if num_bytes == 4 {
-        let byte_array = bytes.try_into().unwrap_or_else(|bytes: Vec<u8>| panic!("Expected a Vec of length {} but it was {}.", 4, bytes.len()));
-        return f32::from_bits(u32::from_be_bytes(byte_array)) as f64;
+    let mask = if num_bits >= 64 { u64::MAX } else { (1 << num_bits) - 1 };
+    (value >> (bit_offset % 8)) as u64 & mask
+}
+
+/// Rounds to the nearest integer, half way cases away from zero, the same as f64::round() followed by a cast to u64.
+/// f64::round() needs std, this only needs core.
+fn round_to_u64(value: f64) -> u64 {
+    let truncated = value as u64;
+    if value - truncated as f64 >= 0.5 {
+        truncated.saturating_add(1)
     }
-    else if num_bytes == 8 {
-        let byte_array = bytes.try_into().unwrap_or_else(|bytes: Vec<u8>| panic!("Expected a Vec of length {} but it was {}.", 8, bytes.len()));
-        return f64::from_bits(u64::from_be_bytes(byte_array)) as f64;
+    else {
+        truncated
     }
+}
 
-    0.0
+/// Writes the raw bytes of a field, in little endian order, to the buffer so that its components can be unpacked.
+fn field_to_le_bytes(field: &FitFieldValue, bytes: &mut Vec<u8>) {
+    let width = base_type_size(field.base_type);
+
+    bytes.clear();
+    match &field.value {
+        FitValue::ByteArray(value) => bytes.extend_from_slice(value),
+        FitValue::UInt(_) | FitValue::UIntArray(_) => field.uint_values().iter().for_each(|value| bytes.extend_from_slice(&value.to_le_bytes()[..width])),
+        FitValue::SInt(_) | FitValue::SIntArray(_) => field.sint_values().iter().for_each(|value| bytes.extend_from_slice(&value.to_le_bytes()[..width])),
+        _ => {}
+    }
+}
+
+/// Unpacks the components of each field, as described by the FIT profile, into the fields they refer to. Fields that
+/// are created this way are expanded in turn. A component is skipped if the message already has a valid value for the field
+/// it would be unpacked into. Components that are unpacked into the same field are stored as an array.
+/// Accumulated components are added to the running totals, which are reset by messages that contain the full value.
+fn expand_components(fields: &mut Vec<FitFieldValue>, definition: &LocalMessageDefinition, accumulators: &mut FieldAccumulators) {
+    let msg_profile = match definition.msg_profile {
+        Some(msg_profile) => msg_profile,
+        None => return
+    };
+    let num_original_fields = fields.len();
+
+    for (field, _) in fields.iter().zip(&definition.accumulated).filter(|(_, is_accumulated)| **is_accumulated) {
+        if let Some(value) = field.uint_values().last() {
+            if !field.is_invalid {
+                accumulators.get((msg_profile.global_message_num, field.field_def)).set(*value);
+            }
+        }
+    }
+    if definition.num_components == 0 {
+        return;
+    }
+
+    let mut buffer = Vec::new();
+    let mut i = 0;
+    while i < fields.len() {
+        let field = &fields[i];
+        i += 1;
+
+        let profile = match field.profile {
+            Some(profile) if !field.is_dev_field && !field.is_invalid && !profile.components.is_empty() => profile,
+            _ => continue
+        };
+
+        // Scalars are unpacked in place, arrays are copied to the buffer.
+        let scalar_bytes;
+        let bytes: &[u8] = match field.value {
+            FitValue::UInt(value) => { scalar_bytes = value.to_le_bytes(); &scalar_bytes[..base_type_size(field.base_type)] },
+            FitValue::SInt(value) => { scalar_bytes = value.to_le_bytes(); &scalar_bytes[..base_type_size(field.base_type)] },
+            _ => { field_to_le_bytes(field, &mut buffer); &buffer }
+        };
+        let mut bit_offset = 0;
+
+        for component in profile.components {
+            let num_bits = component.bits as usize;
+            if bit_offset + num_bits > bytes.len() * 8 {
+                break;
+            }
+            let mut raw_value = bits_to_num(bytes, bit_offset, num_bits);
+            bit_offset += num_bits;
+
+            let target = match msg_profile.find_field(component.field_num) {
+                Some(target) => target,
+                None => continue
+            };
+            if fields[..num_original_fields].iter().any(|field| !field.is_dev_field && !field.is_invalid && field.field_def == component.field_num) {
+                continue;
+            }
+            if component.accumulate {
+                let accumulator = accumulators.get((msg_profile.global_message_num, component.field_num));
+                raw_value = accumulator.accumulate(raw_value, component.bits);
+            }
+
+            // The component and the field it is unpacked into may have a different scale and offset.
+            let value = if component.scale == target.scale && component.offset == target.offset {
+                raw_value
+            }
+            else {
+                round_to_u64((raw_value as f64 / component.scale - component.offset + target.offset) * target.scale)
+            };
+
+            // Add the value to the field, creating the field if this is the first component that refers to it.
+            let existing = fields[num_original_fields..].iter_mut().find(|field| field.field_def == component.field_num);
+            match existing {
+                Some(expanded) => expanded.append_value(value),
+                None => {
+                    let mut expanded = FitFieldValue::new();
+                    expanded.field_def = component.field_num;
+                    expanded.base_type = target.base_type;
+                    expanded.profile = Some(target);
+                    expanded.value = match base_type_to_field_type(target.base_type) {
+                        FieldType::FieldTypeSInt => FitValue::SInt(value as i64),
+                        FieldType::FieldTypeFloat => FitValue::Float(value as f64),
+                        _ => FitValue::UInt(value)
+                    };
+                    if let Some(subfield) = select_subfield(&expanded, fields) {
+                        expanded.profile = Some(subfield);
+                    }
+                    fields.push(expanded);
+                }
+            }
+        }
+    }
+}
+
+/// Returns true if the field is the field with the given name in the FIT profile, and holds a valid value.
+fn is_profile_field(field: &FitFieldValue, name: &str) -> bool {
+    !field.is_dev_field && !field.is_invalid && field.profile.is_some_and(|profile| profile.name == name)
+}
+
+/// Utility function for reading a subfield from one of the message structs. Returns the subfield's profile if the
+/// reference field's value selects the subfield.
+fn subfield_profile<T: Into<u64>>(global_message_num: u16, field_num: u8, subfield_name: &str, ref_field_num: u8, ref_value: Option<T>) -> Option<&'static fit_profile::FieldProfile> {
+    let subfield = fit_profile::find_subfield(global_message_num, field_num, subfield_name)?;
+    if subfield.is_selected_by(ref_field_num, ref_value?.into()) {
+        return Some(&subfield.field);
+    }
+    None
+}
+
+/// Same as subfield_profile(), but converts the raw value into the subfield's units.
+fn scale_subfield<T: Into<u64>, V: Into<f64>>(global_message_num: u16, field_num: u8, subfield_name: &str, ref_field_num: u8, ref_value: Option<T>, value: Option<V>) -> Option<f64> {
+    let profile = subfield_profile(global_message_num, field_num, subfield_name, ref_field_num, ref_value)?;
+    Some(profile.apply_scale(value?.into()))
+}
+
+/// Returns the profile of the subfield that the other fields of the message select for the given field, if any.
+fn select_subfield(field: &FitFieldValue, fields: &[FitFieldValue]) -> Option<&'static fit_profile::FieldProfile> {
+    let profile = field.profile?;
+
+    for subfield in profile.subfields {
+        for other in fields {
+            if let FitValue::UInt(value) = other.value {
+                if !other.is_dev_field && !other.is_invalid && subfield.is_selected_by(other.field_def, value) {
+                    return Some(&subfield.field);
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Replaces the profile of each field whose meaning depends on another field with the profile of the selected subfield.
+/// Has to wait until the whole message is read, since the reference field may come after the field.
+fn resolve_subfields(fields: &mut [FitFieldValue]) {
+    for i in 0..fields.len() {
+        if fields[i].is_dev_field {
+            continue;
+        }
+        if let Some(subfield) = select_subfield(&fields[i], fields) {
+            fields[i].profile = Some(subfield);
+        }
+    }
 }
 
 /// Utility function for converting between semicircles and degrees.
@@ -758,28 +1105,33 @@ pub struct FitFileIdMsg {
 impl FitFileIdMsg {
 
     /// Constructor: Takes the fields that were read by the file parser and puts them into a structure.
-    pub fn new(fields: Vec<FitFieldValue>) -> Self {
+    pub fn new(fields: &[FitFieldValue]) -> Self {
         let mut msg = FitFileIdMsg { manufacturer: None, 
             serial_number: None, time_created: None, product_name: None, 
             number: None, file_type: None, product: None, 
         };
 
         for field in fields {
-            if !field.is_dev_field {
+            if !field.is_dev_field && !field.is_invalid {
                 match field.field_def {
                     1 => { msg.manufacturer = Some(field.get_u8()); },
                     3 => { msg.serial_number = Some(field.get_u32()); },
                     4 => { msg.time_created = Some(field.get_u32()); },
-                    8 => { msg.product_name = Some(field.value_string); },
+                    8 => { msg.product_name = Some(field.get_string().to_string()); },
                     5 => { msg.number = Some(field.get_u16()); },
                     0 => { msg.file_type = Some(field.get_u8()); },
                     2 => { msg.product = Some(field.get_u16()); },
-                    _ => { panic!("FileId field not implemented {:#x}", field.field_def); }
+                    _ => {}
                 }
             }
         }
         msg
     }
+
+    /// The product, as one of Garmin's product IDs, if the manufacturer is Garmin or one of its subsidiaries.
+    pub fn garmin_product(&self) -> Option<u16> {
+        subfield_profile(GLOBAL_MSG_NUM_FILE_ID, 2, "garmin_product", 1, self.manufacturer).and(self.product)
+    }
 }
 
 // Auto-generated by print_message_struct in lib.rs
@@ -791,22 +1143,71 @@ pub struct FitFileCreatorMsg {
 impl FitFileCreatorMsg {
 
     /// Constructor: Takes the fields that were read by the file parser and puts them into a structure.
-    pub fn new(fields: Vec<FitFieldValue>) -> Self {
+    pub fn new(fields: &[FitFieldValue]) -> Self {
         let mut msg = FitFileCreatorMsg { hardware_version: None, 
             software_version: None, 
         };
 
         for field in fields {
-            if !field.is_dev_field {
+            if !field.is_dev_field && !field.is_invalid {
                 match field.field_def {
                     1 => { msg.hardware_version = Some(field.get_u8()); },
                     0 => { msg.software_version = Some(field.get_u16()); },
-                    _ => { panic!("FileCreator field not implemented {:#x}", field.field_def); }
+                    _ => {}
+                }
+            }
+        }
+        msg
+    }
+}
+
+pub struct FitActivityMsg {
+    pub timestamp: Option<u32>,
+    pub total_timer_time: Option<u32>,
+    pub num_sessions: Option<u16>,
+    pub activity_type: Option<u8>,
+    pub event: Option<u8>,
+    pub event_type: Option<u8>,
+    pub local_timestamp: Option<u32>,
+    pub event_group: Option<u8>,
+}
+
+impl FitActivityMsg {
+
+    /// Constructor: Takes the fields that were read by the file parser and puts them into a structure.
+    pub fn new(fields: &[FitFieldValue]) -> Self {
+        let mut msg = FitActivityMsg { timestamp: None, 
+            total_timer_time: None, num_sessions: None, activity_type: None, 
+            event: None, event_type: None, local_timestamp: None, event_group: None, 
+        };
+
+        for field in fields {
+            if !field.is_dev_field && !field.is_invalid {
+                match field.field_def {
+                    253 => { msg.timestamp = Some(field.get_u32()); },
+                    0 => { msg.total_timer_time = Some(field.get_u32()); },
+                    1 => { msg.num_sessions = Some(field.get_u16()); },
+                    2 => { msg.activity_type = Some(field.get_u8()); },
+                    3 => { msg.event = Some(field.get_u8()); },
+                    4 => { msg.event_type = Some(field.get_u8()); },
+                    5 => { msg.local_timestamp = Some(field.get_u32()); },
+                    6 => { msg.event_group = Some(field.get_u8()); },
+                    _ => {}
                 }
             }
         }
         msg
     }
+
+    /// Offset of the athlete's time zone from UTC, in seconds, from the timestamp and local timestamp.
+    pub fn timezone_offset_s(&self) -> Option<i32> {
+        FitDateTime::from_fit(self.timestamp?).local_offset_s(self.local_timestamp?)
+    }
+
+    /// Total timer time, excluding pauses, in seconds.
+    pub fn total_timer_time_s(&self) -> Option<f64> {
+        self.total_timer_time.map(|value| scale_field(GLOBAL_MSG_NUM_ACTIVITY, 0, value as f64))
+    }
 }
 
 // Auto-generated by print_message_struct in lib.rs
@@ -834,11 +1235,11 @@ pub struct FitSessionMsg {
     pub max_neg_grade: Option<i16>,
     pub training_stress_score: Option<u16>,
     pub enhanced_max_altitude: Option<u32>,
-    pub max_cadence_position: Option<u8>,
+    pub max_cadence_position: Option<Vec<u8>>,
     pub total_calories: Option<u16>,
     pub avg_pos_grade: Option<i16>,
-    pub time_in_cadence_zone: Option<u32>,
-    pub zone_count: Option<u16>,
+    pub time_in_cadence_zone: Option<Vec<u32>>,
+    pub zone_count: Option<Vec<u16>>,
     pub enhanced_max_speed: Option<u32>,
     pub avg_cadence: Option<u8>,
     pub total_fractional_ascent: Option<u8>,
@@ -846,45 +1247,45 @@ pub struct FitSessionMsg {
     pub swc_long: Option<i32>,
     pub max_pos_vertical_speed: Option<i16>,
     pub avg_stance_time_balance: Option<u16>,
-    pub max_saturated_hemoglobin_percent: Option<u16>,
+    pub max_saturated_hemoglobin_percent: Option<Vec<u16>>,
     pub event_type: Option<u8>,
     pub first_lap_index: Option<u16>,
     pub enhanced_avg_speed: Option<u32>,
     pub avg_flow: Option<f32>,
-    pub time_in_hr_zone: Option<u32>,
+    pub time_in_hr_zone: Option<Vec<u32>>,
     pub pool_length_unit: Option<u8>,
     pub max_cadence: Option<u8>,
     pub event_group: Option<u8>,
-    pub avg_cadence_position: Option<u8>,
+    pub avg_cadence_position: Option<Vec<u8>>,
     pub start_position_long: Option<i32>,
     pub total_timer_time: Option<u32>,
     pub trigger: Option<u8>,
     pub max_speed: Option<u16>,
     pub nec_lat: Option<i32>,
     pub total_work: Option<u32>,
-    pub min_total_hemoglobin_conc: Option<u16>,
-    pub min_saturated_hemoglobin_percent: Option<u16>,
+    pub min_total_hemoglobin_conc: Option<Vec<u16>>,
+    pub min_saturated_hemoglobin_percent: Option<Vec<u16>>,
     pub max_lev_motor_power: Option<u16>,
     pub swc_lat: Option<i32>,
-    pub avg_left_power_phase: Option<u8>,
+    pub avg_left_power_phase: Option<Vec<u8>>,
     pub avg_ball_speed: Option<u16>,
-    pub stroke_count: Option<u16>,
+    pub stroke_count: Option<Vec<u16>>,
     pub total_fractional_cycles: Option<u8>,
     pub enhanced_avg_altitude: Option<u32>,
     pub total_training_effect: Option<u8>,
     pub avg_neg_grade: Option<i16>,
     pub min_altitude: Option<u16>,
     pub max_pos_grade: Option<i16>,
-    pub avg_right_power_phase_peak: Option<u8>,
+    pub avg_right_power_phase_peak: Option<Vec<u8>>,
     pub avg_right_torque_effectiveness: Option<u8>,
     pub avg_neg_vertical_speed: Option<i16>,
-    pub max_total_hemoglobin_conc: Option<u16>,
+    pub max_total_hemoglobin_conc: Option<Vec<u16>>,
     pub stand_count: Option<u16>,
     pub min_heart_rate: Option<u8>,
     pub sub_sport: Option<u8>,
     pub nec_long: Option<i32>,
-    pub avg_total_hemoglobin_conc: Option<u16>,
-    pub avg_power_position: Option<u16>,
+    pub avg_total_hemoglobin_conc: Option<Vec<u16>>,
+    pub avg_power_position: Option<Vec<u16>>,
     pub sport_index: Option<u8>,
     pub avg_fractional_cadence: Option<u8>,
     pub normalized_power: Option<u16>,
@@ -902,8 +1303,8 @@ pub struct FitSessionMsg {
     pub num_laps: Option<u16>,
     pub avg_grade: Option<i16>,
     pub avg_stroke_count: Option<u32>,
-    pub avg_left_power_phase_peak: Option<u8>,
-    pub max_power_position: Option<u16>,
+    pub avg_left_power_phase_peak: Option<Vec<u8>>,
+    pub max_power_position: Option<Vec<u16>>,
     pub total_fat_calories: Option<u16>,
     pub pool_length: Option<u16>,
     pub total_ascent: Option<u16>,
@@ -912,8 +1313,8 @@ pub struct FitSessionMsg {
     pub max_altitude: Option<u16>,
     pub time_standing: Option<u32>,
     pub avg_left_pco: Option<i8>,
-    pub time_in_power_zone: Option<u32>,
-    pub time_in_speed_zone: Option<u32>,
+    pub time_in_power_zone: Option<Vec<u32>>,
+    pub time_in_speed_zone: Option<Vec<u32>>,
     pub avg_power: Option<u16>,
     pub opponent_score: Option<u16>,
     pub avg_combined_pedal_smoothness: Option<u8>,
@@ -923,7 +1324,7 @@ pub struct FitSessionMsg {
     pub threshold_power: Option<u16>,
     pub start_time: Option<u32>,
     pub avg_vertical_oscillation: Option<u16>,
-    pub avg_saturated_hemoglobin_percent: Option<u16>,
+    pub avg_saturated_hemoglobin_percent: Option<Vec<u16>>,
     pub avg_right_pco: Option<i8>,
     pub sport: Option<u8>,
     pub avg_temperature: Option<i8>,
@@ -932,7 +1333,7 @@ pub struct FitSessionMsg {
     pub player_score: Option<u16>,
     pub avg_stance_time_percent: Option<u16>,
     pub avg_stroke_distance: Option<u16>,
-    pub avg_right_power_phase: Option<u8>,
+    pub avg_right_power_phase: Option<Vec<u8>>,
     pub avg_speed: Option<u16>,
     pub avg_vam: Option<u16>,
     pub max_heart_rate: Option<u8>,
@@ -943,7 +1344,7 @@ pub struct FitSessionMsg {
 impl FitSessionMsg {
 
     /// Constructor: Takes the fields that were read by the file parser and puts them into a structure.
-    pub fn new(fields: Vec<FitFieldValue>) -> Self {
+    pub fn new(fields: &[FitFieldValue]) -> Self {
         let mut msg = FitSessionMsg { total_cycles: None, 
             num_lengths: None, total_distance: None, avg_stance_time: None, 
             avg_right_pedal_smoothness: None, total_moving_time: None, avg_vertical_ratio: None, 
@@ -991,7 +1392,7 @@ impl FitSessionMsg {
         };
 
         for field in fields {
-            if !field.is_dev_field {
+            if !field.is_dev_field && !field.is_invalid {
                 match field.field_def {
                     10 => { msg.total_cycles = Some(field.get_u32()); },
                     33 => { msg.num_lengths = Some(field.get_u16()); },
@@ -1016,11 +1417,11 @@ impl FitSessionMsg {
                     56 => { msg.max_neg_grade = Some(field.get_i16()); },
                     35 => { msg.training_stress_score = Some(field.get_u16()); },
                     128 => { msg.enhanced_max_altitude = Some(field.get_u32()); },
-                    123 => { msg.max_cadence_position = Some(field.get_u8()); },
+                    123 => { msg.max_cadence_position = Some(field.get_u8_array()); },
                     11 => { msg.total_calories = Some(field.get_u16()); },
                     53 => { msg.avg_pos_grade = Some(field.get_i16()); },
-                    67 => { msg.time_in_cadence_zone = Some(field.get_u32()); },
-                    86 => { msg.zone_count = Some(field.get_u16()); },
+                    67 => { msg.time_in_cadence_zone = Some(field.get_u32_array()); },
+                    86 => { msg.zone_count = Some(field.get_u16_array()); },
                     125 => { msg.enhanced_max_speed = Some(field.get_u32()); },
                     18 => { msg.avg_cadence = Some(field.get_u8()); },
                     199 => { msg.total_fractional_ascent = Some(field.get_u8()); },
@@ -1028,45 +1429,45 @@ impl FitSessionMsg {
                     32 => { msg.swc_long = Some(field.get_i32()); },
                     62 => { msg.max_pos_vertical_speed = Some(field.get_i16()); },
                     133 => { msg.avg_stance_time_balance = Some(field.get_u16()); },
-                    100 => { msg.max_saturated_hemoglobin_percent = Some(field.get_u16()); },
+                    100 => { msg.max_saturated_hemoglobin_percent = Some(field.get_u16_array()); },
                     1 => { msg.event_type = Some(field.get_u8()); },
                     25 => { msg.first_lap_index = Some(field.get_u16()); },
                     124 => { msg.enhanced_avg_speed = Some(field.get_u32()); },
                     187 => { msg.avg_flow = Some(field.get_f32()); },
-                    65 => { msg.time_in_hr_zone = Some(field.get_u32()); },
+                    65 => { msg.time_in_hr_zone = Some(field.get_u32_array()); },
                     46 => { msg.pool_length_unit = Some(field.get_u8()); },
                     19 => { msg.max_cadence = Some(field.get_u8()); },
                     27 => { msg.event_group = Some(field.get_u8()); },
-                    122 => { msg.avg_cadence_position = Some(field.get_u8()); },
+                    122 => { msg.avg_cadence_position = Some(field.get_u8_array()); },
                     4 => { msg.start_position_long = Some(field.get_i32()); },
                     8 => { msg.total_timer_time = Some(field.get_u32()); },
                     28 => { msg.trigger = Some(field.get_u8()); },
                     15 => { msg.max_speed = Some(field.get_u16()); },
                     29 => { msg.nec_lat = Some(field.get_i32()); },
                     48 => { msg.total_work = Some(field.get_u32()); },
-                    96 => { msg.min_total_hemoglobin_conc = Some(field.get_u16()); },
-                    99 => { msg.min_saturated_hemoglobin_percent = Some(field.get_u16()); },
+                    96 => { msg.min_total_hemoglobin_conc = Some(field.get_u16_array()); },
+                    99 => { msg.min_saturated_hemoglobin_percent = Some(field.get_u16_array()); },
                     130 => { msg.max_lev_motor_power = Some(field.get_u16()); },
                     31 => { msg.swc_lat = Some(field.get_i32()); },
-                    116 => { msg.avg_left_power_phase = Some(field.get_u8()); },
+                    116 => { msg.avg_left_power_phase = Some(field.get_u8_array()); },
                     88 => { msg.avg_ball_speed = Some(field.get_u16()); },
-                    85 => { msg.stroke_count = Some(field.get_u16()); },
+                    85 => { msg.stroke_count = Some(field.get_u16_array()); },
                     94 => { msg.total_fractional_cycles = Some(field.get_u8()); },
                     126 => { msg.enhanced_avg_altitude = Some(field.get_u32()); },
                     24 => { msg.total_training_effect = Some(field.get_u8()); },
                     54 => { msg.avg_neg_grade = Some(field.get_i16()); },
                     71 => { msg.min_altitude = Some(field.get_u16()); },
                     55 => { msg.max_pos_grade = Some(field.get_i16()); },
-                    119 => { msg.avg_right_power_phase_peak = Some(field.get_u8()); },
+                    119 => { msg.avg_right_power_phase_peak = Some(field.get_u8_array()); },
                     102 => { msg.avg_right_torque_effectiveness = Some(field.get_u8()); },
                     61 => { msg.avg_neg_vertical_speed = Some(field.get_i16()); },
-                    97 => { msg.max_total_hemoglobin_conc = Some(field.get_u16()); },
+                    97 => { msg.max_total_hemoglobin_conc = Some(field.get_u16_array()); },
                     113 => { msg.stand_count = Some(field.get_u16()); },
                     64 => { msg.min_heart_rate = Some(field.get_u8()); },
                     6 => { msg.sub_sport = Some(field.get_u8()); },
                     30 => { msg.nec_long = Some(field.get_i32()); },
-                    95 => { msg.avg_total_hemoglobin_conc = Some(field.get_u16()); },
-                    120 => { msg.avg_power_position = Some(field.get_u16()); },
+                    95 => { msg.avg_total_hemoglobin_conc = Some(field.get_u16_array()); },
+                    120 => { msg.avg_power_position = Some(field.get_u16_array()); },
                     111 => { msg.sport_index = Some(field.get_u8()); },
                     92 => { msg.avg_fractional_cadence = Some(field.get_u8()); },
                     34 => { msg.normalized_power = Some(field.get_u16()); },
@@ -1077,15 +1478,15 @@ impl FitSessionMsg {
                     182 => { msg.total_flow = Some(field.get_f32()); },
                     37 => { msg.left_right_balance = Some(field.get_u16()); },
                     3 => { msg.start_position_lat = Some(field.get_i32()); },
-                    84 => { msg.opponent_name = Some(field.value_string); },
+                    84 => { msg.opponent_name = Some(field.get_string().to_string()); },
                     58 => { msg.max_temperature = Some(field.get_i8()); },
                     131 => { msg.lev_battery_consumption = Some(field.get_u8()); },
                     134 => { msg.avg_step_length = Some(field.get_u16()); },
                     26 => { msg.num_laps = Some(field.get_u16()); },
                     52 => { msg.avg_grade = Some(field.get_i16()); },
                     41 => { msg.avg_stroke_count = Some(field.get_u32()); },
-                    117 => { msg.avg_left_power_phase_peak = Some(field.get_u8()); },
-                    121 => { msg.max_power_position = Some(field.get_u16()); },
+                    117 => { msg.avg_left_power_phase_peak = Some(field.get_u8_array()); },
+                    121 => { msg.max_power_position = Some(field.get_u16_array()); },
                     13 => { msg.total_fat_calories = Some(field.get_u16()); },
                     44 => { msg.pool_length = Some(field.get_u16()); },
                     22 => { msg.total_ascent = Some(field.get_u16()); },
@@ -1094,8 +1495,8 @@ impl FitSessionMsg {
                     50 => { msg.max_altitude = Some(field.get_u16()); },
                     112 => { msg.time_standing = Some(field.get_u32()); },
                     114 => { msg.avg_left_pco = Some(field.get_i8()); },
-                    68 => { msg.time_in_power_zone = Some(field.get_u32()); },
-                    66 => { msg.time_in_speed_zone = Some(field.get_u32()); },
+                    68 => { msg.time_in_power_zone = Some(field.get_u32_array()); },
+                    66 => { msg.time_in_speed_zone = Some(field.get_u32_array()); },
                     20 => { msg.avg_power = Some(field.get_u16()); },
                     83 => { msg.opponent_score = Some(field.get_u16()); },
                     105 => { msg.avg_combined_pedal_smoothness = Some(field.get_u8()); },
@@ -1105,7 +1506,7 @@ impl FitSessionMsg {
                     45 => { msg.threshold_power = Some(field.get_u16()); },
                     2 => { msg.start_time = Some(field.get_u32()); },
                     89 => { msg.avg_vertical_oscillation = Some(field.get_u16()); },
-                    98 => { msg.avg_saturated_hemoglobin_percent = Some(field.get_u16()); },
+                    98 => { msg.avg_saturated_hemoglobin_percent = Some(field.get_u16_array()); },
                     115 => { msg.avg_right_pco = Some(field.get_i8()); },
                     5 => { msg.sport = Some(field.get_u8()); },
                     57 => { msg.avg_temperature = Some(field.get_i8()); },
@@ -1114,7 +1515,7 @@ impl FitSessionMsg {
                     82 => { msg.player_score = Some(field.get_u16()); },
                     90 => { msg.avg_stance_time_percent = Some(field.get_u16()); },
                     42 => { msg.avg_stroke_distance = Some(field.get_u16()); },
-                    118 => { msg.avg_right_power_phase = Some(field.get_u8()); },
+                    118 => { msg.avg_right_power_phase = Some(field.get_u8_array()); },
                     14 => { msg.avg_speed = Some(field.get_u16()); },
                     139 => { msg.avg_vam = Some(field.get_u16()); },
                     17 => { msg.max_heart_rate = Some(field.get_u8()); },
@@ -1126,6 +1527,31 @@ impl FitSessionMsg {
         }
         msg
     }
+
+    /// Total elapsed time, in seconds.
+    pub fn total_elapsed_time_s(&self) -> Option<f64> {
+        self.total_elapsed_time.map(|value| scale_field(GLOBAL_MSG_NUM_SESSION, 7, value as f64))
+    }
+
+    /// Total timer time, excluding pauses, in seconds.
+    pub fn total_timer_time_s(&self) -> Option<f64> {
+        self.total_timer_time.map(|value| scale_field(GLOBAL_MSG_NUM_SESSION, 8, value as f64))
+    }
+
+    /// Total distance, in meters.
+    pub fn total_distance_m(&self) -> Option<f64> {
+        self.total_distance.map(|value| scale_field(GLOBAL_MSG_NUM_SESSION, 9, value as f64))
+    }
+
+    /// Average speed, in meters per second.
+    pub fn avg_speed_m_per_s(&self) -> Option<f64> {
+        self.avg_speed.map(|value| scale_field(GLOBAL_MSG_NUM_SESSION, 14, value as f64))
+    }
+
+    /// Maximum speed, in meters per second.
+    pub fn max_speed_m_per_s(&self) -> Option<f64> {
+        self.max_speed.map(|value| scale_field(GLOBAL_MSG_NUM_SESSION, 15, value as f64))
+    }
 }
 
 // Auto-generated by print_message_struct in lib.rs
@@ -1153,7 +1579,7 @@ pub struct FitDeviceInfoMsg {
 impl FitDeviceInfoMsg {
 
     /// Constructor: Takes the fields that were read by the file parser and puts them into a structure.
-    pub fn new(fields: Vec<FitFieldValue>) -> Self {
+    pub fn new(fields: &[FitFieldValue]) -> Self {
         let mut msg = FitDeviceInfoMsg { battery_voltage: None, 
             cum_operating_time: None, serial_number: None, product: None,
             timestamp: None, sensor_position: None, source_type: None, 
@@ -1164,7 +1590,7 @@ impl FitDeviceInfoMsg {
         };
 
         for field in fields {
-            if !field.is_dev_field {
+            if !field.is_dev_field && !field.is_invalid {
                 match field.field_def {
                     10 => { msg.battery_voltage = Some(field.get_u16()); },
                     7 => { msg.cum_operating_time = Some(field.get_u32()); },
@@ -1176,10 +1602,10 @@ impl FitDeviceInfoMsg {
                     5 => { msg.software_version = Some(field.get_u16()); },
                     20 => { msg.ant_transmission_type = Some(field.get_u8()); },
                     21 => { msg.ant_device_number = Some(field.get_u16()); },
-                    19 => { msg.descriptor = Some(field.value_string); },
+                    19 => { msg.descriptor = Some(field.get_string().to_string()); },
                     1 => { msg.device_type = Some(field.get_u8()); },
                     22 => { msg.ant_network = Some(field.get_u8()); },
-                    27 => { msg.product_name = Some(field.value_string); },
+                    27 => { msg.product_name = Some(field.get_string().to_string()); },
                     0 => { msg.device_index = Some(field.get_u8()); },
                     6 => { msg.hardware_version = Some(field.get_u8()); },
                     11 => { msg.battery_status = Some(field.get_u8()); },
@@ -1190,24 +1616,29 @@ impl FitDeviceInfoMsg {
         }
         msg
     }
+
+    /// Battery voltage, in volts.
+    pub fn battery_voltage_v(&self) -> Option<f64> {
+        self.battery_voltage.map(|value| scale_field(GLOBAL_MSG_NUM_DEVICE_INFO, 10, value as f64))
+    }
 }
 
 // Auto-generated by print_message_struct in lib.rs
 pub struct FitLapMsg {
     pub opponent_score: Option<u16>,
     pub avg_vertical_ratio: Option<u16>,
-    pub avg_saturated_hemoglobin_percent: Option<u16>,
+    pub avg_saturated_hemoglobin_percent: Option<Vec<u16>>,
     pub num_lengths: Option<u16>,
     pub max_temperature: Option<i8>,
     pub sport: Option<u8>,
     pub min_altitude: Option<u16>,
     pub avg_right_torque_effectiveness: Option<u8>,
     pub normalized_power: Option<u16>,
-    pub time_in_hr_zone: Option<u32>,
+    pub time_in_hr_zone: Option<Vec<u32>>,
     pub left_right_balance: Option<u16>,
-    pub avg_right_power_phase: Option<u8>,
+    pub avg_right_power_phase: Option<Vec<u8>>,
     pub event_group: Option<u8>,
-    pub avg_left_power_phase: Option<u8>,
+    pub avg_left_power_phase: Option<Vec<u8>>,
     pub avg_cadence: Option<u8>,
     pub start_time: Option<u32>,
     pub avg_power: Option<u16>,
@@ -1215,7 +1646,7 @@ pub struct FitLapMsg {
     pub avg_neg_vertical_speed: Option<i16>,
     pub total_elapsed_time: Option<u32>,
     pub max_pos_grade: Option<i16>,
-    pub max_cadence_position: Option<u8>,
+    pub max_cadence_position: Option<Vec<u8>>,
     pub event_type: Option<u8>,
     pub start_position_long: Option<i32>,
     pub total_descent: Option<u16>,
@@ -1228,20 +1659,20 @@ pub struct FitLapMsg {
     pub time_standing: Option<u32>,
     pub first_length_index: Option<u16>,
     pub total_cycles: Option<u32>,
-    pub avg_cadence_position: Option<u8>,
+    pub avg_cadence_position: Option<Vec<u8>>,
     pub lev_battery_consumption: Option<u8>,
     pub avg_temperature: Option<i8>,
     pub max_pos_vertical_speed: Option<i16>,
     pub timestamp: Option<u32>,
     pub enhanced_max_speed: Option<u32>,
-    pub zone_count: Option<u16>,
+    pub zone_count: Option<Vec<u16>>,
     pub avg_pos_grade: Option<i16>,
     pub avg_lev_motor_power: Option<u16>,
     pub total_timer_time: Option<u32>,
     pub avg_pos_vertical_speed: Option<i16>,
     pub total_work: Option<u32>,
-    pub time_in_speed_zone: Option<u32>,
-    pub stroke_count: Option<u16>,
+    pub time_in_speed_zone: Option<Vec<u32>>,
+    pub stroke_count: Option<Vec<u16>>,
     pub avg_vertical_oscillation: Option<u16>,
     pub avg_fractional_cadence: Option<u8>,
     pub jump_count: Option<u16>,
@@ -1249,7 +1680,7 @@ pub struct FitLapMsg {
     pub avg_step_length: Option<u16>,
     pub total_fractional_descent: Option<u8>,
     pub avg_combined_pedal_smoothness: Option<u8>,
-    pub max_power_position: Option<u16>,
+    pub max_power_position: Option<Vec<u16>>,
     pub total_flow: Option<f32>,
     pub avg_stroke_distance: Option<u16>,
     pub max_neg_grade: Option<i16>,
@@ -1261,10 +1692,10 @@ pub struct FitLapMsg {
     pub avg_stance_time_balance: Option<u16>,
     pub avg_neg_grade: Option<i16>,
     pub lap_trigger: Option<u8>,
-    pub max_saturated_hemoglobin_percent: Option<u16>,
+    pub max_saturated_hemoglobin_percent: Option<Vec<u16>>,
     pub max_heart_rate: Option<u8>,
     pub total_fractional_cycles: Option<u8>,
-    pub avg_left_power_phase_peak: Option<u8>,
+    pub avg_left_power_phase_peak: Option<Vec<u8>>,
     pub total_moving_time: Option<u32>,
     pub avg_stance_time: Option<u16>,
     pub total_calories: Option<u16>,
@@ -1277,9 +1708,9 @@ pub struct FitLapMsg {
     pub total_fractional_ascent: Option<u8>,
     pub end_position_lat: Option<i32>,
     pub total_distance: Option<u32>,
-    pub avg_power_position: Option<u16>,
-    pub time_in_power_zone: Option<u32>,
-    pub max_total_hemoglobin_conc: Option<u16>,
+    pub avg_power_position: Option<Vec<u16>>,
+    pub time_in_power_zone: Option<Vec<u32>>,
+    pub max_total_hemoglobin_conc: Option<Vec<u16>>,
     pub avg_stance_time_percent: Option<u16>,
     pub start_position_lat: Option<i32>,
     pub gps_accuracy: Option<u8>,
@@ -1290,29 +1721,29 @@ pub struct FitLapMsg {
     pub total_fat_calories: Option<u16>,
     pub avg_left_pco: Option<i8>,
     pub avg_right_pco: Option<i8>,
-    pub avg_right_power_phase_peak: Option<u8>,
+    pub avg_right_power_phase_peak: Option<Vec<u8>>,
     pub end_position_long: Option<i32>,
     pub avg_left_pedal_smoothness: Option<u8>,
     pub avg_speed: Option<u16>,
-    pub avg_total_hemoglobin_conc: Option<u16>,
+    pub avg_total_hemoglobin_conc: Option<Vec<u16>>,
     pub total_grit: Option<f32>,
     pub intensity: Option<u8>,
     pub avg_grit: Option<f32>,
     pub max_fractional_cadence: Option<u8>,
-    pub min_total_hemoglobin_conc: Option<u16>,
+    pub min_total_hemoglobin_conc: Option<Vec<u16>>,
     pub avg_flow: Option<f32>,
     pub max_cadence: Option<u8>,
     pub avg_grade: Option<i16>,
-    pub min_saturated_hemoglobin_percent: Option<u16>,
+    pub min_saturated_hemoglobin_percent: Option<Vec<u16>>,
     pub total_ascent: Option<u16>,
     pub max_neg_vertical_speed: Option<i16>,
-    pub time_in_cadence_zone: Option<u32>,
+    pub time_in_cadence_zone: Option<Vec<u32>>,
 }
 
 impl FitLapMsg {
 
     /// Constructor: Takes the fields that were read by the file parser and puts them into a structure.
-    pub fn new(fields: Vec<FitFieldValue>) -> Self {
+    pub fn new(fields: &[FitFieldValue]) -> Self {
         let mut msg = FitLapMsg { opponent_score: None, 
             avg_vertical_ratio: None, avg_saturated_hemoglobin_percent: None, num_lengths: None, 
             max_temperature: None, sport: None, min_altitude: None, 
@@ -1355,22 +1786,22 @@ impl FitLapMsg {
         };
 
         for field in fields {
-            if !field.is_dev_field {
+            if !field.is_dev_field && !field.is_invalid {
                 match field.field_def {
                     74 => { msg.opponent_score = Some(field.get_u16()); },
                     118 => { msg.avg_vertical_ratio = Some(field.get_u16()); },
-                    87 => { msg.avg_saturated_hemoglobin_percent = Some(field.get_u16()); },
+                    87 => { msg.avg_saturated_hemoglobin_percent = Some(field.get_u16_array()); },
                     32 => { msg.num_lengths = Some(field.get_u16()); },
                     51 => { msg.max_temperature = Some(field.get_i8()); },
                     25 => { msg.sport = Some(field.get_u8()); },
                     62 => { msg.min_altitude = Some(field.get_u16()); },
                     92 => { msg.avg_right_torque_effectiveness = Some(field.get_u8()); },
                     33 => { msg.normalized_power = Some(field.get_u16()); },
-                    57 => { msg.time_in_hr_zone = Some(field.get_u32()); },
+                    57 => { msg.time_in_hr_zone = Some(field.get_u32_array()); },
                     34 => { msg.left_right_balance = Some(field.get_u16()); },
-                    104 => { msg.avg_right_power_phase = Some(field.get_u8()); },
+                    104 => { msg.avg_right_power_phase = Some(field.get_u8_array()); },
                     26 => { msg.event_group = Some(field.get_u8()); },
-                    102 => { msg.avg_left_power_phase = Some(field.get_u8()); },
+                    102 => { msg.avg_left_power_phase = Some(field.get_u8_array()); },
                     17 => { msg.avg_cadence = Some(field.get_u8()); },
                     2 => { msg.start_time = Some(field.get_u32()); },
                     19 => { msg.avg_power = Some(field.get_u16()); },
@@ -1378,7 +1809,7 @@ impl FitLapMsg {
                     54 => { msg.avg_neg_vertical_speed = Some(field.get_i16()); },
                     7 => { msg.total_elapsed_time = Some(field.get_u32()); },
                     48 => { msg.max_pos_grade = Some(field.get_i16()); },
-                    109 => { msg.max_cadence_position = Some(field.get_u8()); },
+                    109 => { msg.max_cadence_position = Some(field.get_u8_array()); },
                     1 => { msg.event_type = Some(field.get_u8()); },
                     4 => { msg.start_position_long = Some(field.get_i32()); },
                     22 => { msg.total_descent = Some(field.get_u16()); },
@@ -1391,20 +1822,20 @@ impl FitLapMsg {
                     98 => { msg.time_standing = Some(field.get_u32()); },
                     35 => { msg.first_length_index = Some(field.get_u16()); },
                     10 => { msg.total_cycles = Some(field.get_u32()); },
-                    108 => { msg.avg_cadence_position = Some(field.get_u8()); },
+                    108 => { msg.avg_cadence_position = Some(field.get_u8_array()); },
                     117 => { msg.lev_battery_consumption = Some(field.get_u8()); },
                     50 => { msg.avg_temperature = Some(field.get_i8()); },
                     55 => { msg.max_pos_vertical_speed = Some(field.get_i16()); },
                     253 => { msg.timestamp = Some(field.get_u32()); },
                     111 => { msg.enhanced_max_speed = Some(field.get_u32()); },
-                    76 => { msg.zone_count = Some(field.get_u16()); },
+                    76 => { msg.zone_count = Some(field.get_u16_array()); },
                     46 => { msg.avg_pos_grade = Some(field.get_i16()); },
                     115 => { msg.avg_lev_motor_power = Some(field.get_u16()); },
                     8 => { msg.total_timer_time = Some(field.get_u32()); },
                     53 => { msg.avg_pos_vertical_speed = Some(field.get_i16()); },
                     41 => { msg.total_work = Some(field.get_u32()); },
-                    58 => { msg.time_in_speed_zone = Some(field.get_u32()); },
-                    75 => { msg.stroke_count = Some(field.get_u16()); },
+                    58 => { msg.time_in_speed_zone = Some(field.get_u32_array()); },
+                    75 => { msg.stroke_count = Some(field.get_u16_array()); },
                     77 => { msg.avg_vertical_oscillation = Some(field.get_u16()); },
                     80 => { msg.avg_fractional_cadence = Some(field.get_u8()); },
                     151 => { msg.jump_count = Some(field.get_u16()); },
@@ -1412,7 +1843,7 @@ impl FitLapMsg {
                     120 => { msg.avg_step_length = Some(field.get_u16()); },
                     157 => { msg.total_fractional_descent = Some(field.get_u8()); },
                     95 => { msg.avg_combined_pedal_smoothness = Some(field.get_u8()); },
-                    107 => { msg.max_power_position = Some(field.get_u16()); },
+                    107 => { msg.max_power_position = Some(field.get_u16_array()); },
                     150 => { msg.total_flow = Some(field.get_f32()); },
                     37 => { msg.avg_stroke_distance = Some(field.get_u16()); },
                     49 => { msg.max_neg_grade = Some(field.get_i16()); },
@@ -1424,10 +1855,10 @@ impl FitLapMsg {
                     119 => { msg.avg_stance_time_balance = Some(field.get_u16()); },
                     47 => { msg.avg_neg_grade = Some(field.get_i16()); },
                     24 => { msg.lap_trigger = Some(field.get_u8()); },
-                    89 => { msg.max_saturated_hemoglobin_percent = Some(field.get_u16()); },
+                    89 => { msg.max_saturated_hemoglobin_percent = Some(field.get_u16_array()); },
                     16 => { msg.max_heart_rate = Some(field.get_u8()); },
                     82 => { msg.total_fractional_cycles = Some(field.get_u8()); },
-                    103 => { msg.avg_left_power_phase_peak = Some(field.get_u8()); },
+                    103 => { msg.avg_left_power_phase_peak = Some(field.get_u8_array()); },
                     52 => { msg.total_moving_time = Some(field.get_u32()); },
                     79 => { msg.avg_stance_time = Some(field.get_u16()); },
                     11 => { msg.total_calories = Some(field.get_u16()); },
@@ -1440,9 +1871,9 @@ impl FitLapMsg {
                     156 => { msg.total_fractional_ascent = Some(field.get_u8()); },
                     5 => { msg.end_position_lat = Some(field.get_i32()); },
                     9 => { msg.total_distance = Some(field.get_u32()); },
-                    106 => { msg.avg_power_position = Some(field.get_u16()); },
-                    60 => { msg.time_in_power_zone = Some(field.get_u32()); },
-                    86 => { msg.max_total_hemoglobin_conc = Some(field.get_u16()); },
+                    106 => { msg.avg_power_position = Some(field.get_u16_array()); },
+                    60 => { msg.time_in_power_zone = Some(field.get_u32_array()); },
+                    86 => { msg.max_total_hemoglobin_conc = Some(field.get_u16_array()); },
                     78 => { msg.avg_stance_time_percent = Some(field.get_u16()); },
                     3 => { msg.start_position_lat = Some(field.get_i32()); },
                     44 => { msg.gps_accuracy = Some(field.get_u8()); },
@@ -1453,36 +1884,61 @@ impl FitLapMsg {
                     12 => { msg.total_fat_calories = Some(field.get_u16()); },
                     100 => { msg.avg_left_pco = Some(field.get_i8()); },
                     101 => { msg.avg_right_pco = Some(field.get_i8()); },
-                    105 => { msg.avg_right_power_phase_peak = Some(field.get_u8()); },
+                    105 => { msg.avg_right_power_phase_peak = Some(field.get_u8_array()); },
                     6 => { msg.end_position_long = Some(field.get_i32()); },
                     93 => { msg.avg_left_pedal_smoothness = Some(field.get_u8()); },
                     13 => { msg.avg_speed = Some(field.get_u16()); },
-                    84 => { msg.avg_total_hemoglobin_conc = Some(field.get_u16()); },
+                    84 => { msg.avg_total_hemoglobin_conc = Some(field.get_u16_array()); },
                     149 => { msg.total_grit = Some(field.get_f32()); },
                     23 => { msg.intensity = Some(field.get_u8()); },
                     153 => { msg.avg_grit = Some(field.get_f32()); },
                     81 => { msg.max_fractional_cadence = Some(field.get_u8()); },
-                    85 => { msg.min_total_hemoglobin_conc = Some(field.get_u16()); },
+                    85 => { msg.min_total_hemoglobin_conc = Some(field.get_u16_array()); },
                     154 => { msg.avg_flow = Some(field.get_f32()); },
                     18 => { msg.max_cadence = Some(field.get_u8()); },
                     45 => { msg.avg_grade = Some(field.get_i16()); },
-                    88 => { msg.min_saturated_hemoglobin_percent = Some(field.get_u16()); },
+                    88 => { msg.min_saturated_hemoglobin_percent = Some(field.get_u16_array()); },
                     21 => { msg.total_ascent = Some(field.get_u16()); },
                     56 => { msg.max_neg_vertical_speed = Some(field.get_i16()); },
-                    59 => { msg.time_in_cadence_zone = Some(field.get_u32()); },
+                    59 => { msg.time_in_cadence_zone = Some(field.get_u32_array()); },
                     _ => { /* panic!("Lap field not implemented {:#x}", field.field_def); */ }
                 }
             }
         }
         msg
     }
+
+    /// Total elapsed time, in seconds.
+    pub fn total_elapsed_time_s(&self) -> Option<f64> {
+        self.total_elapsed_time.map(|value| scale_field(GLOBAL_MSG_NUM_LAP, 7, value as f64))
+    }
+
+    /// Total timer time, excluding pauses, in seconds.
+    pub fn total_timer_time_s(&self) -> Option<f64> {
+        self.total_timer_time.map(|value| scale_field(GLOBAL_MSG_NUM_LAP, 8, value as f64))
+    }
+
+    /// Total distance, in meters.
+    pub fn total_distance_m(&self) -> Option<f64> {
+        self.total_distance.map(|value| scale_field(GLOBAL_MSG_NUM_LAP, 9, value as f64))
+    }
+
+    /// Average speed, in meters per second.
+    pub fn avg_speed_m_per_s(&self) -> Option<f64> {
+        self.avg_speed.map(|value| scale_field(GLOBAL_MSG_NUM_LAP, 13, value as f64))
+    }
+
+    /// Maximum speed, in meters per second.
+    pub fn max_speed_m_per_s(&self) -> Option<f64> {
+        self.max_speed.map(|value| scale_field(GLOBAL_MSG_NUM_LAP, 14, value as f64))
+    }
 }
 
 // Auto-generated by print_message_struct in lib.rs
 pub struct FitLengthMsg {
     pub opponent_score: Option<u16>,
-    pub stroke_count: Option<u16>,
-    pub zone_count: Option<u16>,
+    pub stroke_count: Option<Vec<u16>>,
+    pub zone_count: Option<Vec<u16>>,
     pub length_type: Option<u8>,
     pub total_elapsed_time: Option<u32>,
     pub player_score: Option<u16>,
@@ -1503,7 +1959,7 @@ pub struct FitLengthMsg {
 impl FitLengthMsg {
 
     /// Constructor: Takes the fields that were read by the file parser and puts them into a structure.
-    pub fn new(fields: Vec<FitFieldValue>) -> Self {
+    pub fn new(fields: &[FitFieldValue]) -> Self {
         let mut msg = FitLengthMsg { opponent_score: None, 
             stroke_count: None, zone_count: None, length_type: None, 
             total_elapsed_time: None, player_score: None, timestamp: None, 
@@ -1514,11 +1970,11 @@ impl FitLengthMsg {
         };
 
         for field in fields {
-            if !field.is_dev_field {
+            if !field.is_dev_field && !field.is_invalid {
                 match field.field_def {
                     19 => { msg.opponent_score = Some(field.get_u16()); },
-                    20 => { msg.stroke_count = Some(field.get_u16()); },
-                    21 => { msg.zone_count = Some(field.get_u16()); },
+                    20 => { msg.stroke_count = Some(field.get_u16_array()); },
+                    21 => { msg.zone_count = Some(field.get_u16_array()); },
                     12 => { msg.length_type = Some(field.get_u8()); },
                     3 => { msg.total_elapsed_time = Some(field.get_u32()); },
                     18 => { msg.player_score = Some(field.get_u16()); },
@@ -1540,6 +1996,21 @@ impl FitLengthMsg {
         }
         msg
     }
+
+    /// Total elapsed time, in seconds.
+    pub fn total_elapsed_time_s(&self) -> Option<f64> {
+        self.total_elapsed_time.map(|value| scale_field(GLOBAL_MSG_NUM_LENGTH, 3, value as f64))
+    }
+
+    /// Total timer time, excluding pauses, in seconds.
+    pub fn total_timer_time_s(&self) -> Option<f64> {
+        self.total_timer_time.map(|value| scale_field(GLOBAL_MSG_NUM_LENGTH, 4, value as f64))
+    }
+
+    /// Average speed, in meters per second.
+    pub fn avg_speed_m_per_s(&self) -> Option<f64> {
+        self.avg_speed.map(|value| scale_field(GLOBAL_MSG_NUM_LENGTH, 6, value as f64))
+    }
 }
 
 // Auto-generated by print_message_struct in lib.rs
@@ -1570,7 +2041,7 @@ pub struct FitRecordMsg {
     pub ebike_assist_mode: Option<u8>,
     pub n2_load: Option<u16>,
     pub grade: Option<i16>,
-    pub left_power_phase: Option<u8>,
+    pub left_power_phase: Option<Vec<u8>>,
     pub power: Option<u16>,
     pub timestamp: Option<u32>,
     pub vertical_oscillation: Option<u16>,
@@ -1582,7 +2053,7 @@ pub struct FitRecordMsg {
     pub stance_time_percent: Option<u16>,
     pub enhanced_altitude: Option<u32>,
     pub stance_time_balance: Option<u16>,
-    pub speed_1s: Option<u8>,
+    pub speed_1s: Option<Vec<u8>>,
     pub battery_soc: Option<u8>,
     pub total_hemoglobin_conc_min: Option<u16>,
     pub cns_load: Option<u8>,
@@ -1605,16 +2076,16 @@ pub struct FitRecordMsg {
     pub saturated_hemoglobin_percent_max: Option<u16>,
     pub altitude: Option<u16>,
     pub left_pco: Option<i8>,
-    pub left_power_phase_peak: Option<u8>,
+    pub left_power_phase_peak: Option<Vec<u8>>,
     pub flow: Option<f32>,
     pub device_index: Option<u8>,
     pub cadence: Option<u8>,
     pub ebike_assist_level_percent: Option<u8>,
-    pub right_power_phase_peak: Option<u8>,
+    pub right_power_phase_peak: Option<Vec<u8>>,
     pub ebike_battery_level: Option<u8>,
-    pub compressed_speed_distance: Option<u8>,
+    pub compressed_speed_distance: Option<Vec<u8>>,
     pub left_pedal_smoothness: Option<u8>,
-    pub right_power_phase: Option<u8>,
+    pub right_power_phase: Option<Vec<u8>>,
     pub right_torque_effectiveness: Option<u8>,
     pub time_to_surface: Option<u32>,
 }
@@ -1622,7 +2093,7 @@ pub struct FitRecordMsg {
 impl FitRecordMsg {
 
     /// Constructor: Takes the fields that were read by the file parser and puts them into a structure.
-    pub fn new(fields: Vec<FitFieldValue>) -> Self {
+    pub fn new(fields: &[FitFieldValue]) -> Self {
         let mut msg = FitRecordMsg { step_length: None, 
             resistance: None, speed: None, accumulated_power: None, 
             next_stop_depth: None, stroke_type: None, heart_rate: None, 
@@ -1651,7 +2122,7 @@ impl FitRecordMsg {
         };
 
         for field in fields {
-            if !field.is_dev_field {
+            if !field.is_dev_field && !field.is_invalid {
                 match field.field_def {
                     85 => { msg.step_length = Some(field.get_u16()); },
                     10 => { msg.resistance = Some(field.get_u8()); },
@@ -1679,7 +2150,7 @@ impl FitRecordMsg {
                     119 => { msg.ebike_assist_mode = Some(field.get_u8()); },
                     98 => { msg.n2_load = Some(field.get_u16()); },
                     9 => { msg.grade = Some(field.get_i16()); },
-                    69 => { msg.left_power_phase = Some(field.get_u8()); },
+                    69 => { msg.left_power_phase = Some(field.get_u8_array()); },
                     7 => { msg.power = Some(field.get_u16()); },
                     253 => { msg.timestamp = Some(field.get_u32()); },
                     39 => { msg.vertical_oscillation = Some(field.get_u16()); },
@@ -1691,7 +2162,7 @@ impl FitRecordMsg {
                     40 => { msg.stance_time_percent = Some(field.get_u16()); },
                     78 => { msg.enhanced_altitude = Some(field.get_u32()); },
                     84 => { msg.stance_time_balance = Some(field.get_u16()); },
-                    17 => { msg.speed_1s = Some(field.get_u8()); },
+                    17 => { msg.speed_1s = Some(field.get_u8_array()); },
                     81 => { msg.battery_soc = Some(field.get_u8()); },
                     55 => { msg.total_hemoglobin_conc_min = Some(field.get_u16()); },
                     97 => { msg.cns_load = Some(field.get_u8()); },
@@ -1714,16 +2185,16 @@ impl FitRecordMsg {
                     59 => { msg.saturated_hemoglobin_percent_max = Some(field.get_u16()); },
                     2 => { msg.altitude = Some(field.get_u16()); },
                     67 => { msg.left_pco = Some(field.get_i8()); },
-                    70 => { msg.left_power_phase_peak = Some(field.get_u8()); },
+                    70 => { msg.left_power_phase_peak = Some(field.get_u8_array()); },
                     115 => { msg.flow = Some(field.get_f32()); },
                     62 => { msg.device_index = Some(field.get_u8()); },
                     4 => { msg.cadence = Some(field.get_u8()); },
                     120 => { msg.ebike_assist_level_percent = Some(field.get_u8()); },
-                    72 => { msg.right_power_phase_peak = Some(field.get_u8()); },
+                    72 => { msg.right_power_phase_peak = Some(field.get_u8_array()); },
                     118 => { msg.ebike_battery_level = Some(field.get_u8()); },
-                    8 => { msg.compressed_speed_distance = Some(field.get_u8()); },
+                    8 => { msg.compressed_speed_distance = Some(field.get_u8_array()); },
                     45 => { msg.left_pedal_smoothness = Some(field.get_u8()); },
-                    71 => { msg.right_power_phase = Some(field.get_u8()); },
+                    71 => { msg.right_power_phase = Some(field.get_u8_array()); },
                     44 => { msg.right_torque_effectiveness = Some(field.get_u8()); },
                     95 => { msg.time_to_surface = Some(field.get_u32()); },
                     87 => { }, // Can't find a definition for these.
@@ -1735,6 +2206,31 @@ impl FitRecordMsg {
         }
         msg
     }
+
+    /// Altitude, in meters.
+    pub fn altitude_m(&self) -> Option<f64> {
+        self.altitude.map(|value| scale_field(GLOBAL_MSG_NUM_RECORD, 2, value as f64))
+    }
+
+    /// Distance, in meters.
+    pub fn distance_m(&self) -> Option<f64> {
+        self.distance.map(|value| scale_field(GLOBAL_MSG_NUM_RECORD, 5, value as f64))
+    }
+
+    /// Speed, in meters per second.
+    pub fn speed_m_per_s(&self) -> Option<f64> {
+        self.speed.map(|value| scale_field(GLOBAL_MSG_NUM_RECORD, 6, value as f64))
+    }
+
+    /// Enhanced speed, in meters per second.
+    pub fn enhanced_speed_m_per_s(&self) -> Option<f64> {
+        self.enhanced_speed.map(|value| scale_field(GLOBAL_MSG_NUM_RECORD, 73, value as f64))
+    }
+
+    /// Enhanced altitude, in meters.
+    pub fn enhanced_altitude_m(&self) -> Option<f64> {
+        self.enhanced_altitude.map(|value| scale_field(GLOBAL_MSG_NUM_RECORD, 78, value as f64))
+    }
 }
 
 pub struct FitEventMsg {
@@ -1758,7 +2254,7 @@ pub struct FitEventMsg {
 impl FitEventMsg {
 
     /// Constructor: Takes the fields that were read by the file parser and puts them into a structure.
-    pub fn new(fields: Vec<FitFieldValue>) -> Self {
+    pub fn new(fields: &[FitFieldValue]) -> Self {
         let mut msg = FitEventMsg { event_group: None,
             rear_gear: None, data16: None, event: None,
             rear_gear_num: None, score: None, data: None,
@@ -1768,7 +2264,7 @@ impl FitEventMsg {
         };
 
         for field in fields {
-            if !field.is_dev_field {
+            if !field.is_dev_field && !field.is_invalid {
                 match field.field_def {
                     4 => { msg.event_group = Some(field.get_u8()); },
                     12 => { msg.rear_gear = Some(field.get_u8()); },
@@ -1791,6 +2287,106 @@ impl FitEventMsg {
         }
         msg
     }
+
+    /// Returns the data field if the event selects the given subfield.
+    fn data_subfield(&self, subfield_name: &str) -> Option<u32> {
+        subfield_profile(GLOBAL_MSG_NUM_EVENT, 3, subfield_name, 0, self.event).and(self.data)
+    }
+
+    /// Same as data_subfield(), but in the subfield's units.
+    fn scaled_data_subfield(&self, subfield_name: &str) -> Option<f64> {
+        scale_subfield(GLOBAL_MSG_NUM_EVENT, 3, subfield_name, 0, self.event, self.data)
+    }
+
+    /// What started or stopped the timer, if this is a timer event.
+    pub fn timer_trigger(&self) -> Option<u8> {
+        self.data_subfield("timer_trigger").map(|value| value as u8)
+    }
+
+    /// Index of the course point, if this is a course point event.
+    pub fn course_point_index(&self) -> Option<u16> {
+        self.data_subfield("course_point_index").map(|value| value as u16)
+    }
+
+    /// Battery voltage, in volts, if this is a battery event.
+    pub fn battery_level_v(&self) -> Option<f64> {
+        self.scaled_data_subfield("battery_level")
+    }
+
+    /// Speed of the virtual partner, in meters per second, if this is a virtual partner pace event.
+    pub fn virtual_partner_speed_m_per_s(&self) -> Option<f64> {
+        self.scaled_data_subfield("virtual_partner_speed")
+    }
+
+    /// Heart rate that triggered the alert, in beats per minute.
+    pub fn hr_high_alert(&self) -> Option<u8> {
+        self.data_subfield("hr_high_alert").map(|value| value as u8)
+    }
+
+    /// Heart rate that triggered the alert, in beats per minute.
+    pub fn hr_low_alert(&self) -> Option<u8> {
+        self.data_subfield("hr_low_alert").map(|value| value as u8)
+    }
+
+    /// Speed that triggered the alert, in meters per second.
+    pub fn speed_high_alert_m_per_s(&self) -> Option<f64> {
+        self.scaled_data_subfield("speed_high_alert")
+    }
+
+    /// Speed that triggered the alert, in meters per second.
+    pub fn speed_low_alert_m_per_s(&self) -> Option<f64> {
+        self.scaled_data_subfield("speed_low_alert")
+    }
+
+    /// Cadence that triggered the alert, in revolutions per minute.
+    pub fn cad_high_alert(&self) -> Option<u16> {
+        self.data_subfield("cad_high_alert").map(|value| value as u16)
+    }
+
+    /// Cadence that triggered the alert, in revolutions per minute.
+    pub fn cad_low_alert(&self) -> Option<u16> {
+        self.data_subfield("cad_low_alert").map(|value| value as u16)
+    }
+
+    /// Power that triggered the alert, in watts.
+    pub fn power_high_alert(&self) -> Option<u16> {
+        self.data_subfield("power_high_alert").map(|value| value as u16)
+    }
+
+    /// Power that triggered the alert, in watts.
+    pub fn power_low_alert(&self) -> Option<u16> {
+        self.data_subfield("power_low_alert").map(|value| value as u16)
+    }
+
+    /// Duration that triggered the alert, in seconds.
+    pub fn time_duration_alert_s(&self) -> Option<f64> {
+        self.scaled_data_subfield("time_duration_alert")
+    }
+
+    /// Distance that triggered the alert, in meters.
+    pub fn distance_duration_alert_m(&self) -> Option<f64> {
+        self.scaled_data_subfield("distance_duration_alert")
+    }
+
+    /// Calories that triggered the alert.
+    pub fn calorie_duration_alert(&self) -> Option<u32> {
+        self.data_subfield("calorie_duration_alert")
+    }
+
+    /// State of the fitness equipment, if this is a fitness equipment event.
+    pub fn fitness_equipment_state(&self) -> Option<u8> {
+        self.data_subfield("fitness_equipment_state").map(|value| value as u8)
+    }
+
+    /// New rider position, if this is a rider position change event.
+    pub fn rider_position(&self) -> Option<u8> {
+        self.data_subfield("rider_position").map(|value| value as u8)
+    }
+
+    /// Type of timeout, if this is a communication timeout event.
+    pub fn comm_timeout(&self) -> Option<u16> {
+        self.data_subfield("comm_timeout").map(|value| value as u16)
+    }
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -1814,7 +2410,7 @@ pub struct FitWorkoutMsg {
 }
 
 impl FitWorkoutMsg {
-    pub fn new(fields: Vec<FitFieldValue>) -> FitWorkoutMsg {
+    pub fn new(fields: &[FitFieldValue]) -> FitWorkoutMsg {
         let mut msg = FitWorkoutMsg {
             message_index: None,
             sport: None,
@@ -1827,12 +2423,12 @@ impl FitWorkoutMsg {
         };
 
         for field in fields.iter() {
-            if !field.is_dev_field {
+            if !field.is_dev_field && !field.is_invalid {
                 match field.field_def {
                     4 => msg.sport = Some(field.get_u8()),
                     5 => msg.capabilities = Some(field.get_u32()),
                     6 => msg.num_valid_steps = Some(field.get_u16()),
-                    8 => msg.workout_name = Some(field.value_string.clone()),
+                    8 => msg.workout_name = Some(field.get_string().to_string()),
                     11 => msg.sub_sport = Some(field.get_u8()),
                     14 => msg.pool_length = Some(field.get_u16()),
                     15 => msg.pool_length_unit = Some(field.get_u8()),
@@ -1844,10 +2440,16 @@ impl FitWorkoutMsg {
 
         msg
     }
+
+    /// Pool length, in meters.
+    pub fn pool_length_m(&self) -> Option<f64> {
+        self.pool_length.map(|value| scale_field(GLOBAL_MSG_NUM_WORKOUT, 14, value as f64))
+    }
 }
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct FitWorkoutStepMsg {
+    /// Index of the step within the workout, repeat steps refer to other steps by this index
     pub message_index: u16,
     pub step_name: Option<String>,
     /// enum values defined in constants `WORKOUT_STEP_DURATION_*`
@@ -1885,9 +2487,9 @@ pub struct FitWorkoutStepMsg {
 }
 
 impl FitWorkoutStepMsg {
-    pub fn new(message_index: u16, fields: Vec<FitFieldValue>) -> FitWorkoutStepMsg {
+    pub fn new(message_index: MessageIndex, fields: &[FitFieldValue]) -> FitWorkoutStepMsg {
         let mut msg = FitWorkoutStepMsg{
-            message_index,
+            message_index: message_index.index(),
             step_name: None,
             duration_type: None,
             duration_value: None,
@@ -1905,9 +2507,9 @@ impl FitWorkoutStepMsg {
         };
 
         for field in fields {
-            if !field.is_dev_field {
+            if !field.is_dev_field && !field.is_invalid {
                 match field.field_def {
-                    0 => msg.step_name = Some(field.value_string),
+                    0 => msg.step_name = Some(field.get_string().to_string()),
                     1 => msg.duration_type = Some(field.get_u8()),
                     2 => msg.duration_value = Some(field.get_u32()),
                     3 => msg.target_type = Some(field.get_u8()),
@@ -1915,7 +2517,7 @@ impl FitWorkoutStepMsg {
                     5 => msg.custom_target_low = Some(field.get_u32()),
                     6 => msg.custom_target_high = Some(field.get_u32()),
                     7 => msg.intensity = Some(field.get_u8()),
-                    8 => msg.notes = Some(field.value_string),
+                    8 => msg.notes = Some(field.get_string().to_string()),
                     9 => msg.equipment = Some(field.get_u8()),
                     19 => msg.secondary_target_type = Some(field.get_u8()),
                     20 => msg.secondary_target_value = Some(field.get_u32()),
@@ -1928,75 +2530,500 @@ impl FitWorkoutStepMsg {
 
         msg
     }
-}
 
-#[derive(Clone, Debug)]
-pub enum FieldType {
-    FieldTypeNotSet, // Value not set
-    FieldTypeUInt, // Value is an unsigned integer
-    FieldTypeSInt, // Value is a signed integer
-    FieldTypeFloat, // Value is a float
-    FieldTypeByteArray, // Value is a byte array
-    FieldTypeStr // Value is a tring
-}
+    /// Returns the duration value if the duration type selects the given subfield.
+    fn duration_subfield(&self, subfield_name: &str) -> Option<u32> {
+        subfield_profile(GLOBAL_MSG_NUM_WORKOUT_STEP, 2, subfield_name, 1, self.duration_type).and(self.duration_value)
+    }
 
-#[derive(Clone, Debug)]
-pub struct FitFieldValue {
-    pub field_def: u8, // From the message definition
-    pub base_type: u8, // Base type, from the SDK
-    pub type_enum: FieldType, // Tells us which of the following to use
-    pub value_uint: u64,
-    pub value_sint: i64,
-    pub value_float: f64,
-    pub value_byte_array: Vec<u8>,
-    pub value_string: String,
-    pub is_dev_field: bool
-}
+    /// Returns the target value if the target type, or the duration type for repeat steps, selects the given subfield.
+    fn target_subfield(&self, subfield_name: &str) -> Option<u32> {
+        subfield_profile(GLOBAL_MSG_NUM_WORKOUT_STEP, 4, subfield_name, 3, self.target_type)
+            .or_else(|| subfield_profile(GLOBAL_MSG_NUM_WORKOUT_STEP, 4, subfield_name, 1, self.duration_type))
+            .and(self.target_value)
+    }
 
-impl FitFieldValue {
-    pub fn new() -> Self {
-        let state = FitFieldValue{ field_def: 0, type_enum: FieldType::FieldTypeNotSet, base_type: 0, value_uint: 0, value_sint: 0, value_float: 0.0, value_byte_array: Vec::<u8>::new(), value_string: String::new(), is_dev_field: false };
-        state
+    /// Duration of the step, in seconds, if the duration type is time or repetition time.
+    pub fn duration_time_s(&self) -> Option<f64> {
+        scale_subfield(GLOBAL_MSG_NUM_WORKOUT_STEP, 2, "duration_time", 1, self.duration_type, self.duration_value)
+    }
+
+    /// Distance of the step, in meters, if the duration type is distance.
+    pub fn duration_distance_m(&self) -> Option<f64> {
+        scale_subfield(GLOBAL_MSG_NUM_WORKOUT_STEP, 2, "duration_distance", 1, self.duration_type, self.duration_value)
+    }
+
+    /// Heart rate that ends the step, if the duration type is a heart rate. See `custom_target_low` for how to interpret it.
+    pub fn duration_hr(&self) -> Option<u32> {
+        self.duration_subfield("duration_hr")
+    }
+
+    /// Calories that end the step, if the duration type is calories.
+    pub fn duration_calories(&self) -> Option<u32> {
+        self.duration_subfield("duration_calories")
+    }
+
+    /// Index of the step to repeat from, if this is a repeat step.
+    pub fn duration_step(&self) -> Option<u32> {
+        self.duration_subfield("duration_step")
+    }
+
+    /// Power that ends the step, if the duration type is a power. See `custom_target_low` for how to interpret it.
+    pub fn duration_power(&self) -> Option<u32> {
+        self.duration_subfield("duration_power")
+    }
+
+    /// Number of repetitions, if the duration type is reps.
+    pub fn duration_reps(&self) -> Option<u32> {
+        self.duration_subfield("duration_reps")
+    }
+
+    /// Speed zone, if the target type is speed.
+    pub fn target_speed_zone(&self) -> Option<u32> {
+        self.target_subfield("target_speed_zone")
+    }
+
+    /// Heart rate zone, if the target type is heart rate.
+    pub fn target_hr_zone(&self) -> Option<u32> {
+        self.target_subfield("target_hr_zone")
+    }
+
+    /// Cadence zone, if the target type is cadence.
+    pub fn target_cadence_zone(&self) -> Option<u32> {
+        self.target_subfield("target_cadence_zone")
+    }
+
+    /// Power zone, if the target type is power.
+    pub fn target_power_zone(&self) -> Option<u32> {
+        self.target_subfield("target_power_zone")
+    }
+
+    /// Swim stroke, if the target type is swim stroke.
+    pub fn target_stroke_type(&self) -> Option<u8> {
+        self.target_subfield("target_stroke_type").map(|value| value as u8)
+    }
+
+    /// Number of times to repeat, if this step repeats until a number of steps is complete.
+    pub fn repeat_steps(&self) -> Option<u32> {
+        self.target_subfield("repeat_steps")
+    }
+
+    /// Time to repeat for, in seconds, if this step repeats until a time.
+    pub fn repeat_time_s(&self) -> Option<f64> {
+        scale_subfield(GLOBAL_MSG_NUM_WORKOUT_STEP, 4, "repeat_time", 1, self.duration_type, self.target_value)
+    }
+
+    /// Distance to repeat for, in meters, if this step repeats until a distance.
+    pub fn repeat_distance_m(&self) -> Option<f64> {
+        scale_subfield(GLOBAL_MSG_NUM_WORKOUT_STEP, 4, "repeat_distance", 1, self.duration_type, self.target_value)
+    }
+
+    /// Calories to repeat for, if this step repeats until a number of calories.
+    pub fn repeat_calories(&self) -> Option<u32> {
+        self.target_subfield("repeat_calories")
+    }
+
+    /// Heart rate to repeat until, if this step repeats until a heart rate. See `custom_target_low` for how to interpret it.
+    pub fn repeat_hr(&self) -> Option<u32> {
+        self.target_subfield("repeat_hr")
+    }
+
+    /// Power to repeat until, if this step repeats until a power. See `custom_target_low` for how to interpret it.
+    pub fn repeat_power(&self) -> Option<u32> {
+        self.target_subfield("repeat_power")
+    }
+
+    /// Custom speed range, in meters per second, if the target type is speed.
+    pub fn custom_target_speed_m_per_s(&self) -> (Option<f64>, Option<f64>) {
+        (scale_subfield(GLOBAL_MSG_NUM_WORKOUT_STEP, 5, "custom_target_speed_low", 3, self.target_type, self.custom_target_low),
+         scale_subfield(GLOBAL_MSG_NUM_WORKOUT_STEP, 6, "custom_target_speed_high", 3, self.target_type, self.custom_target_high))
+    }
+
+    /// Custom heart rate range, if the target type is heart rate. See `custom_target_low` for how to interpret it.
+    pub fn custom_target_heart_rate(&self) -> (Option<u32>, Option<u32>) {
+        (subfield_profile(GLOBAL_MSG_NUM_WORKOUT_STEP, 5, "custom_target_heart_rate_low", 3, self.target_type).and(self.custom_target_low),
+         subfield_profile(GLOBAL_MSG_NUM_WORKOUT_STEP, 6, "custom_target_heart_rate_high", 3, self.target_type).and(self.custom_target_high))
+    }
+
+    /// Custom cadence range, in revolutions per minute, if the target type is cadence.
+    pub fn custom_target_cadence(&self) -> (Option<u32>, Option<u32>) {
+        (subfield_profile(GLOBAL_MSG_NUM_WORKOUT_STEP, 5, "custom_target_cadence_low", 3, self.target_type).and(self.custom_target_low),
+         subfield_profile(GLOBAL_MSG_NUM_WORKOUT_STEP, 6, "custom_target_cadence_high", 3, self.target_type).and(self.custom_target_high))
+    }
+
+    /// Custom power range, if the target type is power. See `custom_target_low` for how to interpret it.
+    pub fn custom_target_power(&self) -> (Option<u32>, Option<u32>) {
+        (subfield_profile(GLOBAL_MSG_NUM_WORKOUT_STEP, 5, "custom_target_power_low", 3, self.target_type).and(self.custom_target_low),
+         subfield_profile(GLOBAL_MSG_NUM_WORKOUT_STEP, 6, "custom_target_power_high", 3, self.target_type).and(self.custom_target_high))
+    }
+}
+
+/// Part of a memo, such as a long description, that was split across several memo_glob messages.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FitMemoGlobMsg {
+    pub part_index: Option<u32>, // Position of this part within the memo
+    pub memo: Option<Vec<u8>>,
+    pub message_number: Option<u16>, // Global message number of the message the memo belongs to
+    pub message_index: Option<u16>, // Message index of the message the memo belongs to
+}
+
+impl FitMemoGlobMsg {
+
+    /// Constructor: Takes the fields that were read by the file parser and puts them into a structure.
+    pub fn new(fields: &[FitFieldValue]) -> Self {
+        let mut msg = FitMemoGlobMsg { part_index: None, memo: None, message_number: None, message_index: None };
+
+        for field in fields {
+            if !field.is_dev_field && !field.is_invalid {
+                match field.field_def {
+                    FIELD_PART_INDEX => { msg.part_index = Some(field.get_u32()); },
+                    0 => {
+                        // Older versions of the profile described the memo as a string.
+                        match field.type_enum() {
+                            FieldType::FieldTypeStr => msg.memo = Some(field.get_string().as_bytes().to_vec()),
+                            _ => msg.memo = Some(field.get_u8_array())
+                        }
+                    },
+                    1 => { msg.message_number = Some(field.get_u16()); },
+                    2 => { msg.message_index = Some(field.get_u16()); },
+                    _ => {}
+                }
+            }
+        }
+        msg
+    }
+}
+
+/// Reassembles memos that were split across memo_glob messages. Parts may arrive in any order, and
+/// are joined in order of their part index. Memos are keyed by the global message number and message
+/// index of the message they belong to.
+#[derive(Clone, Debug, Default)]
+pub struct FitMemoGlobAssembler {
+    parts: BTreeMap<(u16, u16), MemoParts>
+}
+
+/// The parts of a memo, keyed by part index.
+type MemoParts = BTreeMap<u32, Vec<u8>>;
+
+impl FitMemoGlobAssembler {
+    pub fn new() -> Self {
+        FitMemoGlobAssembler { parts: BTreeMap::new() }
+    }
+
+    /// Adds a part. Parts without a memo, a part index, a message number or a message index are ignored, since
+    /// there is no telling which memo they belong to. A repeated part replaces the earlier one.
+    pub fn add(&mut self, msg: &FitMemoGlobMsg) {
+        if let (Some(part_index), Some(memo), Some(message_number), Some(message_index)) = (msg.part_index, &msg.memo, msg.message_number, msg.message_index) {
+            self.parts.entry((message_number, message_index)).or_default().insert(part_index, memo.clone());
+        }
+    }
+
+    /// Returns the global message numbers and message indexes of the messages that have a memo, in order.
+    pub fn keys(&self) -> Vec<(u16, u16)> {
+        self.parts.keys().cloned().collect()
+    }
+
+    /// Returns the memo for the given message, with its parts joined in order.
+    pub fn memo(&self, message_number: u16, message_index: u16) -> Option<Vec<u8>> {
+        let parts = self.parts.get(&(message_number, message_index))?;
+        Some(parts.values().flatten().cloned().collect())
+    }
+
+    /// Same as memo(), but as a string. Null padding at the end of the memo is removed.
+    pub fn memo_string(&self, message_number: u16, message_index: u16) -> Option<String> {
+        let memo = self.memo(message_number, message_index)?;
+        let len = memo.iter().rposition(|byte| *byte != 0).map_or(0, |pos| pos + 1);
+        Some(String::from_utf8_lossy(&memo[..len]).into_owned())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum FieldType {
+    FieldTypeNotSet, // Value not set
+    FieldTypeUInt, // Value is an unsigned integer
+    FieldTypeSInt, // Value is a signed integer
+    FieldTypeFloat, // Value is a float
+    FieldTypeByteArray, // Value is a byte array
+    FieldTypeStr // Value is a tring
+}
+
+/// The value of a field. Fields that hold more than one number, or more than one null terminated string, use the array variants.
+/// Strings and byte arrays may borrow from the data the field was decoded from, see Fit::read_slice_ref().
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum FitValue<'a> {
+    #[default]
+    NotSet,
+    UInt(u64),
+    SInt(i64),
+    Float(f64),
+    ByteArray(Cow<'a, [u8]>),
+    Str(Cow<'a, str>),
+    UIntArray(Vec<u64>),
+    SIntArray(Vec<i64>),
+    FloatArray(Vec<f64>),
+    StrArray(Vec<Cow<'a, str>>)
+}
+
+impl FitValue<'_> {
+    /// Copies any borrowed strings or byte arrays, so the value no longer borrows from the data it was decoded from.
+    pub fn into_owned(self) -> FitValue<'static> {
+        match self {
+            FitValue::NotSet => FitValue::NotSet,
+            FitValue::UInt(value) => FitValue::UInt(value),
+            FitValue::SInt(value) => FitValue::SInt(value),
+            FitValue::Float(value) => FitValue::Float(value),
+            FitValue::ByteArray(bytes) => FitValue::ByteArray(Cow::Owned(bytes.into_owned())),
+            FitValue::Str(value) => FitValue::Str(Cow::Owned(value.into_owned())),
+            FitValue::UIntArray(values) => FitValue::UIntArray(values),
+            FitValue::SIntArray(values) => FitValue::SIntArray(values),
+            FitValue::FloatArray(values) => FitValue::FloatArray(values),
+            FitValue::StrArray(values) => FitValue::StrArray(values.into_iter().map(|value| Cow::Owned(value.into_owned())).collect())
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct FitFieldValue<'a> {
+    pub field_def: u8, // From the message definition
+    pub base_type: u8, // Base type, from the SDK
+    pub value: FitValue<'a>,
+    pub is_dev_field: bool,
+    pub is_invalid: bool, // Set if the field holds its base type's invalid value, i.e. the device didn't provide a value
+    pub profile: Option<&'static fit_profile::FieldProfile>, // Name, scale, offset, and units from the FIT profile, if the field is in the profile. Refers to the subfield if another field selects one.
+    pub developer_field: Option<Arc<DeveloperFieldDescription>> // Name, scale, offset, and units of a developer field, if a field description message described it
+}
+
+impl Default for FitFieldValue<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> FitFieldValue<'a> {
+    pub fn new() -> Self {
+        FitFieldValue{ field_def: 0, base_type: 0, value: FitValue::NotSet, is_dev_field: false, is_invalid: false, profile: None, developer_field: None }
+    }
+
+    /// Copies any borrowed strings or byte arrays, so the field no longer borrows from the data it was decoded from.
+    pub fn into_owned(self) -> FitFieldValue<'static> {
+        FitFieldValue{ field_def: self.field_def, base_type: self.base_type, value: self.value.into_owned(), is_dev_field: self.is_dev_field, is_invalid: self.is_invalid, profile: self.profile, developer_field: self.developer_field }
+    }
+
+    /// Tells us which of the get_* functions to use.
+    pub fn type_enum(&self) -> FieldType {
+        match self.value {
+            FitValue::NotSet => FieldType::FieldTypeNotSet,
+            FitValue::UInt(_) | FitValue::UIntArray(_) => FieldType::FieldTypeUInt,
+            FitValue::SInt(_) | FitValue::SIntArray(_) => FieldType::FieldTypeSInt,
+            FitValue::Float(_) | FitValue::FloatArray(_) => FieldType::FieldTypeFloat,
+            FitValue::ByteArray(_) => FieldType::FieldTypeByteArray,
+            FitValue::Str(_) | FitValue::StrArray(_) => FieldType::FieldTypeStr
+        }
+    }
+
+    /// Returns the field's name from the FIT profile, or from the field description of a developer field.
+    pub fn name(&self) -> Option<&str> {
+        match &self.developer_field {
+            Some(description) => Some(&description.name),
+            None => self.profile.map(|profile| profile.name)
+        }
+    }
+
+    /// Returns the units of the scaled value, or an empty string if the profile or field description doesn't list any.
+    pub fn units(&self) -> &str {
+        match &self.developer_field {
+            Some(description) => &description.units,
+            None => self.profile.map_or("", |profile| profile.units)
+        }
+    }
+
+    /// Returns the value after applying the profile's scale and offset, i.e. in the units returned by units().
+    /// Returns None if the field is invalid or isn't a number. The first element is used for arrays.
+    /// This works for every field in the profile. The message structs only have unit accessors, such as
+    /// FitRecordMsg::altitude_m(), for their most commonly used fields.
+    pub fn scaled(&self) -> Option<f64> {
+        if self.is_invalid {
+            return None;
+        }
+
+        let value = match &self.value {
+            FitValue::UInt(_) | FitValue::UIntArray(_) => *self.uint_values().first()? as f64,
+            FitValue::SInt(_) | FitValue::SIntArray(_) => *self.sint_values().first()? as f64,
+            FitValue::Float(_) | FitValue::FloatArray(_) => *self.float_values().first()?,
+            _ => return None
+        };
+        Some(self.apply_scale(value))
+    }
+
+    /// Same as scaled(), but for every element of an array field. Invalid elements are not filtered out.
+    pub fn scaled_array(&self) -> Vec<f64> {
+        match self.type_enum() {
+            FieldType::FieldTypeUInt => self.uint_values().iter().map(|value| self.apply_scale(*value as f64)).collect(),
+            FieldType::FieldTypeSInt => self.sint_values().iter().map(|value| self.apply_scale(*value as f64)).collect(),
+            FieldType::FieldTypeFloat => self.float_values().iter().map(|value| self.apply_scale(*value)).collect(),
+            _ => Vec::new()
+        }
+    }
+
+    fn apply_scale(&self, value: f64) -> f64 {
+        if let Some(description) = &self.developer_field {
+            return description.apply_scale(value);
+        }
+        match self.profile {
+            Some(profile) => profile.apply_scale(value),
+            None => value
+        }
+    }
+
+    /// Returns true if the field holds more than one value.
+    pub fn is_array(&self) -> bool {
+        matches!(self.value, FitValue::UIntArray(_) | FitValue::SIntArray(_) | FitValue::FloatArray(_) | FitValue::StrArray(_))
+    }
+
+    /// Appends a value to a field that already holds at least one value, turning it into an array.
+    fn append_value(&mut self, value: u64) {
+        self.value = match core::mem::take(&mut self.value) {
+            FitValue::SInt(first) => FitValue::SIntArray(vec![first, value as i64]),
+            FitValue::SIntArray(mut values) => { values.push(value as i64); FitValue::SIntArray(values) },
+            FitValue::Float(first) => FitValue::FloatArray(vec![first, value as f64]),
+            FitValue::FloatArray(mut values) => { values.push(value as f64); FitValue::FloatArray(values) },
+            FitValue::UIntArray(mut values) => { values.push(value); FitValue::UIntArray(values) },
+            FitValue::UInt(first) => FitValue::UIntArray(vec![first, value]),
+            _ => FitValue::UIntArray(vec![0, value])
+        };
+    }
+
+    /// Unsigned values of the field, a scalar field is treated as an array with one element.
+    fn uint_values(&self) -> &[u64] {
+        match &self.value {
+            FitValue::UInt(value) => core::slice::from_ref(value),
+            FitValue::UIntArray(values) => values,
+            _ => &[]
+        }
+    }
+
+    /// Signed values of the field, a scalar field is treated as an array with one element.
+    fn sint_values(&self) -> &[i64] {
+        match &self.value {
+            FitValue::SInt(value) => core::slice::from_ref(value),
+            FitValue::SIntArray(values) => values,
+            _ => &[]
+        }
+    }
+
+    /// Floating point values of the field, a scalar field is treated as an array with one element.
+    fn float_values(&self) -> &[f64] {
+        match &self.value {
+            FitValue::Float(value) => core::slice::from_ref(value),
+            FitValue::FloatArray(values) => values,
+            _ => &[]
+        }
+    }
+
+    /// Strings of the field, a field with a single string is treated as an array with one element.
+    fn string_values(&self) -> &[Cow<'a, str>] {
+        match &self.value {
+            FitValue::Str(value) => core::slice::from_ref(value),
+            FitValue::StrArray(values) => values,
+            _ => &[]
+        }
     }
 
     pub fn get_i8(&self) -> i8 {
-        return self.value_sint as i8;
+        self.get_i64() as i8
     }
 
     pub fn get_i16(&self) -> i16 {
-        return self.value_sint as i16;
+        self.get_i64() as i16
     }
 
     pub fn get_i32(&self) -> i32 {
-        return self.value_sint as i32;
+        self.get_i64() as i32
     }
 
+    /// Arrays return their first element.
     pub fn get_i64(&self) -> i64 {
-        return self.value_sint as i64;
+        self.sint_values().first().copied().unwrap_or(0)
     }
 
     pub fn get_u8(&self) -> u8 {
-        return self.value_uint as u8;
+        self.get_u64() as u8
     }
 
     pub fn get_u16(&self) -> u16 {
-        return self.value_uint as u16;
+        self.get_u64() as u16
     }
 
     pub fn get_u32(&self) -> u32 {
-        return self.value_uint as u32;
+        self.get_u64() as u32
     }
 
+    /// Arrays return their first element.
     pub fn get_u64(&self) -> u64 {
-        return self.value_uint as u64;
+        self.uint_values().first().copied().unwrap_or(0)
     }
 
     pub fn get_f32(&self) -> f32 {
-        return self.value_float as f32;
+        self.get_f64() as f32
     }
 
+    /// Arrays return their first element.
     pub fn get_f64(&self) -> f64 {
-        return self.value_float as f64;
+        self.float_values().first().copied().unwrap_or(0.0)
+    }
+
+    /// Returns the first string of a string field, or an empty string if the field isn't a string.
+    pub fn get_string(&self) -> &str {
+        self.string_values().first().map_or("", |value| value)
+    }
+
+    pub fn get_i8_array(&self) -> Vec<i8> {
+        self.sint_values().iter().map(|value| *value as i8).collect()
+    }
+
+    pub fn get_i16_array(&self) -> Vec<i16> {
+        self.sint_values().iter().map(|value| *value as i16).collect()
+    }
+
+    pub fn get_i32_array(&self) -> Vec<i32> {
+        self.sint_values().iter().map(|value| *value as i32).collect()
+    }
+
+    pub fn get_i64_array(&self) -> Vec<i64> {
+        self.sint_values().to_vec()
+    }
+
+    /// Byte fields are returned as-is.
+    pub fn get_u8_array(&self) -> Vec<u8> {
+        match &self.value {
+            FitValue::ByteArray(bytes) => bytes.to_vec(),
+            _ => self.uint_values().iter().map(|value| *value as u8).collect()
+        }
+    }
+
+    pub fn get_u16_array(&self) -> Vec<u16> {
+        self.uint_values().iter().map(|value| *value as u16).collect()
+    }
+
+    pub fn get_u32_array(&self) -> Vec<u32> {
+        self.uint_values().iter().map(|value| *value as u32).collect()
+    }
+
+    pub fn get_u64_array(&self) -> Vec<u64> {
+        self.uint_values().to_vec()
+    }
+
+    pub fn get_f32_array(&self) -> Vec<f32> {
+        self.float_values().iter().map(|value| *value as f32).collect()
+    }
+
+    pub fn get_f64_array(&self) -> Vec<f64> {
+        self.float_values().to_vec()
+    }
+
+    /// Every string of a string field, a field with a single string is treated as an array with one element.
+    pub fn get_string_array(&self) -> Vec<String> {
+        self.string_values().iter().map(|value| value.to_string()).collect()
     }
 }
 
@@ -2005,13 +3032,14 @@ impl FitFieldValue {
 pub struct FieldDefinition {
     pub field_def: u8, // Field definition number
     pub size: u8, // Number of bytes
-    pub base_type: u8, // Base type (from the SDK)
-    pub is_dev_field: bool // Set if this represents a developer defined field
+    pub base_type: u8, // Base type (from the SDK), developer fields get theirs from a field description message instead
+    pub is_dev_field: bool, // Set if this represents a developer defined field
+    pub developer_data_index: u8 // Developer fields only, identifies the application that defined the field
 }
 
 impl Ord for FieldDefinition {
     fn cmp(&self, other: &Self) -> Ordering {
-        (self.field_def, &self.size, &self.base_type, &self.is_dev_field).cmp(&(other.field_def, &other.size, &other.base_type, &other.is_dev_field))
+        (self.field_def, &self.size, &self.base_type, &self.is_dev_field, &self.developer_data_index).cmp(&(other.field_def, &other.size, &other.base_type, &other.is_dev_field, &other.developer_data_index))
     }
 }
 
@@ -2023,7 +3051,7 @@ impl PartialOrd for FieldDefinition {
 
 impl PartialEq for FieldDefinition {
     fn eq(&self, other: &Self) -> bool {
-        (self.field_def, &self.size, &self.is_dev_field) == (other.field_def, &other.size, &other.is_dev_field)
+        (self.field_def, &self.size, &self.is_dev_field, &self.developer_data_index) == (other.field_def, &other.size, &other.is_dev_field, &other.developer_data_index)
     }
 }
 
@@ -2031,61 +3059,394 @@ impl Eq for FieldDefinition { }
 
 pub type FieldDefinitionList = Vec<FieldDefinition>;
 
+/// Describes a developer defined field, as read from a field description message.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DeveloperFieldDescription {
+    pub developer_data_index: u8, // Identifies the application that defined the field
+    pub field_def: u8, // Field definition number, as used in definition messages
+    pub base_type: u8, // Base type (from the SDK)
+    pub name: String,
+    pub units: String,
+    pub scale: Option<u8>,
+    pub offset: Option<i8>,
+    pub application_id: Option<Vec<u8>> // From the developer data ID message with the same developer data index
+}
+
+impl DeveloperFieldDescription {
+    /// Converts a raw value into the field's units. A scale of zero is treated as no scale.
+    pub fn apply_scale(&self, value: f64) -> f64 {
+        value / self.scale.filter(|scale| *scale != 0).unwrap_or(1) as f64 - self.offset.unwrap_or(0) as f64
+    }
+}
+
+/// A definition message, describes the layout of the data messages that use the same local message type.
+#[derive(Clone, Debug, Default)]
+pub struct FitDefinitionMessage {
+    pub local_message_type: u8,
+    pub global_message_num: u16,
+    pub is_big_endian: bool,
+    pub segment: usize, // Index of the FIT file this message came from, when several are chained together
+    pub field_defs: FieldDefinitionList
+}
+
+/// A date_time value. These are normally seconds since UTC 00:00:00 Dec 31 1989, but values below
+/// FIT_DATE_TIME_MIN are relative to a time defined by the device, typically when it was powered on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FitDateTime {
+    System(u32), // Seconds relative to the device's own clock
+    Utc(u32) // Seconds since the FIT epoch
+}
+
+impl FitDateTime {
+    /// Interprets a date_time as it is stored in the file.
+    pub fn from_fit(value: u32) -> Self {
+        if value < FIT_DATE_TIME_MIN { FitDateTime::System(value) } else { FitDateTime::Utc(value) }
+    }
+
+    /// The value as it is stored in the file.
+    pub fn value(&self) -> u32 {
+        match self {
+            FitDateTime::System(value) | FitDateTime::Utc(value) => *value
+        }
+    }
+
+    pub fn is_utc(&self) -> bool {
+        matches!(self, FitDateTime::Utc(_))
+    }
+
+    /// Seconds since the UNIX epoch, or None for system time. This is a u64 as late FIT times don't fit in a u32 once the
+    /// epoch is moved back to 1970.
+    pub fn unix_timestamp(&self) -> Option<u64> {
+        match self {
+            FitDateTime::Utc(value) => Some(FIT_TIMESTAMP_OFFSET as u64 + *value as u64),
+            FitDateTime::System(_) => None
+        }
+    }
+
+    /// Converts to a SystemTime, or None for system time.
+    #[cfg(feature = "std")]
+    pub fn to_system_time(&self) -> Option<SystemTime> {
+        self.unix_timestamp().map(|timestamp| UNIX_EPOCH + Duration::from_secs(timestamp))
+    }
+
+    /// Offset of the local time zone from UTC, in seconds, given a local_date_time for the same moment, such as the
+    /// local_timestamp of an activity message. None if either time is system time.
+    pub fn local_offset_s(&self, local_timestamp: u32) -> Option<i32> {
+        match (self, FitDateTime::from_fit(local_timestamp)) {
+            (FitDateTime::Utc(utc), FitDateTime::Utc(local)) => Some(local.wrapping_sub(*utc) as i32),
+            _ => None
+        }
+    }
+}
+
+/// The message_index field. The low 12 bits are the index, the high bit marks the message as selected.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MessageIndex(pub u16);
+
+impl MessageIndex {
+    pub fn new(value: u16) -> Self {
+        MessageIndex(value)
+    }
+
+    /// The index of the message, without the selected and reserved bits.
+    pub fn index(&self) -> u16 {
+        self.0 & MESSAGE_INDEX_MASK
+    }
+
+    pub fn is_selected(&self) -> bool {
+        self.0 & MESSAGE_INDEX_SELECTED != 0
+    }
+
+    /// The raw value, as stored in the file.
+    pub fn value(&self) -> u16 {
+        self.0
+    }
+}
+
+impl From<u16> for MessageIndex {
+    fn from(value: u16) -> Self {
+        MessageIndex(value)
+    }
+}
+
+/// A data message, along with the values of each of its fields.
+#[derive(Clone, Debug, Default)]
+pub struct FitDataMessage<'a> {
+    pub timestamp: Option<FitDateTime>, // None if no timestamp has been seen yet
+    pub global_message_num: u16,
+    pub local_message_type: u8,
+    pub message_index: MessageIndex,
+    pub segment: usize, // Index of the FIT file this message came from, when several are chained together
+    pub fields: Vec<FitFieldValue<'a>>,
+    pub event_timestamps: Vec<f64> // UNIX timestamps of the events in an hr message, resolved from its event_timestamp fields. Seconds of system time if the hr message's timestamp was.
+}
+
+impl FitDataMessage<'_> {
+    /// Copies any borrowed strings or byte arrays, so the message no longer borrows from the data it was decoded from.
+    pub fn into_owned(self) -> FitDataMessage<'static> {
+        let fields = self.fields.into_iter().map(FitFieldValue::into_owned).collect();
+        FitDataMessage { timestamp: self.timestamp, global_message_num: self.global_message_num, local_message_type: self.local_message_type, message_index: self.message_index, segment: self.segment, fields, event_timestamps: self.event_timestamps }
+    }
+}
+
+/// A decoded record from a FIT file.
+#[derive(Clone, Debug)]
+pub enum FitMessage {
+    Definition(FitDefinitionMessage),
+    Data(FitDataMessage<'static>)
+}
+
+/// Receives messages as they are decoded. Override the methods for the messages you care about, everything else is ignored.
+/// Closures that take a &FitMessage can also be used as handlers.
+pub trait FitMessageHandler {
+    /// Called for every message. The default implementation dispatches to the more specific methods below.
+    fn on_message(&mut self, msg: &FitMessage) {
+        match msg {
+            FitMessage::Definition(def) => self.on_definition(def),
+            FitMessage::Data(data) => self.on_data(data)
+        }
+    }
+
+    /// Called for each definition message.
+    fn on_definition(&mut self, _msg: &FitDefinitionMessage) {}
+
+    /// Called for each data message. The default implementation decodes the messages we know about and dispatches them.
+    fn on_data(&mut self, msg: &FitDataMessage) {
+        match msg.global_message_num {
+            GLOBAL_MSG_NUM_FILE_ID => self.on_file_id(msg, &FitFileIdMsg::new(&msg.fields)),
+            GLOBAL_MSG_NUM_FILE_CREATOR => self.on_file_creator(msg, &FitFileCreatorMsg::new(&msg.fields)),
+            GLOBAL_MSG_NUM_ACTIVITY => self.on_activity(msg, &FitActivityMsg::new(&msg.fields)),
+            GLOBAL_MSG_NUM_SESSION => self.on_session(msg, &FitSessionMsg::new(&msg.fields)),
+            GLOBAL_MSG_NUM_LAP => self.on_lap(msg, &FitLapMsg::new(&msg.fields)),
+            GLOBAL_MSG_NUM_RECORD => self.on_record(msg, &FitRecordMsg::new(&msg.fields)),
+            GLOBAL_MSG_NUM_EVENT => self.on_event(msg, &FitEventMsg::new(&msg.fields)),
+            GLOBAL_MSG_NUM_DEVICE_INFO => self.on_device_info(msg, &FitDeviceInfoMsg::new(&msg.fields)),
+            GLOBAL_MSG_NUM_WORKOUT => self.on_workout(msg, &FitWorkoutMsg::new(&msg.fields)),
+            GLOBAL_MSG_NUM_WORKOUT_STEP => self.on_workout_step(msg, &FitWorkoutStepMsg::new(msg.message_index, &msg.fields)),
+            GLOBAL_MSG_NUM_LENGTH => self.on_length(msg, &FitLengthMsg::new(&msg.fields)),
+            GLOBAL_MSG_NUM_MEMO_GLOB => self.on_memo_glob(msg, &FitMemoGlobMsg::new(&msg.fields)),
+            _ => self.on_other(msg)
+        }
+    }
+
+    fn on_file_id(&mut self, _msg: &FitDataMessage, _file_id: &FitFileIdMsg) {}
+    fn on_file_creator(&mut self, _msg: &FitDataMessage, _file_creator: &FitFileCreatorMsg) {}
+    fn on_activity(&mut self, _msg: &FitDataMessage, _activity: &FitActivityMsg) {}
+    fn on_session(&mut self, _msg: &FitDataMessage, _session: &FitSessionMsg) {}
+    fn on_lap(&mut self, _msg: &FitDataMessage, _lap: &FitLapMsg) {}
+    fn on_record(&mut self, _msg: &FitDataMessage, _record: &FitRecordMsg) {}
+    fn on_event(&mut self, _msg: &FitDataMessage, _event: &FitEventMsg) {}
+    fn on_device_info(&mut self, _msg: &FitDataMessage, _device_info: &FitDeviceInfoMsg) {}
+    fn on_workout(&mut self, _msg: &FitDataMessage, _workout: &FitWorkoutMsg) {}
+    fn on_workout_step(&mut self, _msg: &FitDataMessage, _workout_step: &FitWorkoutStepMsg) {}
+    fn on_length(&mut self, _msg: &FitDataMessage, _length: &FitLengthMsg) {}
+    fn on_memo_glob(&mut self, _msg: &FitDataMessage, _memo_glob: &FitMemoGlobMsg) {}
+
+    /// Called for data messages that don't have a more specific method.
+    fn on_other(&mut self, _msg: &FitDataMessage) {}
+}
+
+impl<F: FnMut(&FitMessage)> FitMessageHandler for F {
+    fn on_message(&mut self, msg: &FitMessage) {
+        self(msg)
+    }
+}
+
+/// Receives the messages decoded by Fit::read_slice_ref(). The same FitDataMessage is reused for each data message, but
+/// the strings and byte arrays of its fields borrow from the slice being read, so they can be kept for as long as the slice.
+/// Closures that take a &FitDataMessage can also be used as handlers, definition messages are then ignored.
+pub trait FitMessageRefHandler<'a> {
+    /// Called for each definition message.
+    fn on_definition(&mut self, _msg: &FitDefinitionMessage) {}
+
+    /// Called for each data message.
+    fn on_data(&mut self, msg: &FitDataMessage<'a>);
+}
+
+impl<'a, F: FnMut(&FitDataMessage<'a>)> FitMessageRefHandler<'a> for F {
+    fn on_data(&mut self, msg: &FitDataMessage<'a>) {
+        self(msg)
+    }
+}
+
+/// Running total of a field that devices write as a rolling counter, such as accumulated power.
+#[derive(Clone, Copy, Debug, Default)]
+struct FieldAccumulator {
+    last_value: u64, // Last value of the rolling counter
+    accumulated_value: u64 // Running total
+}
+
+impl FieldAccumulator {
+    /// Adds the change in the rolling counter, which wraps around after the given number of bits, to the running total.
+    fn accumulate(&mut self, value: u64, bits: u8) -> u64 {
+        let mask = if bits >= 64 { u64::MAX } else { (1 << bits) - 1 };
+        self.accumulated_value = self.accumulated_value.wrapping_add(value.wrapping_sub(self.last_value) & mask);
+        self.last_value = value;
+        self.accumulated_value
+    }
+
+    /// Restarts the running total from a value that was written in full.
+    fn set(&mut self, value: u64) {
+        self.last_value = value;
+        self.accumulated_value = value;
+    }
+}
+
+/// Running totals of accumulated fields, key is the global message number and field number. Files only have a handful,
+/// so a list is quicker to search than a hash map.
+#[derive(Debug, Default)]
+struct FieldAccumulators(Vec<((u16, u8), FieldAccumulator)>);
+
+impl FieldAccumulators {
+    /// Returns the running total of the given field, starting a new one if there isn't one yet.
+    fn get(&mut self, key: (u16, u8)) -> &mut FieldAccumulator {
+        let index = match self.0.iter().position(|(other, _)| *other == key) {
+            Some(index) => index,
+            None => {
+                self.0.push((key, FieldAccumulator::default()));
+                self.0.len() - 1
+            }
+        };
+        &mut self.0[index].1
+    }
+}
+
+/// A definition message, along with what can be worked out from it ahead of time, so that the data messages that use it
+/// can be decoded without looking anything up in the profile.
+#[derive(Debug)]
+struct LocalMessageDefinition {
+    global_msg_num: u16,
+    is_big_endian: bool,
+    field_defs: FieldDefinitionList,
+    message_size: usize, // Number of bytes in each data message, not counting the record header
+    msg_profile: Option<&'static fit_profile::MessageProfile>,
+    field_profiles: Vec<Option<&'static fit_profile::FieldProfile>>, // Profile of each field, None for developer fields and fields the profile doesn't list
+    accumulated: Vec<bool>, // Set for each field that holds the full value of an accumulated component
+    num_components: usize, // Number of fields that may be unpacked from the components of other fields
+    timestamp_16_field: Option<usize>, // Index of the timestamp_16 field, if the message has one
+    has_event_timestamp: bool // Set if the message may have event_timestamp fields that need resolving
+}
+
+impl LocalMessageDefinition {
+    fn new(global_msg_num: u16, is_big_endian: bool, field_defs: FieldDefinitionList) -> Self {
+        let msg_profile = fit_profile::find_message(global_msg_num);
+        let field_profiles: Vec<_> = field_defs.iter().map(|def| if def.is_dev_field { None } else { msg_profile.and_then(|msg_profile| msg_profile.find_field(def.field_def)) }).collect();
+        let accumulated = field_defs.iter().map(|def| !def.is_dev_field && msg_profile.is_some_and(|msg_profile| msg_profile.is_accumulated(def.field_def))).collect();
+        let timestamp_16_field = field_profiles.iter().position(|profile| profile.is_some_and(|profile| profile.name == "timestamp_16"));
+        let num_components = field_profiles.iter().flatten().map(|profile| profile.components.len() + profile.subfields.iter().map(|subfield| subfield.field.components.len()).sum::<usize>()).sum();
+        let has_event_timestamp = msg_profile.is_some_and(|msg_profile| msg_profile.fields.iter().any(|field| field.name == "event_timestamp"));
+        let message_size = field_defs.iter().map(|def| def.size as usize).sum();
+
+        LocalMessageDefinition{ global_msg_num, is_big_endian, field_defs, message_size, msg_profile, field_profiles, accumulated, num_components, timestamp_16_field, has_event_timestamp }
+    }
+}
+
 /// Contains everything we need to remember about the state of the file parsing operation.
 #[derive(Debug, Default)]
 struct FitState {
-    endianness_map: HashMap<u8, bool>, // true = messages of the given local message type are in big endian format
-    global_msg_map: HashMap<u8, u16>, // Associates local message types with global message numbers
-    field_defs: HashMap<u8, FieldDefinitionList>, // Describes the format of local messages, key is the local message type
+    definitions: [Option<Arc<LocalMessageDefinition>>; 16], // Describes the format of local messages, indexed by the local message type
     timestamp: u32, // Current timestamp, listed here as it may be updated by a compressed timestamp header
-    bytes_read: u64 // Number of bytes read so far
+    bytes_read: u64, // Number of bytes read so far
+    segment: usize, // Index of the FIT file being read, when several are chained together
+    segment_offset: u64, // Offset of the start of the FIT file being read, used when reporting errors
+    accumulators: FieldAccumulators, // Running totals of accumulated fields
+    application_ids: BTreeMap<u8, Option<Vec<u8>>>, // From developer data ID messages, key is the developer data index
+    dev_field_descriptions: BTreeMap<(u8, u8), Arc<DeveloperFieldDescription>>, // From field description messages, key is the developer data index and field number
+    event_timestamp_anchor: Option<(f64, f64)> // Timestamp, as a UNIX timestamp or in system time, and event_timestamp, in seconds, of the last hr message that had both
 }
 
 impl FitState {
     pub fn new() -> Self {
-        let state = FitState{ endianness_map: HashMap::<u8, bool>::new(), global_msg_map: HashMap::<u8, u16>::new(), field_defs: HashMap::<u8, FieldDefinitionList>::new(), timestamp: 0, bytes_read: 0 };
-        state
+        FitState{ definitions: Default::default(), timestamp: 0, bytes_read: 0, segment: 0, segment_offset: 0, accumulators: FieldAccumulators::default(), application_ids: BTreeMap::new(), dev_field_descriptions: BTreeMap::new(), event_timestamp_anchor: None }
     }
 
-    /// For debugging purposes.
-    fn print(&self) {
-        println!("----------------------------------------");
+    /// A timestamp_16 field holds the low 16 bits of the timestamp, the rest come from the last full timestamp.
+    fn expand_timestamp_16(&self, timestamp_16: u16) -> u32 {
+        self.timestamp.wrapping_add((timestamp_16 as u32).wrapping_sub(self.timestamp) & 0xFFFF)
+    }
 
-        for (local_msg_type, local_msg_def) in &self.field_defs {
-            println!("Local Msg Type {}:", local_msg_type);
-            for field_def in local_msg_def.iter() {
-                println!("   Field Def {} Size {} Base Type {:#x}", field_def.field_def, field_def.size, field_def.base_type);
-            }
+    /// The event_timestamp field of an hr message counts from an arbitrary point. A message that has both a timestamp
+    /// and an event_timestamp ties the two together.
+    fn anchor_event_timestamps(&mut self, fields: &[FitFieldValue], timestamp: u32) {
+        let event_timestamp = fields.iter().find(|field| is_profile_field(field, "event_timestamp")).and_then(|field| field.scaled());
+        if let Some(event_timestamp) = event_timestamp {
+            let fractional_timestamp = fields.iter().find(|field| is_profile_field(field, "fractional_timestamp")).and_then(|field| field.scaled()).unwrap_or(0.0);
+            let epoch = if FitDateTime::from_fit(timestamp).is_utc() { FIT_TIMESTAMP_OFFSET as f64 } else { 0.0 };
+            self.event_timestamp_anchor = Some((epoch + timestamp as f64 + fractional_timestamp, event_timestamp));
         }
+    }
 
-        println!("----------------------------------------");
+    /// Converts the event_timestamp values, including those unpacked from event_timestamp_12, into UNIX timestamps. If the
+    /// anchoring timestamp was system time then so are the results.
+    fn resolve_event_timestamps(&self, fields: &[FitFieldValue], event_timestamps: &mut Vec<f64>) {
+        let field = fields.iter().find(|field| is_profile_field(field, "event_timestamp"));
+        if let (Some((timestamp, event_timestamp)), Some(field)) = (self.event_timestamp_anchor, field) {
+            let start = event_timestamps.len();
+            event_timestamps.extend(field.scaled_array());
+            event_timestamps[start..].iter_mut().for_each(|value| *value = timestamp + *value - event_timestamp);
+        }
     }
 
-    /// Adds the given global message/local message combo to the hash map.
-    fn insert_local_msg_def(&mut self, local_msg_type: u8, is_big_endian: bool, global_msg_num: u16, field_defs: FieldDefinitionList) {
+    /// Offset of the next unread byte, relative to the start of the stream.
+    fn offset(&self) -> u64 {
+        self.segment_offset + self.bytes_read
+    }
 
-        // Update the endianness map.
-        if self.endianness_map.contains_key(&local_msg_type) {
-            self.endianness_map.remove(&local_msg_type);
+    /// Remembers the application described by a developer data ID message. Field descriptions that were
+    /// registered under the same developer data index no longer apply.
+    fn insert_developer_data_id(&mut self, fields: &[FitFieldValue]) {
+        let mut developer_data_index = None;
+        let mut application_id = None;
+
+        for field in fields.iter().filter(|field| !field.is_dev_field && !field.is_invalid) {
+            match field.field_def {
+                1 => application_id = Some(field.get_u8_array()),
+                3 => developer_data_index = Some(field.get_u8()),
+                _ => {}
+            }
         }
-        self.endianness_map.insert(local_msg_type, is_big_endian);
 
-        // Update the global message number association.
-        if self.global_msg_map.contains_key(&local_msg_type) {
-            self.global_msg_map.remove(&local_msg_type);
+        if let Some(developer_data_index) = developer_data_index {
+            self.dev_field_descriptions.retain(|(index, _), _| *index != developer_data_index);
+            self.application_ids.insert(developer_data_index, application_id);
         }
-        self.global_msg_map.insert(local_msg_type, global_msg_num);
+    }
 
-        // Update the message definition.
-        if self.field_defs.contains_key(&local_msg_type) {
-            self.field_defs.remove(&local_msg_type);
+    /// Registers the developer field described by a field description message.
+    fn insert_field_description(&mut self, fields: &[FitFieldValue]) {
+        let mut description = DeveloperFieldDescription::default();
+        let mut has_index = false;
+        let mut has_field_def = false;
+        let mut has_base_type = false;
+
+        for field in fields.iter().filter(|field| !field.is_dev_field && !field.is_invalid) {
+            match field.field_def {
+                0 => { description.developer_data_index = field.get_u8(); has_index = true; },
+                1 => { description.field_def = field.get_u8(); has_field_def = true; },
+                2 => { description.base_type = field.get_u8(); has_base_type = true; },
+                3 => description.name = field.get_string().to_string(),
+                6 => description.scale = Some(field.get_u8()).filter(|scale| *scale != 0 && *scale != 0xFF), // Zero would divide by zero, treat it as no scale
+                7 => description.offset = Some(field.get_i8()),
+                8 => description.units = field.get_string().to_string(),
+                _ => {}
+            }
+        }
+
+        if has_index && has_field_def && has_base_type {
+            description.application_id = self.application_ids.get(&description.developer_data_index).cloned().flatten();
+            self.dev_field_descriptions.insert((description.developer_data_index, description.field_def), Arc::new(description));
         }
-        self.field_defs.insert(local_msg_type, field_defs);
+    }
+
+    /// Associates the given definition with the local message type, replacing any earlier definition.
+    fn insert_local_msg_def(&mut self, local_msg_type: u8, is_big_endian: bool, global_msg_num: u16, field_defs: FieldDefinitionList) {
+        let definition = LocalMessageDefinition::new(global_msg_num, is_big_endian, field_defs);
+        self.definitions[(local_msg_type & RECORD_HDR_LOCAL_MSG_TYPE) as usize] = Some(Arc::new(definition));
     }
 }
 
 /// Parses and validates the FIT file header.
-#[derive(Debug, Default)]
+#[derive(Clone, Debug, Default)]
 pub struct FitHeader {
     pub header: Vec<u8>,
     pub header_buf2: [u8; 2], // Additional information introduced with the 14 byte header
@@ -2094,12 +3455,11 @@ pub struct FitHeader {
 
 impl FitHeader {
     pub fn new() -> Self {
-        let header = FitHeader{ header: Vec::new(), header_buf2: [0u8; 2], header_len: 0 };
-        header
+        FitHeader{ header: Vec::new(), header_buf2: [0u8; 2], header_len: 0 }
     }
 
     /// Reads the FIT File Header from the buffer.
-    pub fn read<R: Read>(&mut self, reader: &mut BufReader<R>) -> Result<()> {
+    pub fn read<R: Read>(&mut self, reader: &mut R) -> io::Result<()> {
 
         // Reads first 12 bytes of the header (12 bytes is the minimum header size for a valid FIT file).
         self.header = read_n(reader, 12)?;
@@ -2108,9 +3468,9 @@ impl FitHeader {
         // Does this file use the newer, 14 byte header?
         if self.header[HEADER_FILE_SIZE_OFFSET] == 14 {
             let mut additional_bytes = read_n(reader, 2)?;
-
+            self.header_buf2.copy_from_slice(&additional_bytes);
             self.header.append(&mut additional_bytes);
-            self.header_len = self.header_len + 2;
+            self.header_len += 2;
         }
 
         Ok(())
@@ -2118,30 +3478,111 @@ impl FitHeader {
 
     /// Validates the FIT File Header. Call after calling read().
     pub fn validate(&self) -> bool {
-        let mut valid  = self.header[HEADER_DATA_TYPE_0_OFFSET] == '.' as u8;
-        valid = valid && self.header[HEADER_DATA_TYPE_1_OFFSET] == 'F' as u8;
-        valid = valid && self.header[HEADER_DATA_TYPE_2_OFFSET] == 'I' as u8;
-        valid = valid && self.header[HEADER_DATA_TYPE_3_OFFSET] == 'T' as u8;
+        let mut valid  = self.header[HEADER_DATA_TYPE_0_OFFSET] == b'.';
+        valid = valid && self.header[HEADER_DATA_TYPE_1_OFFSET] == b'F';
+        valid = valid && self.header[HEADER_DATA_TYPE_2_OFFSET] == b'I';
+        valid = valid && self.header[HEADER_DATA_TYPE_3_OFFSET] == b'T';
         valid
     }
 
+    /// Validates the CRC of the 14 byte header. Call after calling read().
+    /// Headers without a CRC (12 byte headers, or a CRC of zero) are considered valid.
+    pub fn validate_crc(&self) -> bool {
+        match self.crc() {
+            Some(expected_crc) => self.compute_crc() == expected_crc,
+            None => true
+        }
+    }
+
+    /// Computes the CRC of the first 12 bytes of the header.
+    pub fn compute_crc(&self) -> u16 {
+        let mut crc: u16 = 0;
+        for byte in self.header.iter().take(HEADER_CRC_1_OFFSET) {
+            crc = check_crc(crc, *byte);
+        }
+        crc
+    }
+
+    /// Returns the CRC stored in the FIT File Header, if there is one.
+    pub fn crc(&self) -> Option<u16> {
+        if self.header_len < 14 {
+            return None;
+        }
+
+        let crc = byte_array_to_uint16(&self.header_buf2, false);
+        if crc == 0 {
+            return None;
+        }
+        Some(crc)
+    }
+
     /// Prints the raw bytes comprising the FIT File Header.
+    #[cfg(feature = "std")]
     pub fn print(&self) {
         for byte in self.header.iter() {
             print!("{:#04x} ", byte);
         }
     }
 
-    /// Calculates and returns the data size from the FIT File Header.
-    pub fn data_size(&self) -> u32 {
-        let mut data_size = self.header[HEADER_DATA_SIZE_LSB_OFFSET] as u32;
-        data_size = data_size | (self.header[HEADER_DATA_SIZE_1_OFFSET] as u32) << 8;
-        data_size = data_size | (self.header[HEADER_DATA_SIZE_2_OFFSET] as u32) << 16;
-        data_size = data_size | (self.header[HEADER_DATA_SIZE_MSB_OFFSET] as u32) << 24;
-        data_size
+    /// Calculates and returns the data size from the FIT File Header.
+    pub fn data_size(&self) -> u32 {
+        let mut data_size = self.header[HEADER_DATA_SIZE_LSB_OFFSET] as u32;
+        data_size |= (self.header[HEADER_DATA_SIZE_1_OFFSET] as u32) << 8;
+        data_size |= (self.header[HEADER_DATA_SIZE_2_OFFSET] as u32) << 16;
+        data_size |= (self.header[HEADER_DATA_SIZE_MSB_OFFSET] as u32) << 24;
+        data_size
+    }
+}
+
+/// Where records are decoded from. A reader has to copy each record into a buffer, a slice is decoded in place.
+trait RecordSource {
+    /// Returns the next num_bytes bytes, or an UnexpectedEof error if there aren't that many.
+    fn take(&mut self, num_bytes: usize) -> io::Result<&[u8]>;
+}
+
+impl RecordSource for &[u8] {
+    fn take(&mut self, num_bytes: usize) -> io::Result<&[u8]> {
+        take_bytes(self, num_bytes)
+    }
+}
+
+/// Same as RecordSource::take(), but the bytes borrow from the data rather than from the slice that refers to it.
+fn take_bytes<'a>(data: &mut &'a [u8], num_bytes: usize) -> io::Result<&'a [u8]> {
+    if data.len() < num_bytes {
+        *data = &data[data.len()..];
+        return Err(Error::new(io::ErrorKind::UnexpectedEof, "Failed to read the required number of bytes."));
+    }
+    let (bytes, rest) = data.split_at(num_bytes);
+    *data = rest;
+    Ok(bytes)
+}
+
+/// Reads records from a reader, reusing the same buffer for each one.
+struct ReaderSource<'a, R: Read> {
+    reader: &'a mut R,
+    buffer: &'a mut Vec<u8>
+}
+
+impl<'a, R: Read> ReaderSource<'a, R> {
+    fn new(reader: &'a mut R, buffer: &'a mut Vec<u8>) -> Self {
+        ReaderSource{ reader, buffer }
+    }
+}
+
+impl<R: Read> RecordSource for ReaderSource<'_, R> {
+    fn take(&mut self, num_bytes: usize) -> io::Result<&[u8]> {
+        self.buffer.resize(num_bytes, 0);
+        self.reader.read_exact(self.buffer)?;
+        Ok(self.buffer)
     }
 }
 
+/// What FitRecord::read_header() found at the start of a record.
+enum FitRecordKind {
+    Definition(FitDefinitionMessage),
+    Data(Arc<LocalMessageDefinition>) // The data message that follows is decoded with this definition
+}
+
 /// Parses FIT file records.
 #[derive(Debug, Default)]
 struct FitRecord {
@@ -2150,12 +3591,11 @@ struct FitRecord {
 
 impl FitRecord {
     pub fn new() -> Self {
-        let rec = FitRecord{ header_byte: 0 };
-        rec
+        FitRecord{ header_byte: 0 }
     }
 
     /// Assumes the buffer is pointing to the beginning of the definition message, reads the message, and updates the field definitions.
-    fn read_definition_message<R: Read>(&mut self, reader: &mut BufReader<R>, state: &mut FitState) -> Result<()> {
+    fn read_definition_message<S: RecordSource>(&mut self, source: &mut S, state: &mut FitState) -> Result<FitDefinitionMessage> {
         // Local message type.
         let local_msg_type = self.header_byte & RECORD_HDR_LOCAL_MSG_TYPE;
 
@@ -2164,170 +3604,220 @@ impl FitRecord {
         // 1: Architecture
         // 2-3: Global Message Number
         // 4: Number of Fields
-        let mut definition_header: [u8; 5] = [0; 5];
-        reader.read_exact(&mut definition_header)?;
-        state.bytes_read = state.bytes_read + 5;
+        let definition_header: [u8; 5] = source.take(5)?.try_into().unwrap();
+        state.bytes_read += 5;
 
         // Make a note of the Architecture and Global Message Number.
         let is_big_endian = definition_header[DEF_MSG_ARCHITECTURE] == 1;
-        let global_msg_num = byte_array_to_uint16(definition_header[DEF_MSG_GLOBAL_MSG_NUM..(DEF_MSG_GLOBAL_MSG_NUM + 2)].to_vec(), is_big_endian);
+        let global_msg_num = byte_array_to_uint16(&definition_header[DEF_MSG_GLOBAL_MSG_NUM..(DEF_MSG_GLOBAL_MSG_NUM + 2)], is_big_endian);
 
         // Read each field.
         let mut field_defs: FieldDefinitionList = FieldDefinitionList::new();
-        let num_fields = definition_header[DEF_MSG_NUM_FIELDS];
-        for _i in 0..num_fields {
-
-            // Read the field definition (3 bytes).
-            let field_num = read_byte(reader)?;
-            let field_bytes = read_byte(reader)?;
-            let base_type = read_byte(reader)?;
-            state.bytes_read = state.bytes_read + 3;
+        let num_fields = definition_header[DEF_MSG_NUM_FIELDS] as usize;
+        for field_bytes in source.take(3 * num_fields)?.chunks_exact(3) {
 
-            // Add the definition.
-            let field_def = FieldDefinition { field_def:field_num, size:field_bytes, base_type:base_type, is_dev_field:false };
+            // Each field definition is 3 bytes: field number, size and base type.
+            let field_def = FieldDefinition { field_def:field_bytes[0], size:field_bytes[1], base_type:field_bytes[2], is_dev_field:false, developer_data_index:0 };
             field_defs.push(field_def);
         }
+        state.bytes_read += 3 * num_fields as u64;
 
         // Is there any developer information in this record?
         if self.header_byte & RECORD_HDR_MSG_TYPE_SPECIFIC != 0 {
 
             // Read the number of developer fields (1 byte).
-            let num_dev_fields = read_byte(reader)?;
-
-            // Read each developer field.
-            for _i in 0..num_dev_fields {
-
-                // Read the field definition (3 bytes).
-                let field_num = read_byte(reader)?;
-                let field_bytes = read_byte(reader)?;
-                let base_type = read_byte(reader)?;
-                state.bytes_read = state.bytes_read + 3;
+            let num_dev_fields = source.take(1)?[0] as usize;
+            state.bytes_read += 1;
 
-                // Add the definition.
-                let field_def = FieldDefinition { field_def:field_num, size:field_bytes, base_type:base_type, is_dev_field:true };
+            // Read each developer field. The last byte of each field definition is the developer data index, not the base type.
+            for field_bytes in source.take(3 * num_dev_fields)?.chunks_exact(3) {
+                let field_def = FieldDefinition { field_def:field_bytes[0], size:field_bytes[1], base_type:0, is_dev_field:true, developer_data_index:field_bytes[2] };
                 field_defs.push(field_def);
             }
+            state.bytes_read += 3 * num_dev_fields as u64;
         }
 
         // Associate the field definitions with the local message type.
+        let msg = FitDefinitionMessage { local_message_type: local_msg_type, global_message_num: global_msg_num, is_big_endian, segment: state.segment, field_defs: field_defs.clone() };
         state.insert_local_msg_def(local_msg_type, is_big_endian, global_msg_num, field_defs);
 
-        Ok(())
+        Ok(msg)
     }
 
-    /// Assumes the buffer is pointing to the beginning of the data message, reads the message.
-    fn read_data_message<C, R: Read>(&mut self, reader: &mut BufReader<R>, state: &mut FitState, callback: Callback<C>, context: &mut C) -> Result<()> {
+    /// Returns the definition of the data message that follows the record header.
+    fn data_definition(&self, state: &FitState) -> Result<Arc<LocalMessageDefinition>> {
+        let local_msg_type = self.local_msg_type();
 
-        // Local message type. The local message type is stored differently for compressed data headers.
-        let local_msg_type;
-        if self.header_byte & RECORD_HDR_NORMAL != 0 {
-            local_msg_type = (self.header_byte & RECORD_HDR_LOCAL_MSG_TYPE_COMPRESSED) >> 5;
-        }
-        else {
-            local_msg_type = self.header_byte & RECORD_HDR_LOCAL_MSG_TYPE;
+        match &state.definitions[local_msg_type as usize] {
+            Some(definition) => Ok(definition.clone()),
+            None => Err(FitError::MissingDefinition { offset: state.offset(), local_msg_type })
         }
+    }
 
-        // Do we know about this message type?
-        if !state.endianness_map.contains_key(&local_msg_type) {
-            let e = Error::new(std::io::ErrorKind::NotFound, "Field definition not found.");
-            return Err(e);
-        }
-        if !state.global_msg_map.contains_key(&local_msg_type) {
-            let e = Error::new(std::io::ErrorKind::NotFound, "Field definition not found.");
-            return Err(e);
-        }
-        if !state.field_defs.contains_key(&local_msg_type) {
-            let e = Error::new(std::io::ErrorKind::NotFound, "Field definition not found.");
-            return Err(e);
-        }
+    /// Decodes the data message, which has already been taken from the source, into msg. Strings and byte arrays are
+    /// passed through to_value, which either keeps them borrowed from message_data or copies them. The fields of msg are
+    /// cleared first, so that the same message can be reused for each record.
+    fn read_data_message<'a, 'b>(&self, message_data: &'b [u8], definition: &LocalMessageDefinition, state: &mut FitState, msg: &mut FitDataMessage<'a>, to_value: impl Fn(FitValue<'b>) -> FitValue<'a>) -> Result<()> {
+
+        // Local message type. The local message type is stored differently for compressed data headers.
+        let local_msg_type = self.local_msg_type();
 
         // The timestamp may get updated.
         let mut new_timestamp = state.timestamp;
 
-        // Retrieve the field definitions based on the message type.
-        let is_big_endian = *state.endianness_map.get(&local_msg_type).unwrap();
-        let global_msg_num = *state.global_msg_map.get(&local_msg_type).unwrap();
-        let field_defs = state.field_defs.get(&local_msg_type).unwrap();
-
-        // Read data for each message definition.
-        let mut fields = Vec::new();
-        let mut message_index: u16 = 0;
+        let is_big_endian = definition.is_big_endian;
+        let global_msg_num = definition.global_msg_num;
+        let msg_profile = definition.msg_profile;
+
+        // Decode each field in place.
+        let fields = &mut msg.fields;
+        fields.clear();
+        fields.reserve(definition.field_defs.len() + 1 + definition.num_components);
+        let mut message_index = MessageIndex::default();
+        let mut has_timestamp_field = false;
+        let mut has_valid_timestamp = false;
         let mut bytes_read = 0;
-        for def in field_defs.iter() {
+        for (def, profile) in definition.field_defs.iter().zip(&definition.field_profiles) {
 
             let mut field = FitFieldValue::new();
             field.field_def = def.field_def;
             field.is_dev_field = def.is_dev_field;
+            field.profile = *profile;
 
-            // Read the number of bytes prescribed by the field definition.
-            let data = read_n(reader, def.size as u64)?;
-            let num_bytes_read = data.len();
-            if num_bytes_read != def.size as usize {
-                let e = Error::new(std::io::ErrorKind::InvalidData, "Failed to read the required number of bytes.");
-                return Err(e);
-            }
-            bytes_read = bytes_read + num_bytes_read as u64;
+            // Each field is the number of bytes prescribed by the field definition.
+            let data = &message_data[bytes_read..bytes_read + def.size as usize];
+            bytes_read += data.len();
 
-            // Is this a special field, like a timestamp?
-            if def.field_def == FIELD_MSG_INDEX {
-                message_index = byte_array_to_sint16(data, is_big_endian) as u16;
+            // Is this a special field, like a timestamp? These are also passed on with the other fields.
+            if !def.is_dev_field && def.field_def == FIELD_MSG_INDEX {
+                message_index = MessageIndex(byte_array_to_uint16(data, is_big_endian));
             }
-            else if def.field_def == FIELD_TIMESTAMP {
-                new_timestamp = byte_array_to_uint32(data, is_big_endian);
+            else if !def.is_dev_field && def.field_def == FIELD_TIMESTAMP {
+                // An invalid timestamp leaves the current timestamp as it was.
+                let timestamp = byte_array_to_uint32(data, is_big_endian);
+                has_valid_timestamp = timestamp as u64 != base_type_invalid_value(0x86);
+                if has_valid_timestamp {
+                    new_timestamp = timestamp;
+                }
+                has_timestamp_field = true;
             }
-            else if def.field_def == FIELD_PART_INDEX {
-                panic!("Part Index not implemented: Local Message Type: {}.", local_msg_type);
+
+            // Developer fields are described by an earlier field description message. Without one, the raw bytes are kept.
+            let mut base_type = def.base_type;
+            if def.is_dev_field {
+                field.developer_field = state.dev_field_descriptions.get(&(def.developer_data_index, def.field_def)).cloned();
+                base_type = field.developer_field.as_ref().map_or(0x0D, |description| description.base_type);
             }
 
-            // Normal field.
-            else {
-                match def.base_type {
-                    0x00 => { field.value_uint = byte_array_to_uint8(data) as u64; field.base_type = def.base_type; field.type_enum = FieldType::FieldTypeUInt; },
-                    0x01 => { field.value_sint = byte_array_to_sint8(data) as i64; field.base_type = def.base_type; field.type_enum = FieldType::FieldTypeSInt; },
-                    0x02 => { field.value_uint = byte_array_to_uint8(data) as u64; field.base_type = def.base_type; field.type_enum = FieldType::FieldTypeUInt; },
-                    0x83 => { field.value_sint = byte_array_to_sint16(data, is_big_endian) as i64; field.base_type = def.base_type; field.type_enum = FieldType::FieldTypeSInt; },
-                    0x84 => { field.value_uint = byte_array_to_uint16(data, is_big_endian) as u64; field.base_type = def.base_type; field.type_enum = FieldType::FieldTypeUInt; },
-                    0x85 => { field.value_sint = byte_array_to_sint32(data, is_big_endian) as i64; field.base_type = def.base_type; field.type_enum = FieldType::FieldTypeSInt; },
-                    0x86 => { field.value_uint = byte_array_to_uint32(data, is_big_endian) as u64; field.base_type = def.base_type; field.type_enum = FieldType::FieldTypeUInt; },
-                    0x07 => { field.value_string = byte_array_to_string(data, def.size as usize); field.base_type = def.base_type; field.type_enum = FieldType::FieldTypeStr; },
-                    0x88 => { field.value_float = byte_array_to_float(data, 4, is_big_endian); field.base_type = def.base_type; field.type_enum = FieldType::FieldTypeFloat; },
-                    0x89 => { field.value_float = byte_array_to_float(data, 8, is_big_endian); field.base_type = def.base_type; field.type_enum = FieldType::FieldTypeFloat; },
-                    0x0A => { field.value_uint = byte_array_to_uint8(data) as u64; field.base_type = def.base_type; field.type_enum = FieldType::FieldTypeUInt; },
-                    0x8B => { field.value_uint = byte_array_to_uint16(data, is_big_endian) as u64; field.base_type = def.base_type; field.type_enum = FieldType::FieldTypeUInt; },
-                    0x8C => { field.value_uint = byte_array_to_uint32(data, is_big_endian) as u64; field.base_type = def.base_type; field.type_enum = FieldType::FieldTypeUInt; },
-                    0x0D => { field.value_byte_array = data; field.base_type = def.base_type; field.type_enum = FieldType::FieldTypeByteArray; },
-                    0x8E => { field.value_sint = byte_array_to_sint64(data, is_big_endian) as i64; field.base_type = def.base_type; field.type_enum = FieldType::FieldTypeSInt; },
-                    0x8F => { field.value_uint = byte_array_to_uint64(data, is_big_endian) as u64; field.base_type = def.base_type; field.type_enum = FieldType::FieldTypeUInt; },
-                    0x90 => { field.value_uint = byte_array_to_uint64(data, is_big_endian) as u64; field.base_type = def.base_type; field.type_enum = FieldType::FieldTypeUInt; },
-                    _ => { if !def.is_dev_field {
-                            panic!("Base type {:#x} not implemented for field {:#x} and local message type {}. Bytes read so far {:#x}.", def.base_type, def.field_def, local_msg_type, state.bytes_read + bytes_read as u64);
-                        }
+            // Arrays: the definition size is a multiple of the base type size. Strings and byte arrays are handled below.
+            let width = base_type_size(base_type);
+            let is_array = base_type != 0x07 && base_type != 0x0D && data.len() >= 2 * width;
+
+            field.base_type = base_type;
+            field.value = match base_type {
+                0x07 => {
+                    field.is_invalid = byte_array_is_invalid(data, base_type, is_big_endian);
+                    let mut strings = byte_array_to_strings(data);
+                    to_value(match (strings.next(), strings.next()) {
+                        (Some(first), Some(second)) => { let mut values = vec![first, second]; values.extend(strings); FitValue::StrArray(values) },
+                        (first, _) => FitValue::Str(first.unwrap_or_default())
+                    })
+                },
+                0x0D => {
+                    field.is_invalid = byte_array_is_invalid(data, base_type, is_big_endian);
+                    to_value(FitValue::ByteArray(Cow::Borrowed(data)))
+                },
+                0x00 | 0x01 | 0x02 | 0x83 | 0x84 | 0x85 | 0x86 | 0x88 | 0x89 | 0x0A | 0x8B | 0x8C | 0x8E | 0x8F | 0x90 if is_array || data.len() != width => {
+                    field.is_invalid = byte_array_is_invalid(data, base_type, is_big_endian);
+                    if is_array { byte_array_to_array(data, base_type, is_big_endian) } else { byte_array_to_value(data, base_type, is_big_endian) }
+                },
+                0x00 | 0x01 | 0x02 | 0x83 | 0x84 | 0x85 | 0x86 | 0x88 | 0x89 | 0x0A | 0x8B | 0x8C | 0x8E | 0x8F | 0x90 => {
+                    // The common case, the raw value is only decoded once.
+                    let raw_value = byte_array_to_num(data, width, is_big_endian);
+                    field.is_invalid = raw_value == base_type_invalid_value(base_type);
+                    raw_to_value(raw_value, base_type)
+                },
+                _ => { if !def.is_dev_field {
+                        let offset = state.offset() + (bytes_read - def.size as usize) as u64;
+                        return Err(FitError::UnsupportedBaseType { offset, local_msg_type, field_def: def.field_def, base_type });
                     }
+                    field.base_type = 0x0D;
+                    field.is_invalid = byte_array_is_invalid(data, 0x0D, is_big_endian);
+                    to_value(FitValue::ByteArray(Cow::Borrowed(data)))
                 }
-                fields.push(field);
+            };
+            fields.push(field);
+        }
+
+        // Messages like monitoring may only have the low 16 bits of the timestamp, resolve these against the last full timestamp.
+        let mut has_timestamp_16 = false;
+        if !has_timestamp_field && state.timestamp > 0 {
+            if let Some(field) = definition.timestamp_16_field.map(|index| &fields[index]).filter(|field| !field.is_invalid) {
+                new_timestamp = state.expand_timestamp_16(field.get_u16());
+                has_timestamp_16 = true;
             }
         }
 
-        // Update the bytes_read state. Have to do this outside of the loop to make rust happy.
-        state.bytes_read = state.bytes_read + bytes_read;
+        // Messages with a compressed timestamp header or a timestamp_16 field don't have a timestamp field, so add one.
+        if !has_timestamp_field && (has_timestamp_16 || self.header_byte & RECORD_HDR_NORMAL != 0) {
+            let mut field = FitFieldValue::new();
+            field.field_def = FIELD_TIMESTAMP;
+            field.base_type = 0x86;
+            field.value = FitValue::UInt(new_timestamp as u64);
+            field.profile = msg_profile.and_then(|msg_profile| msg_profile.find_field(FIELD_TIMESTAMP));
+            fields.push(field);
+        }
+
+        // Field descriptions and developer data IDs describe the developer fields of the messages that follow.
+        if global_msg_num == GLOBAL_MSG_NUM_DEVELOPER_DATA_ID {
+            state.insert_developer_data_id(fields);
+        }
+        else if global_msg_num == GLOBAL_MSG_NUM_FIELD_DESCRIPTION {
+            state.insert_field_description(fields);
+        }
+
+        if has_valid_timestamp && definition.has_event_timestamp {
+            state.anchor_event_timestamps(fields, new_timestamp);
+        }
 
-        // Convert the FIT timestamp to UNIX. FIT timestamps are seconds since UTC 00:00:00 Dec 31 1989.
-        let mut display_timestamp = 0;
-        if new_timestamp > 0 {
-            display_timestamp = 631065600 + new_timestamp;
+        // Resolve fields whose meaning depends on other fields, then unpack fields that are made up of other fields,
+        // such as compressed speed and distance.
+        resolve_subfields(fields);
+        expand_components(fields, definition, &mut state.accumulators);
+        msg.event_timestamps.clear();
+        if definition.has_event_timestamp {
+            state.resolve_event_timestamps(&msg.fields, &mut msg.event_timestamps);
         }
 
-        // Tell the people.
-        callback(display_timestamp, global_msg_num, local_msg_type, message_index, fields, context);
+        // Update the bytes_read state. Have to do this outside of the loop to make rust happy.
+        state.bytes_read += bytes_read as u64;
+
+        // FIT timestamps are seconds since UTC 00:00:00 Dec 31 1989, or system time if small enough.
+        let timestamp = if new_timestamp > 0 { Some(FitDateTime::from_fit(new_timestamp)) } else { None };
 
         // Store the (possibly) updated timestamp.
         state.timestamp = new_timestamp;
 
+        msg.timestamp = timestamp;
+        msg.global_message_num = global_msg_num;
+        msg.local_message_type = local_msg_type;
+        msg.message_index = message_index;
+        msg.segment = state.segment;
         Ok(())
     }
 
-    /// Assumes the buffer is pointing to the beginning of the compressed timestamp message, reads the message.
-    fn read_compressed_timestamp_message<C, R: Read>(&mut self, reader: &mut BufReader<R>, state: &mut FitState, callback: Callback<C>, context: &mut C) -> Result<()> {
+    /// Decodes the data message into a FitDataMessage that owns its values. Kept out of line, as inlining it into the loop
+    /// over the records is slower.
+    #[inline(never)]
+    fn read_owned_data_message(&self, message_data: &[u8], definition: &LocalMessageDefinition, state: &mut FitState) -> Result<FitMessage> {
+        let mut msg = FitDataMessage::default();
+        self.read_data_message(message_data, definition, state, &mut msg, FitValue::into_owned)?;
+        Ok(FitMessage::Data(msg))
+    }
+
+    /// Assumes the buffer is pointing to the beginning of the compressed timestamp message, updates the timestamp and
+    /// returns the definition of the data that follows.
+    fn read_compressed_timestamp_message(&mut self, state: &mut FitState) -> Result<FitRecordKind> {
         // Compressed Timestamp Header.
         let time_offset = (self.header_byte & 0x1f) as u32;
         if time_offset >= state.timestamp & 0x0000001F { // offset value is greater than least significant 5 bits of previous timestamp
@@ -2337,129 +3827,858 @@ impl FitRecord {
             state.timestamp = (state.timestamp & 0xFFFFFFE0) + time_offset + 0x00000020;
         }
 
-        // Read the data fields that follow.
-        self.read_data_message(reader, state, callback, context)?;
-
-        Ok(())
+        // The data fields follow.
+        self.data_definition(state).map(FitRecordKind::Data)
     }
 
-    /// Assumes the buffer is pointing to the beginning of the normal message, reads the message.
-    fn read_normal_message<C, R: Read>(&mut self, reader: &mut BufReader<R>, state: &mut FitState, callback: Callback<C>, context: &mut C) -> Result<()> {
+    /// Assumes the buffer is pointing to the beginning of the normal message, reads the definition message or returns the
+    /// definition of the data message.
+    fn read_normal_message<S: RecordSource>(&mut self, source: &mut S, state: &mut FitState) -> Result<FitRecordKind> {
         // Reserve bit should be zero in normal messages.
         if self.header_byte & RECORD_HDR_RESERVED != 0 {
-            let e = Error::new(std::io::ErrorKind::InvalidData, "Reserve bit set.");
-            return Err(e);
+            return Err(FitError::InvalidRecordHeader { offset: state.offset() - 1, local_msg_type: self.local_msg_type() });
         }
 
         // Data or definition message?
         // A value of zero indicates a data message.
         if self.header_byte & RECORD_HDR_MSG_TYPE != 0 {
-            self.read_definition_message(reader, state)?;
+            self.read_definition_message(source, state).map(FitRecordKind::Definition)
         }
         else {
-            self.read_data_message(reader, state, callback, context)?;
+            self.data_definition(state).map(FitRecordKind::Data)
         }
-
-        Ok(())
     }
 
-    /// Assumes the buffer is pointing to the beginning of the next record message, reads the message.
-    fn read<C, R: Read>(&mut self, reader: &mut BufReader<R>, state: &mut FitState, callback: Callback<C>, context: &mut C) -> Result<()> {
+    /// Assumes the buffer is pointing to the beginning of the next record message, reads the record header and, for a
+    /// definition message, the rest of the message.
+    fn read_header<S: RecordSource>(&mut self, source: &mut S, state: &mut FitState) -> Result<FitRecordKind> {
         // The first byte is a bit field that tells us more about the record.
-        self.header_byte = read_byte(reader)?;
-        state.bytes_read = state.bytes_read + 1;
+        self.header_byte = source.take(1).map_err(|e| FitError::from_io(e, state.offset(), None))?[0];
+        state.bytes_read += 1;
         //println!("header_byte {:#04x} bytes_read {}", self.header_byte, state.bytes_read);
 
         // Normal header or compressed timestamp header?
         // A value of zero indicates a normal header.
         if self.header_byte & RECORD_HDR_NORMAL != 0 {
-            self.read_compressed_timestamp_message(reader, state, callback, context)?;
+            self.read_compressed_timestamp_message(state)
         }
         else {
-            self.read_normal_message(reader, state, callback, context)?;
+            self.read_normal_message(source, state)
+        }
+    }
+
+    /// Assumes the buffer is pointing to the beginning of the next record message, reads the message.
+    fn read<S: RecordSource>(&mut self, source: &mut S, state: &mut FitState) -> Result<FitMessage> {
+        let result = match self.read_header(source, state) {
+            Ok(FitRecordKind::Definition(msg)) => Ok(FitMessage::Definition(msg)),
+            Ok(FitRecordKind::Data(definition)) => source.take(definition.message_size).map_err(FitError::from)
+                .and_then(|message_data| self.read_owned_data_message(message_data, &definition, state)),
+            Err(e) => Err(e)
+        };
+        self.map_truncation(result, state)
+    }
+
+    /// Same as read(), except that a data message is decoded into msg, borrowing from the slice. Returns the message if
+    /// the record is a definition message.
+    fn read_ref<'a>(&mut self, source: &mut &'a [u8], state: &mut FitState, msg: &mut FitDataMessage<'a>) -> Result<Option<FitDefinitionMessage>> {
+        let result = match self.read_header(source, state) {
+            Ok(FitRecordKind::Definition(definition_msg)) => Ok(Some(definition_msg)),
+            Ok(FitRecordKind::Data(definition)) => take_bytes(source, definition.message_size).map_err(FitError::from)
+                .and_then(|message_data| self.read_data_message(message_data, &definition, state, msg, |value| value).map(|_| None)),
+            Err(e) => Err(e)
+        };
+        self.map_truncation(result, state)
+    }
+
+    /// Running out of data part way through the record means the file was truncated.
+    fn map_truncation<T>(&self, result: Result<T>, state: &FitState) -> Result<T> {
+        result.map_err(|e| match e {
+            FitError::Io(e) => FitError::from_io(e, state.offset(), Some(self.local_msg_type())),
+            e => e
+        })
+    }
+
+    /// Returns the local message type from the record header. It is stored differently for compressed timestamp headers.
+    fn local_msg_type(&self) -> u8 {
+        if self.header_byte & RECORD_HDR_NORMAL != 0 {
+            (self.header_byte & RECORD_HDR_LOCAL_MSG_TYPE_COMPRESSED) >> 5
+        }
+        else {
+            self.header_byte & RECORD_HDR_LOCAL_MSG_TYPE
+        }
+    }
+}
+
+/// CRC of every possible byte value, so that the CRC can be updated a byte at a time instead of four bits at a time.
+const CRC_TABLE: [u16; 256] = build_crc_table();
+
+/// Builds the lookup table for check_crc().
+const fn build_crc_table() -> [u16; 256] {
+    let mut table = [0u16; 256];
+    let mut byte = 0;
+    while byte < 256 {
+        let mut crc = byte as u16;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 { (crc >> 1) ^ 0xA001 } else { crc >> 1 };
+            bit += 1;
+        }
+        table[byte] = crc;
+        byte += 1;
+    }
+    table
+}
+
+/// CRC validation function. Updates the running CRC with the given byte and returns the new value.
+fn check_crc(crc: u16, byte: u8) -> u16 {
+    (crc >> 8) ^ CRC_TABLE[((crc ^ byte as u16) & 0xFF) as usize]
+}
+
+/// Wraps a reader and computes the CRC over every byte that passes through it.
+struct CrcReader<'a, R: Read> {
+    reader: &'a mut R,
+    crc: u16 // Running CRC of everything read so far
+}
+
+impl<'a, R: Read> CrcReader<'a, R> {
+    fn new(reader: &'a mut R, crc: u16) -> Self {
+        CrcReader{ reader, crc }
+    }
+}
+
+impl<R: Read> Read for CrcReader<'_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let num_bytes = self.reader.read(buf)?;
+        for byte in buf.iter().take(num_bytes) {
+            self.crc = check_crc(self.crc, *byte);
+        }
+        Ok(num_bytes)
+    }
+}
+
+/// Where the FitReader or FitStreamDecoder is within the current header+data+CRC segment.
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum FitReaderPhase {
+    Header,
+    Records,
+    Crc,
+    Trailing, // After the last FIT file, whatever else arrives is counted in trailing_bytes and ignored
+    Done
+}
+
+/// Decodes FIT messages one at a time. The FIT spec allows several FIT files to be chained together,
+/// so this keeps reading header+data+CRC segments until the end of the stream is reached.
+/// Iteration stops after the first error.
+pub struct FitReader<R: BufRead> {
+    pub segment_headers: Vec<FitHeader>, // Headers of each of the (possibly chained) FIT files read so far
+    pub header_crc_valid: bool, // False until a header has been read, then false if any header contains a CRC and it does not match the header bytes
+    pub crc_valid: bool, // False until the CRC at the end of the first FIT file has been read, then true if each CRC read so far matches its header and data bytes
+    pub strict_crc: bool, // If set then a CRC mismatch is returned as an error, otherwise it is only reported in the flags above
+    pub trailing_bytes: u64, // Bytes after the last FIT file that don't start another one, such as padding. These are ignored.
+    reader: R,
+    state: FitState, // Definitions, timestamp, etc. for the FIT file being read
+    phase: FitReaderPhase,
+    bytes_to_read: u64, // Size of the header and data of the FIT file being read
+    crc: u16, // Running CRC of the FIT file being read
+    segment: usize, // Index of the FIT file being read
+    buffer: Vec<u8> // Holds the record being decoded
+}
+
+impl<R: BufRead> FitReader<R> {
+    pub fn new(reader: R) -> Self {
+        FitReader{ segment_headers: Vec::new(), header_crc_valid: false, crc_valid: false, strict_crc: false, trailing_bytes: 0, reader, state: FitState::new(), phase: FitReaderPhase::Header, bytes_to_read: 0, crc: 0, segment: 0, buffer: Vec::new() }
+    }
+
+    /// Reads the header at the start of each FIT file. Returns false if the data after the last FIT file isn't the header
+    /// of another one, in which case the rest of the stream is counted in trailing_bytes.
+    fn read_header(&mut self) -> Result<bool> {
+        let segment_offset = self.state.offset();
+        let mut header_bytes = [0; 14];
+        let mut header = FitHeader::new();
+
+        // The first 12 bytes are enough to tell whether another FIT file is chained to the last one.
+        let mut num_bytes = read_up_to(&mut self.reader, &mut header_bytes[..12]).map_err(|e| FitError::from_io(e, segment_offset, None))?;
+        if self.segment > 0 && !is_fit_header(&header_bytes[..num_bytes]) {
+            let num_skipped = skip_to_end(&mut self.reader).map_err(|e| FitError::from_io(e, segment_offset, None))?;
+            self.trailing_bytes = num_bytes as u64 + num_skipped;
+            return Ok(false);
+        }
+        if num_bytes == 12 && header_bytes[HEADER_FILE_SIZE_OFFSET] == 14 {
+            self.reader.read_exact(&mut header_bytes[12..]).map_err(|e| FitError::from_io(e, segment_offset, None))?;
+            num_bytes = 14;
+        }
+
+        self.state = FitState::new();
+        self.state.segment = self.segment;
+        self.state.segment_offset = segment_offset;
+
+        // Read the file header.
+        header.read(&mut &header_bytes[..num_bytes]).map_err(|e| FitError::from_io(e, segment_offset, None))?;
+        self.crc = header_bytes[..num_bytes].iter().fold(0, |crc, byte| check_crc(crc, *byte));
+        self.state.bytes_read = header.header_len as u64;
+
+        // Make sure the header is valid.
+        if !header.validate() {
+            return Err(FitError::BadHeader { offset: segment_offset });
+        }
+
+        // The 14 byte header carries its own (optional) CRC.
+        let header_crc_valid = header.validate_crc();
+        self.header_crc_valid = header_crc_valid && (self.segment == 0 || self.header_crc_valid);
+        if !header_crc_valid && self.strict_crc {
+            let offset = segment_offset + HEADER_CRC_1_OFFSET as u64;
+            return Err(FitError::CrcMismatch { offset, expected: header.crc().unwrap_or(0), computed: header.compute_crc() });
+        }
+
+        // Bytes to read is specified in the header as being the number of bytes after the header.
+        // The CRC follows the data.
+        self.bytes_to_read = header.header_len as u64 + header.data_size() as u64;
+        self.segment_headers.push(header);
+
+        Ok(true)
+    }
+
+    /// Reads the next record.
+    fn read_record(&mut self) -> Result<FitMessage> {
+        let mut reader = CrcReader::new(&mut self.reader, self.crc);
+        let mut record = FitRecord::new();
+        let result = record.read(&mut ReaderSource::new(&mut reader, &mut self.buffer), &mut self.state);
+
+        self.crc = reader.crc;
+        result
+    }
+
+    /// Reads the CRC at the end of each FIT file. It is computed over the header and all the data bytes.
+    fn read_crc(&mut self) -> Result<()> {
+        let crc_bytes = read_n(&mut self.reader, 2).map_err(|e| FitError::from_io(e, self.state.offset(), None))?;
+        let expected_crc = byte_array_to_uint16(&crc_bytes, false);
+        let crc_valid = self.crc == expected_crc;
+
+        self.crc_valid = crc_valid && (self.segment == 0 || self.crc_valid);
+        if !crc_valid && self.strict_crc {
+            return Err(FitError::CrcMismatch { offset: self.state.offset(), expected: expected_crc, computed: self.crc });
+        }
+
+        self.state.bytes_read += 2;
+        self.segment += 1;
+
+        Ok(())
+    }
+
+    /// Moves to the next phase, returning the next message if there is one.
+    fn step(&mut self) -> Result<Option<FitMessage>> {
+        match self.phase {
+            FitReaderPhase::Header => {
+                self.phase = if self.read_header()? { FitReaderPhase::Records } else { FitReaderPhase::Done };
+            }
+            FitReaderPhase::Records => {
+                if self.state.bytes_read < self.bytes_to_read {
+                    return self.read_record().map(Some);
+                }
+                self.phase = FitReaderPhase::Crc;
+            }
+            FitReaderPhase::Crc => {
+                self.read_crc()?;
+
+                // Stop at the end of the stream, otherwise there's another FIT file chained to this one.
+                self.phase = if self.reader.fill_buf()?.is_empty() { FitReaderPhase::Done } else { FitReaderPhase::Header };
+            }
+            FitReaderPhase::Trailing | FitReaderPhase::Done => self.phase = FitReaderPhase::Done
+        }
+        Ok(None)
+    }
+}
+
+impl<R: BufRead> Iterator for FitReader<R> {
+    type Item = Result<FitMessage>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.phase != FitReaderPhase::Done {
+            match self.step() {
+                Ok(Some(msg)) => return Some(Ok(msg)),
+                Ok(None) => {}
+                Err(e) => {
+                    self.phase = FitReaderPhase::Done;
+                    return Some(Err(e));
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Decodes FIT data that arrives in chunks of any size, such as over BLE or ANT-FS. Each call to feed() decodes the
+/// records that have been completely received, and keeps the rest until more data arrives. As with FitReader,
+/// chained FIT files are decoded one after the other, and decoding stops after the first error.
+pub struct FitStreamDecoder {
+    pub segment_headers: Vec<FitHeader>, // Headers of each of the (possibly chained) FIT files received so far
+    pub header_crc_valid: bool, // False until a header has been received, then false if any header contains a CRC and it does not match the header bytes
+    pub crc_valid: bool, // False until the CRC at the end of the first FIT file has been received, then true if each CRC received so far matches its header and data bytes
+    pub strict_crc: bool, // If set then a CRC mismatch is returned as an error, otherwise it is only reported in the flags above
+    pub trailing_bytes: u64, // Bytes after the last FIT file that don't start another one, such as padding. These are ignored.
+    state: FitState, // Definitions, timestamp, etc. for the FIT file being decoded
+    phase: FitReaderPhase,
+    bytes_to_read: u64, // Size of the header and data of the FIT file being decoded
+    crc: u16, // Running CRC of the FIT file being decoded
+    segment: usize, // Index of the FIT file being decoded
+    buffer: Vec<u8>, // Data that has been received, the bytes before buffer_start have been decoded
+    buffer_start: usize
+}
+
+impl Default for FitStreamDecoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FitStreamDecoder {
+    pub fn new() -> Self {
+        FitStreamDecoder{ segment_headers: Vec::new(), header_crc_valid: false, crc_valid: false, strict_crc: false, trailing_bytes: 0, state: FitState::new(), phase: FitReaderPhase::Header, bytes_to_read: 0, crc: 0, segment: 0, buffer: Vec::new(), buffer_start: 0 }
+    }
+
+    /// Adds the data to what has been received so far, then passes each message that can now be decoded to the handler.
+    /// Any data after an error is ignored.
+    pub fn feed<H: FitMessageHandler + ?Sized>(&mut self, data: &[u8], handler: &mut H) -> Result<()> {
+        if self.phase == FitReaderPhase::Done {
+            return Ok(());
         }
 
+        self.push(data);
+        while let Some(msg) = self.decode_next()? {
+            handler.on_message(&msg);
+        }
         Ok(())
     }
+
+    /// Adds the data to what has been received so far, without decoding anything.
+    pub(crate) fn push(&mut self, data: &[u8]) {
+        self.buffer.drain(..self.buffer_start);
+        self.buffer_start = 0;
+        self.buffer.extend_from_slice(data);
+    }
+
+    /// Call once all the data has been received. Returns an error if the data ended part way through a FIT file.
+    pub fn finish(&mut self) -> Result<()> {
+        let pending = &self.buffer[self.buffer_start..];
+
+        match self.phase {
+            // Too little data for another header after the last FIT file, so it can't be the start of another one.
+            FitReaderPhase::Header if pending.len() < 12 && !self.segment_headers.is_empty() => {
+                self.trailing_bytes += pending.len() as u64;
+                Ok(())
+            }
+            FitReaderPhase::Trailing | FitReaderPhase::Done => Ok(()),
+            FitReaderPhase::Records if !pending.is_empty() => {
+                let local_msg_type = FitRecord{ header_byte: pending[0] }.local_msg_type();
+                Err(FitError::TruncatedData { offset: self.state.offset(), local_msg_type: Some(local_msg_type) })
+            }
+            _ => Err(FitError::TruncatedData { offset: self.state.offset(), local_msg_type: None })
+        }
+    }
+
+    /// Number of bytes decoded so far, from the start of the stream. A record is counted once all of it has been received.
+    pub fn bytes_decoded(&self) -> u64 {
+        self.state.offset()
+    }
+
+    /// Size of the FIT file being decoded, including its header and CRC, as given by its header. For chained FIT files,
+    /// this is the offset of the end of the current file. None until the first header has been received.
+    pub fn expected_len(&self) -> Option<u64> {
+        let header = self.segment_headers.last()?;
+        Some(self.state.segment_offset + header.header_len as u64 + header.data_size() as u64 + 2)
+    }
+
+    /// Decodes the next message from the data received so far. Returns None if more data is needed.
+    pub(crate) fn decode_next(&mut self) -> Result<Option<FitMessage>> {
+        let result = self.step();
+        if result.is_err() {
+            self.phase = FitReaderPhase::Done;
+        }
+        result
+    }
+
+    fn step(&mut self) -> Result<Option<FitMessage>> {
+        loop {
+            let bytes = &self.buffer[self.buffer_start..];
+
+            match self.phase {
+                FitReaderPhase::Header => {
+                    let header_len = match bytes.first() {
+                        Some(14) => 14,
+                        Some(_) => 12,
+                        None => return Ok(None)
+                    };
+                    if bytes.len() < header_len {
+                        return Ok(None);
+                    }
+
+                    // Only another FIT file can follow the last one, anything else is ignored.
+                    if self.segment > 0 && !is_fit_header(bytes) {
+                        self.phase = FitReaderPhase::Trailing;
+                        continue;
+                    }
+
+                    let segment_offset = self.state.offset();
+                    let mut header = FitHeader::new();
+                    header.read(&mut &bytes[..header_len]).map_err(|e| FitError::from_io(e, segment_offset, None))?;
+
+                    self.state = FitState::new();
+                    self.state.segment = self.segment;
+                    self.state.segment_offset = segment_offset;
+                    self.state.bytes_read = header_len as u64;
+
+                    // Make sure the header is valid. The 14 byte header carries its own (optional) CRC.
+                    if !header.validate() {
+                        return Err(FitError::BadHeader { offset: segment_offset });
+                    }
+                    let header_crc_valid = header.validate_crc();
+                    self.header_crc_valid = header_crc_valid && (self.segment == 0 || self.header_crc_valid);
+                    if !header_crc_valid && self.strict_crc {
+                        let offset = segment_offset + HEADER_CRC_1_OFFSET as u64;
+                        return Err(FitError::CrcMismatch { offset, expected: header.crc().unwrap_or(0), computed: header.compute_crc() });
+                    }
+
+                    self.crc = bytes[..header_len].iter().fold(0, |crc, byte| check_crc(crc, *byte));
+                    self.bytes_to_read = header_len as u64 + header.data_size() as u64;
+                    self.segment_headers.push(header);
+                    self.buffer_start += header_len;
+                    self.phase = FitReaderPhase::Records;
+                }
+                FitReaderPhase::Records => {
+                    if self.state.bytes_read >= self.bytes_to_read {
+                        self.phase = FitReaderPhase::Crc;
+                        continue;
+                    }
+
+                    // Wait for the rest of the record.
+                    let len = match record_len(bytes, &self.state) {
+                        Some(len) if len <= bytes.len() => len,
+                        _ => return Ok(None)
+                    };
+                    let msg = FitRecord::new().read(&mut &bytes[..len], &mut self.state)?;
+                    self.crc = bytes[..len].iter().fold(self.crc, |crc, byte| check_crc(crc, *byte));
+                    self.buffer_start += len;
+                    return Ok(Some(msg));
+                }
+                FitReaderPhase::Crc => {
+                    if bytes.len() < 2 {
+                        return Ok(None);
+                    }
+
+                    // The CRC is computed over the header and all the data bytes.
+                    let expected_crc = byte_array_to_uint16(&bytes[..2], false);
+                    let crc_valid = self.crc == expected_crc;
+                    self.crc_valid = crc_valid && (self.segment == 0 || self.crc_valid);
+                    if !crc_valid && self.strict_crc {
+                        return Err(FitError::CrcMismatch { offset: self.state.offset(), expected: expected_crc, computed: self.crc });
+                    }
+
+                    // Another FIT file may be chained to this one.
+                    self.state.bytes_read += 2;
+                    self.segment += 1;
+                    self.buffer_start += 2;
+                    self.phase = FitReaderPhase::Header;
+                }
+                FitReaderPhase::Trailing => {
+                    self.trailing_bytes += bytes.len() as u64;
+                    self.buffer_start += bytes.len();
+                    return Ok(None);
+                }
+                FitReaderPhase::Done => return Ok(None)
+            }
+        }
+    }
+}
+
+/// Number of bytes requested from an async reader at a time.
+#[cfg(feature = "async")]
+const ASYNC_READ_SIZE: usize = 8192;
+
+/// Decodes FIT messages from a reader that implements tokio's AsyncRead, as a Stream. Decoding is done by a
+/// FitStreamDecoder, which also holds the headers and CRC flags of the data read so far. Requires the async feature.
+#[cfg(feature = "async")]
+pub struct FitAsyncReader<R: AsyncRead + Unpin> {
+    pub decoder: FitStreamDecoder, // Set decoder.strict_crc before reading to have a CRC mismatch returned as an error
+    reader: R,
+    buffer: Vec<u8>, // Holds the data from each read
+    is_done: bool // Set once the end of the data, or an error, is reached
+}
+
+#[cfg(feature = "async")]
+impl<R: AsyncRead + Unpin> FitAsyncReader<R> {
+    pub fn new(reader: R) -> Self {
+        FitAsyncReader{ decoder: FitStreamDecoder::new(), reader, buffer: vec![0; ASYNC_READ_SIZE], is_done: false }
+    }
+}
+
+#[cfg(feature = "async")]
+impl<R: AsyncRead + Unpin> futures_core::Stream for FitAsyncReader<R> {
+    type Item = Result<FitMessage>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        while !this.is_done {
+            match this.decoder.decode_next() {
+                Ok(Some(msg)) => return Poll::Ready(Some(Ok(msg))),
+                Ok(None) => {}
+                Err(e) => {
+                    this.is_done = true;
+                    return Poll::Ready(Some(Err(e)));
+                }
+            }
+
+            // Nothing more can be decoded until more data arrives.
+            let mut read_buf = ReadBuf::new(&mut this.buffer);
+            match Pin::new(&mut this.reader).poll_read(cx, &mut read_buf) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(Ok(())) if read_buf.filled().is_empty() => {
+                    this.is_done = true;
+                    if let Err(e) = this.decoder.finish() {
+                        return Poll::Ready(Some(Err(e)));
+                    }
+                }
+                Poll::Ready(Ok(())) => this.decoder.push(read_buf.filled()),
+                Poll::Ready(Err(e)) => {
+                    this.is_done = true;
+                    return Poll::Ready(Some(Err(FitError::from_io(e, this.decoder.bytes_decoded(), None))));
+                }
+            }
+        }
+        Poll::Ready(None)
+    }
+}
+
+/// Bytes that were skipped while reading in lenient mode, along with the error that caused them to be skipped.
+#[derive(Debug)]
+pub struct FitSkippedRange {
+    pub range: core::ops::Range<u64>, // Offsets of the skipped bytes from the start of the stream
+    pub error: FitError
+}
+
+/// Returns the length of the record at the start of the buffer if it looks like one that can be decoded with the current
+/// definitions: the reserved bits are clear, the local message type is defined, and the whole record fits in the buffer.
+fn plausible_record_len(bytes: &[u8], state: &FitState) -> Option<usize> {
+    let record = FitRecord{ header_byte: *bytes.first()? };
+
+    if record.header_byte & RECORD_HDR_NORMAL == 0 {
+        if record.header_byte & RECORD_HDR_RESERVED != 0 {
+            return None;
+        }
+
+        // Definition messages have a reserved byte of zero and an architecture of zero or one.
+        if record.header_byte & RECORD_HDR_MSG_TYPE != 0 {
+            if bytes.len() < 6 || bytes[1 + DEF_MSG_RESERVED] != 0 || bytes[1 + DEF_MSG_ARCHITECTURE] > 1 {
+                return None;
+            }
+            let mut len = 6 + 3 * bytes[1 + DEF_MSG_NUM_FIELDS] as usize;
+            if record.header_byte & RECORD_HDR_MSG_TYPE_SPECIFIC != 0 {
+                len += 1 + 3 * *bytes.get(len)? as usize;
+            }
+            return if len <= bytes.len() { Some(len) } else { None };
+        }
+        if record.header_byte & RECORD_HDR_MSG_TYPE_SPECIFIC != 0 {
+            return None;
+        }
+    }
+
+    let definition = state.definitions[record.local_msg_type() as usize].as_ref()?;
+    let len = 1 + definition.message_size;
+    if len <= bytes.len() { Some(len) } else { None }
+}
+
+/// Returns the length of the record at the start of the buffer, or None if not enough of it is in the buffer to tell.
+/// Records that can't be decoded are given a length of one, so that decoding them reports the error.
+fn record_len(bytes: &[u8], state: &FitState) -> Option<usize> {
+    let record = FitRecord{ header_byte: *bytes.first()? };
+
+    if record.header_byte & RECORD_HDR_NORMAL == 0 {
+        if record.header_byte & RECORD_HDR_RESERVED != 0 {
+            return Some(1);
+        }
+
+        // Definition messages: a five byte header, then three bytes per field, then the developer fields, if any.
+        if record.header_byte & RECORD_HDR_MSG_TYPE != 0 {
+            let mut len = 6 + 3 * *bytes.get(1 + DEF_MSG_NUM_FIELDS)? as usize;
+            if record.header_byte & RECORD_HDR_MSG_TYPE_SPECIFIC != 0 {
+                len += 1 + 3 * *bytes.get(len)? as usize;
+            }
+            return Some(len);
+        }
+    }
+
+    Some(state.definitions[record.local_msg_type() as usize].as_ref().map_or(1, |definition| 1 + definition.message_size))
+}
+
+/// Returns true if the bytes start with a FIT file header, i.e. a header size of 12 or 14 followed by ".FIT". Used to tell
+/// another FIT file chained to the last one from padding or other data after it.
+fn is_fit_header(bytes: &[u8]) -> bool {
+    bytes.len() >= 12 && (bytes[0] == 12 || bytes[0] == 14) && &bytes[HEADER_DATA_TYPE_0_OFFSET..12] == b".FIT"
+}
+
+/// Works out where the data of the FIT file starting at the given offset ends, and the CRC that follows it, if there is one.
+/// The data size from the header is only used if it is consistent with the rest of the buffer. Otherwise the data runs to
+/// the end of the buffer, less the CRC if the last two bytes are a CRC of everything before them. A data size too big to
+/// add to the offset, as can happen on 32 bit targets, is reported as truncated data.
+fn find_data_end(data: &[u8], segment_offset: usize, header: &FitHeader) -> Result<(usize, Option<u16>)> {
+    let data_start = segment_offset + header.header_len as usize;
+    let truncated = || FitError::TruncatedData { offset: data.len() as u64, local_msg_type: None };
+    let data_end = data_start.checked_add(header.data_size() as usize).ok_or_else(truncated)?;
+    let next_segment = data_end.checked_add(2).ok_or_else(truncated)?;
+
+    if header.data_size() > 0 && (next_segment == data.len() || data.get(next_segment..).is_some_and(is_fit_header)) {
+        return Ok((data_end, Some(byte_array_to_uint16(&data[data_end..next_segment], false))));
+    }
+
+    if data.len() >= data_start + 2 {
+        let crc_offset = data.len() - 2;
+        let expected_crc = byte_array_to_uint16(&data[crc_offset..], false);
+        if data[segment_offset..crc_offset].iter().fold(0, |crc, byte| check_crc(crc, *byte)) == expected_crc {
+            return Ok((crc_offset, Some(expected_crc)));
+        }
+    }
+    Ok((data.len(), None))
 }
 
 /// Parses a FIT file.
 #[derive(Debug, Default)]
 pub struct Fit {
-    pub header: FitHeader
+    pub header: FitHeader, // Header of the first FIT file in the stream
+    pub segment_headers: Vec<FitHeader>, // Headers of each of the (possibly chained) FIT files in the stream
+    pub header_crc_valid: bool, // False if any header contains a CRC and it does not match the header bytes
+    pub crc_valid: bool, // True if the CRC at the end of each FIT file matches its header and data bytes
+    pub strict_crc: bool, // If set then a CRC mismatch aborts the parse with an error, otherwise it is only reported in the flags above
+    pub lenient: bool, // If set then a bad data size in the header is ignored and records that can't be decoded are skipped. A CRC mismatch is still an error if strict_crc is set, a missing CRC only clears crc_valid
+    pub skipped_ranges: Vec<FitSkippedRange>, // Bytes that were skipped in lenient mode
+    pub trailing_bytes: u64 // Bytes after the last FIT file that don't start another one, such as padding. These are ignored.
 }
 
 impl Fit {
     pub fn new() -> Self {
-        let fit = Fit{ header: FitHeader::new() };
-        fit
+        Fit{ header: FitHeader::new(), segment_headers: Vec::new(), header_crc_valid: false, crc_valid: false, strict_crc: false, lenient: false, skipped_ranges: Vec::new(), trailing_bytes: 0 }
     }
 
-    /// CRC validation function.
-    fn check_crc(&self, crc: u16, byte: u8) -> u16{
-        let crc_table: [u16; 16] = [
-            0x0000, 0xCC01, 0xD801, 0x1400, 0xF001, 0x3C00, 0x2800, 0xE401,
-            0xA001, 0x6C00, 0x7800, 0xB401, 0x5000, 0x9C01, 0x8801, 0x4400
-        ];
+    /// Reads the FIT data from the buffer, passing each message to the handler. The FIT spec allows several FIT files to be
+    /// chained together, so this keeps reading header+data+CRC segments until the end of the buffer is reached.
+    #[cfg(feature = "std")]
+    pub fn read<H: FitMessageHandler + ?Sized, R: Read>(&mut self, reader: &mut BufReader<R>, handler: &mut H) -> Result<()> {
+        if self.lenient {
+            let mut data = Vec::new();
+            reader.read_to_end(&mut data).map_err(|e| FitError::from_io(e, 0, None))?;
+            return self.read_slice(&data, handler);
+        }
+
+        self.read_messages(reader, |msg| handler.on_message(&msg))
+    }
 
-        // Compute checksum of lower four bits of byte.
-        let mut crc2 = crc;
-        let mut tmp: u16 = crc_table[(crc2 & 0xf) as usize];
-        crc2 = (crc2 >> 4) & 0x0fff;
-        crc2 = crc2 ^ tmp ^ crc_table[(byte & 0xf) as usize];
+    /// Same as read(), without lenient mode, except that each message is moved to on_message.
+    #[cfg(feature = "std")]
+    fn read_messages<R: Read, F: FnMut(FitMessage)>(&mut self, reader: &mut BufReader<R>, mut on_message: F) -> Result<()> {
+        let mut fit_reader = FitReader::new(reader);
+        fit_reader.strict_crc = self.strict_crc;
+
+        let mut result = Ok(());
+        for msg in &mut fit_reader {
+            match msg {
+                Ok(msg) => on_message(msg),
+                Err(e) => { result = Err(e); break; }
+            }
+        }
 
-        // Now compute checksum of upper four bits of byte.
-        tmp = crc_table[(crc2 & 0xf) as usize];
-        crc2 = (crc2 >> 4) & 0x0fff;
-        crc2 = crc2 ^ tmp ^ crc_table[((byte >> 4) & 0xf) as usize];
+        if let Some(header) = fit_reader.segment_headers.first() {
+            self.header = header.clone();
+        }
+        self.segment_headers = fit_reader.segment_headers;
+        self.header_crc_valid = fit_reader.header_crc_valid;
+        self.crc_valid = fit_reader.crc_valid;
+        self.trailing_bytes = fit_reader.trailing_bytes;
 
-        crc2
+        result
     }
 
-    /// Reads the FIT data from the buffer.
-    pub fn read<C, R: Read>(&mut self, reader: &mut BufReader<R>, callback: Callback<C>, context: &mut C) -> Result<()> {
-        let mut state = FitState::new();
+    /// Same as read(), for readers that implement tokio's AsyncRead. Requires the async feature.
+    #[cfg(feature = "async")]
+    pub async fn read_async<H: FitMessageHandler + ?Sized, R: AsyncRead + Unpin>(&mut self, reader: &mut R, handler: &mut H) -> Result<()> {
+        if self.lenient {
+            let mut data = Vec::new();
+            reader.read_to_end(&mut data).await.map_err(|e| FitError::from_io(e, 0, None))?;
+            return self.read_slice(&data, handler);
+        }
 
-        // Read the file header.
-        self.header.read(reader)?;
-        state.bytes_read = self.header.header_len as u64;
+        let mut decoder = FitStreamDecoder::new();
+        decoder.strict_crc = self.strict_crc;
+
+        let mut buffer = vec![0; ASYNC_READ_SIZE];
+        let result = loop {
+            let num_bytes = match reader.read(&mut buffer).await {
+                Ok(num_bytes) => num_bytes,
+                Err(e) => break Err(FitError::from_io(e, decoder.bytes_decoded(), None))
+            };
+            if num_bytes == 0 {
+                break decoder.finish();
+            }
+            if let Err(e) = decoder.feed(&buffer[..num_bytes], handler) {
+                break Err(e);
+            }
+        };
 
-        // Make sure the header is valid.
-        if self.header.validate() {
+        if let Some(header) = decoder.segment_headers.first() {
+            self.header = header.clone();
+        }
+        self.segment_headers = decoder.segment_headers;
+        self.header_crc_valid = decoder.header_crc_valid;
+        self.crc_valid = decoder.crc_valid;
+        self.trailing_bytes = decoder.trailing_bytes;
+
+        result
+    }
 
-            let mut error = false;
+    /// Reads FIT data from a slice, such as a memory mapped file, passing each message to the handler. Records are decoded in
+    /// place, then copied into a FitMessage. Use read_slice_ref() to avoid copying the fields.
+    ///
+    /// If lenient is set then the data may be truncated or partially corrupt, such as a file left behind when a device's
+    /// battery died. Records that can't be decoded are skipped, along with everything up to the next plausible record
+    /// header, and noted in skipped_ranges. A file without a CRC is accepted, but a CRC mismatch is still an error if
+    /// strict_crc is set.
+    pub fn read_slice<H: FitMessageHandler + ?Sized>(&mut self, data: &[u8], handler: &mut H) -> Result<()> {
+        self.decode_slice(data, |record_data, state| {
+            let msg = FitRecord::new().read(record_data, state)?;
+            handler.on_message(&msg);
+            Ok(())
+        })
+    }
 
-            // Bytes to read is specified in the header as being the number of bytes after the header.
-            // We also need to subtract the two bytes for the CRC.
-            let bytes_to_read = self.header.header_len as u64 + self.header.data_size() as u64 - 2;
+    /// Same as read_slice(), except that the fields of each data message borrow their strings and byte arrays from the
+    /// slice instead of copying them, and the same message, and list of fields, is reused for each record.
+    pub fn read_slice_ref<'a, H: FitMessageRefHandler<'a> + ?Sized>(&mut self, data: &'a [u8], handler: &mut H) -> Result<()> {
+        let mut msg = FitDataMessage::default();
+        self.decode_slice(data, |record_data, state| {
+            match FitRecord::new().read_ref(record_data, state, &mut msg)? {
+                Some(definition) => handler.on_definition(&definition),
+                None => handler.on_data(&msg)
+            }
+            Ok(())
+        })
+    }
 
-            // Read each record.
-            while !error && state.bytes_read < bytes_to_read {
+    /// Walks the headers, records and CRCs of the slice for read_slice() and read_slice_ref(). Each record is decoded by
+    /// read_record, which advances the slice it is given past the record.
+    fn decode_slice<'a, F: FnMut(&mut &'a [u8], &mut FitState) -> Result<()>>(&mut self, data: &'a [u8], mut read_record: F) -> Result<()> {
+        self.segment_headers.clear();
+        self.skipped_ranges.clear();
+        self.trailing_bytes = 0;
+        self.header_crc_valid = false;
+        self.crc_valid = false;
+
+        let mut segment_offset = 0;
+        while segment_offset < data.len() {
+            let mut state = FitState::new();
+            state.segment = self.segment_headers.len();
+            state.segment_offset = segment_offset as u64;
+
+            // Only another FIT file can follow the last one, anything else is ignored.
+            if segment_offset > 0 && !is_fit_header(&data[segment_offset..]) {
+                self.trailing_bytes = (data.len() - segment_offset) as u64;
+                break;
+            }
 
-                let mut record = FitRecord::new();
-                let result = record.read(reader, &mut state, callback, context);
+            // Without a header there's nothing to go on. In lenient mode, anything after the last FIT file is skipped.
+            let mut header = FitHeader::new();
+            let header_result = header.read(&mut &data[segment_offset..]);
+            if header_result.is_err() || !header.validate() {
+                let error = match header_result {
+                    Err(e) => FitError::from_io(e, segment_offset as u64, None),
+                    Ok(()) => FitError::BadHeader { offset: segment_offset as u64 }
+                };
+                if !self.lenient || segment_offset == 0 {
+                    return Err(error);
+                }
+                self.skipped_ranges.push(FitSkippedRange { range: segment_offset as u64..data.len() as u64, error });
+                break;
+            }
+            let header_crc_valid = header.validate_crc();
+            self.header_crc_valid = header_crc_valid && (state.segment == 0 || self.header_crc_valid);
+            if !header_crc_valid && self.strict_crc {
+                let offset = (segment_offset + HEADER_CRC_1_OFFSET) as u64;
+                return Err(FitError::CrcMismatch { offset, expected: header.crc().unwrap_or(0), computed: header.compute_crc() });
+            }
 
-                match result {
-                    Ok(_result) => {
-                    }
-                    Err(e) => {
-                        println!("Error: {} Bytes Read: {}", e, state.bytes_read);
-                        error = true;
+            // In lenient mode the data size from the header is only used if it is consistent with the rest of the data.
+            // The size may be too big to add to the offset on 32 bit targets, the data can't hold that much anyway.
+            let (data_end, mut expected_crc) = if self.lenient {
+                find_data_end(data, segment_offset, &header)?
+            }
+            else {
+                let data_end = (segment_offset + header.header_len as usize).checked_add(header.data_size() as usize);
+                (data_end.ok_or(FitError::TruncatedData { offset: data.len() as u64, local_msg_type: None })?, None)
+            };
+            let records_end = data_end.min(data.len());
+            let mut offset = segment_offset + header.header_len as usize;
+            self.segment_headers.push(header);
+
+            while offset < records_end {
+                let mut record_data = &data[offset..records_end];
+                state.bytes_read = (offset - segment_offset) as u64;
+
+                match read_record(&mut record_data, &mut state) {
+                    Ok(()) => offset = records_end - record_data.len(),
+                    Err(error) if self.lenient => {
+                        // Resume at the next record that looks valid. Unless it's a definition, the record after it must look valid
+                        // too, or it must be the last one.
+                        let is_plausible = |next: usize| match plausible_record_len(&data[next..data_end], &state) {
+                            Some(len) => {
+                                let is_definition = data[next] & (RECORD_HDR_NORMAL | RECORD_HDR_MSG_TYPE) == RECORD_HDR_MSG_TYPE;
+                                is_definition || next + len == data_end || plausible_record_len(&data[next + len..data_end], &state).is_some()
+                            }
+                            None => false
+                        };
+                        let next = (offset + 1..data_end).find(|next| is_plausible(*next)).unwrap_or(data_end);
+                        self.skipped_ranges.push(FitSkippedRange { range: offset as u64..next as u64, error });
+                        offset = next;
                     }
+                    Err(error) => return Err(error)
                 }
             }
 
-            // Read the CRC.
-            //self.check_crc();
+            // Files that weren't closed properly don't have a CRC, but that's only allowed in lenient mode.
+            if !self.lenient {
+                let crc_bytes = data_end.checked_add(2).and_then(|crc_end| data.get(data_end..crc_end));
+                let crc_bytes = crc_bytes.ok_or(FitError::TruncatedData { offset: records_end as u64, local_msg_type: None })?;
+                expected_crc = Some(byte_array_to_uint16(crc_bytes, false));
+            }
+            match expected_crc {
+                Some(expected_crc) => {
+                    let crc = data[segment_offset..data_end].iter().fold(0, |crc, byte| check_crc(crc, *byte));
+                    self.crc_valid = crc == expected_crc && (state.segment == 0 || self.crc_valid);
+                    if crc != expected_crc && self.strict_crc {
+                        return Err(FitError::CrcMismatch { offset: data_end as u64, expected: expected_crc, computed: crc });
+                    }
+                    segment_offset = data_end + 2;
+                }
+                None => {
+                    self.crc_valid = false;
+                    segment_offset = data_end;
+                }
+            }
         }
 
+        if let Some(header) = self.segment_headers.first() {
+            self.header = header.clone();
+        }
         Ok(())
     }
 }
 
+/// Reads a FIT file, calling the callback for each data message.
+#[cfg(feature = "std")]
 pub fn read<C, R: Read>(reader: &mut BufReader<R>, callback: Callback<C>, context: &mut C) -> Result<Fit> {
     let mut fit: Fit = Fit::new();
-    fit.read(reader, callback, context)?;
+    fit.read_messages(reader, |msg| {
+        if let FitMessage::Data(data) = msg {
+            callback(data.timestamp, data.global_message_num, data.local_message_type, data.message_index, data.segment, data.fields, context);
+        }
+    })?;
 
     Ok(fit)
 }
+
@@ -0,0 +1,129 @@
+// by Michael J. Simms
+// Copyright (c) 2021 Michael J. Simms
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+// 
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+// 
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! The parts of std::io that the decoder uses. With the std feature these are std::io's own types, so any std reader
+//! can be decoded from. Without it they are small replacements that only need core and alloc, and readers such as
+//! flash storage or a serial port implement Read (and BufRead, to use a FitReader) themselves.
+
+#[cfg(feature = "std")]
+pub use std::io::{BufRead, Error, ErrorKind, Read, Result};
+
+#[cfg(not(feature = "std"))]
+pub use self::no_std_io::{BufRead, Error, ErrorKind, Read, Result};
+
+#[cfg(not(feature = "std"))]
+mod no_std_io {
+    use core::fmt;
+
+    /// The kinds of error that the decoder distinguishes between.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub enum ErrorKind {
+        UnexpectedEof,
+        Other
+    }
+
+    /// An error returned by a reader.
+    #[derive(Debug)]
+    pub struct Error {
+        kind: ErrorKind,
+        message: &'static str
+    }
+
+    impl Error {
+        pub fn new(kind: ErrorKind, message: &'static str) -> Self {
+            Error{ kind, message }
+        }
+
+        pub fn kind(&self) -> ErrorKind {
+            self.kind
+        }
+    }
+
+    impl fmt::Display for Error {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{}", self.message)
+        }
+    }
+
+    pub type Result<T> = core::result::Result<T, Error>;
+
+    /// A source of bytes.
+    pub trait Read {
+        /// Reads up to buf.len() bytes into buf, returning how many were read. Zero means the end of the data was reached.
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize>;
+
+        /// Fills buf, or returns an UnexpectedEof error if the end of the data is reached first.
+        fn read_exact(&mut self, mut buf: &mut [u8]) -> Result<()> {
+            while !buf.is_empty() {
+                match self.read(buf)? {
+                    0 => return Err(Error::new(ErrorKind::UnexpectedEof, "failed to fill whole buffer")),
+                    num_bytes => buf = &mut buf[num_bytes..]
+                }
+            }
+            Ok(())
+        }
+    }
+
+    /// A reader with an internal buffer, which lets the FitReader check for the end of the data without consuming anything.
+    pub trait BufRead: Read {
+        /// Returns the buffered data, reading more if the buffer is empty. An empty slice means the end of the data was reached.
+        fn fill_buf(&mut self) -> Result<&[u8]>;
+
+        /// Marks num_bytes of the buffered data as read.
+        fn consume(&mut self, num_bytes: usize);
+    }
+
+    impl<R: Read + ?Sized> Read for &mut R {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+            (**self).read(buf)
+        }
+    }
+
+    impl<R: BufRead + ?Sized> BufRead for &mut R {
+        fn fill_buf(&mut self) -> Result<&[u8]> {
+            (**self).fill_buf()
+        }
+
+        fn consume(&mut self, num_bytes: usize) {
+            (**self).consume(num_bytes)
+        }
+    }
+
+    impl Read for &[u8] {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+            let num_bytes = buf.len().min(self.len());
+            let (bytes, rest) = self.split_at(num_bytes);
+            buf[..num_bytes].copy_from_slice(bytes);
+            *self = rest;
+            Ok(num_bytes)
+        }
+    }
+
+    impl BufRead for &[u8] {
+        fn fill_buf(&mut self) -> Result<&[u8]> {
+            Ok(*self)
+        }
+
+        fn consume(&mut self, num_bytes: usize) {
+            *self = &self[num_bytes..];
+        }
+    }
+}
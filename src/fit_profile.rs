@@ -0,0 +1,1482 @@
+// by Michael J. Simms
+// Copyright (c) 2021 Michael J. Simms
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+// 
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+// 
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// Field descriptions from the FIT SDK's profile. The tables at the end of this file are generated from
+// tests/Messages-Table.csv and tests/Types-Table.csv by the create_profile test in lib.rs, so edit the CSV and rerun that test with
+// FIT_UPDATE_PROFILE=1 instead of editing them by hand.
+
+/// Describes part of a field that should be unpacked into another field of the same message.
+#[derive(Debug, PartialEq)]
+pub struct ComponentProfile {
+    pub field_num: u8, // The field that this component is unpacked into
+    pub bits: u8, // Number of bits, components are packed starting with the least significant bit
+    pub scale: f64, // Scale and offset of the packed value, these may differ from those of the field it is unpacked into
+    pub offset: f64,
+    pub accumulate: bool // The packed value is a rolling counter that is added to a running total
+}
+
+/// Describes a field, as listed in the FIT profile.
+#[derive(Debug, PartialEq)]
+pub struct FieldProfile {
+    pub field_num: u8,
+    pub name: &'static str,
+    pub field_type: &'static str, // Either a base type, like "uint16", or the name of a type from the types table, like "sport"
+    pub base_type: u8, // The base type underlying the field type
+    pub scale: f64, // Divide the raw value by this...
+    pub offset: f64, // ...then subtract this to get the value in the units below
+    pub units: &'static str,
+    pub components: &'static [ComponentProfile], // Parts of the field that are unpacked into other fields
+    pub subfields: &'static [SubfieldProfile] // Alternative meanings of the field, selected by the value of another field
+}
+
+/// A value of another field in the same message that selects a subfield.
+#[derive(Debug, PartialEq)]
+pub struct SubfieldReference {
+    pub field_num: u8,
+    pub value: u64
+}
+
+/// Describes a subfield, i.e. a field whose meaning depends on the value of another field,
+/// such as the duration of a workout step, which may be a time or a distance depending on the duration type.
+#[derive(Debug, PartialEq)]
+pub struct SubfieldProfile {
+    pub references: &'static [SubfieldReference], // The subfield applies if any of these match
+    pub field: FieldProfile // Has the same field number as the main field
+}
+
+impl SubfieldProfile {
+    /// Returns true if the given value of the given field selects this subfield.
+    pub fn is_selected_by(&self, field_num: u8, value: u64) -> bool {
+        self.references.iter().any(|reference| reference.field_num == field_num && reference.value == value)
+    }
+}
+
+impl FieldProfile {
+    /// Converts a raw value into the field's units.
+    pub fn apply_scale(&self, value: f64) -> f64 {
+        value / self.scale - self.offset
+    }
+}
+
+/// Describes a message, as listed in the FIT profile.
+#[derive(Debug, PartialEq)]
+pub struct MessageProfile {
+    pub global_message_num: u16,
+    pub name: &'static str,
+    pub fields: &'static [FieldProfile] // Sorted by field number
+}
+
+impl MessageProfile {
+    /// Looks up a field by its field definition number.
+    pub fn find_field(&self, field_num: u8) -> Option<&'static FieldProfile> {
+        let fields = self.fields;
+        fields.binary_search_by_key(&field_num, |field| field.field_num).ok().map(|index| &fields[index])
+    }
+
+    /// Returns true if the field is the running total of a component that is accumulated.
+    pub fn is_accumulated(&self, field_num: u8) -> bool {
+        self.fields.iter().any(|field| field.components.iter().any(|component| component.accumulate && component.field_num == field_num))
+    }
+}
+
+/// Looks up a message by its global message number.
+pub fn find_message(global_message_num: u16) -> Option<&'static MessageProfile> {
+    MESSAGE_PROFILES.binary_search_by_key(&global_message_num, |msg| msg.global_message_num).ok().map(|index| &MESSAGE_PROFILES[index])
+}
+
+/// Looks up a field by its global message number and field definition number.
+pub fn find_field(global_message_num: u16, field_num: u8) -> Option<&'static FieldProfile> {
+    find_message(global_message_num).and_then(|msg| msg.find_field(field_num))
+}
+
+/// Looks up a subfield by its global message number, the field definition number of the main field, and its name.
+pub fn find_subfield(global_message_num: u16, field_num: u8, name: &str) -> Option<&'static SubfieldProfile> {
+    find_field(global_message_num, field_num).and_then(|field| field.subfields.iter().find(|subfield| subfield.field.name == name))
+}
+
+// BEGIN GENERATED CODE
+pub static MESSAGE_PROFILES: &[MessageProfile] = &[
+    MessageProfile { global_message_num: 0, name: "file_id", fields: &[
+        FieldProfile { field_num: 0, name: "type", field_type: "file", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "manufacturer", field_type: "manufacturer", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "product", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[
+            SubfieldProfile { references: &[SubfieldReference { field_num: 1, value: 263 }], field: FieldProfile { field_num: 2, name: "favero_product", field_type: "favero_product", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 1, value: 1 }, SubfieldReference { field_num: 1, value: 15 }, SubfieldReference { field_num: 1, value: 13 }, SubfieldReference { field_num: 1, value: 89 }], field: FieldProfile { field_num: 2, name: "garmin_product", field_type: "garmin_product", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] } },
+        ] },
+        FieldProfile { field_num: 3, name: "serial_number", field_type: "uint32z", base_type: 0x8c, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "time_created", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "number", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 8, name: "product_name", field_type: "string", base_type: 0x07, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 1, name: "capabilities", fields: &[
+        FieldProfile { field_num: 0, name: "languages", field_type: "uint8z", base_type: 0x0a, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "sports", field_type: "sport_bits_0", base_type: 0x0a, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 21, name: "workouts_supported", field_type: "workout_capabilities", base_type: 0x8c, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 23, name: "connectivity_supported", field_type: "connectivity_capabilities", base_type: 0x8c, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 2, name: "device_settings", fields: &[
+        FieldProfile { field_num: 0, name: "active_time_zone", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "utc_offset", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "time_offset", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "time_mode", field_type: "time_mode", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "time_zone_offset", field_type: "sint8", base_type: 0x01, scale: 4.0, offset: 0.0, units: "hr", components: &[], subfields: &[] },
+        FieldProfile { field_num: 12, name: "backlight_mode", field_type: "backlight_mode", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 36, name: "activity_tracker_enabled", field_type: "bool", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 39, name: "clock_time", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 40, name: "pages_enabled", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 46, name: "move_alert_enabled", field_type: "bool", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 47, name: "date_mode", field_type: "date_mode", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 55, name: "display_orientation", field_type: "display_orientation", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 56, name: "mounting_side", field_type: "side", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 57, name: "default_page", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 58, name: "autosync_min_steps", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "steps", components: &[], subfields: &[] },
+        FieldProfile { field_num: 59, name: "autosync_min_time", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "minutes", components: &[], subfields: &[] },
+        FieldProfile { field_num: 80, name: "lactate_threshold_autodetect_enabled", field_type: "bool", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 86, name: "ble_auto_upload_enabled", field_type: "bool", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 89, name: "auto_sync_frequency", field_type: "auto_sync_frequency", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 90, name: "auto_activity_detect", field_type: "auto_activity_detect", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 94, name: "number_of_screens", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 95, name: "smart_notification_display_orientation", field_type: "display_orientation", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 134, name: "tap_interface", field_type: "switch", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 174, name: "tap_sensitivity", field_type: "tap_sensitivity", base_type: 0x00, scale: 1.0, offset: 1.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 3, name: "user_profile", fields: &[
+        FieldProfile { field_num: 0, name: "friendly_name", field_type: "string", base_type: 0x07, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "gender", field_type: "gender", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "age", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "years", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "height", field_type: "uint8", base_type: 0x02, scale: 100.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "weight", field_type: "uint16", base_type: 0x84, scale: 10.0, offset: 0.0, units: "kg", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "language", field_type: "language", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 6, name: "elev_setting", field_type: "display_measure", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 7, name: "weight_setting", field_type: "display_measure", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 8, name: "resting_heart_rate", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "bpm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 9, name: "default_max_running_heart_rate", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "bpm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 10, name: "default_max_biking_heart_rate", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "bpm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 11, name: "default_max_heart_rate", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "bpm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 12, name: "hr_setting", field_type: "display_heart", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 13, name: "speed_setting", field_type: "display_measure", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 14, name: "dist_setting", field_type: "display_measure", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 16, name: "power_setting", field_type: "display_power", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 17, name: "activity_class", field_type: "activity_class", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 18, name: "position_setting", field_type: "display_position", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 21, name: "temperature_setting", field_type: "display_measure", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 22, name: "local_id", field_type: "user_local_id", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 23, name: "global_id", field_type: "byte", base_type: 0x0d, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 28, name: "wake_time", field_type: "localtime_into_day", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 29, name: "sleep_time", field_type: "localtime_into_day", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 30, name: "height_setting", field_type: "display_measure", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 31, name: "user_running_step_length", field_type: "uint16", base_type: 0x84, scale: 1000.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 32, name: "user_walking_step_length", field_type: "uint16", base_type: 0x84, scale: 1000.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 47, name: "depth_setting", field_type: "display_measure", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 49, name: "dive_count", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 254, name: "message_index", field_type: "message_index", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 4, name: "hrm_profile", fields: &[
+        FieldProfile { field_num: 0, name: "enabled", field_type: "bool", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "hrm_ant_id", field_type: "uint16z", base_type: 0x8b, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "log_hrv", field_type: "bool", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "hrm_ant_id_trans_type", field_type: "uint8z", base_type: 0x0a, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 254, name: "message_index", field_type: "message_index", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 5, name: "sdm_profile", fields: &[
+        FieldProfile { field_num: 0, name: "enabled", field_type: "bool", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "sdm_ant_id", field_type: "uint16z", base_type: 0x8b, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "sdm_cal_factor", field_type: "uint16", base_type: 0x84, scale: 10.0, offset: 0.0, units: "%", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "odometer", field_type: "uint32", base_type: 0x86, scale: 100.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "speed_source", field_type: "bool", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "sdm_ant_id_trans_type", field_type: "uint8z", base_type: 0x0a, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 7, name: "odometer_rollover", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 254, name: "message_index", field_type: "message_index", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 6, name: "bike_profile", fields: &[
+        FieldProfile { field_num: 0, name: "name", field_type: "string", base_type: 0x07, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "sport", field_type: "sport", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "sub_sport", field_type: "sub_sport", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "odometer", field_type: "uint32", base_type: 0x86, scale: 100.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "bike_spd_ant_id", field_type: "uint16z", base_type: 0x8b, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "bike_cad_ant_id", field_type: "uint16z", base_type: 0x8b, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 6, name: "bike_spdcad_ant_id", field_type: "uint16z", base_type: 0x8b, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 7, name: "bike_power_ant_id", field_type: "uint16z", base_type: 0x8b, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 8, name: "custom_wheelsize", field_type: "uint16", base_type: 0x84, scale: 1000.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 9, name: "auto_wheelsize", field_type: "uint16", base_type: 0x84, scale: 1000.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 10, name: "bike_weight", field_type: "uint16", base_type: 0x84, scale: 10.0, offset: 0.0, units: "kg", components: &[], subfields: &[] },
+        FieldProfile { field_num: 11, name: "power_cal_factor", field_type: "uint16", base_type: 0x84, scale: 10.0, offset: 0.0, units: "%", components: &[], subfields: &[] },
+        FieldProfile { field_num: 12, name: "auto_wheel_cal", field_type: "bool", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 13, name: "auto_power_zero", field_type: "bool", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 14, name: "id", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 15, name: "spd_enabled", field_type: "bool", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 16, name: "cad_enabled", field_type: "bool", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 17, name: "spdcad_enabled", field_type: "bool", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 18, name: "power_enabled", field_type: "bool", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 19, name: "crank_length", field_type: "uint8", base_type: 0x02, scale: 2.0, offset: -110.0, units: "mm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 20, name: "enabled", field_type: "bool", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 21, name: "bike_spd_ant_id_trans_type", field_type: "uint8z", base_type: 0x0a, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 22, name: "bike_cad_ant_id_trans_type", field_type: "uint8z", base_type: 0x0a, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 23, name: "bike_spdcad_ant_id_trans_type", field_type: "uint8z", base_type: 0x0a, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 24, name: "bike_power_ant_id_trans_type", field_type: "uint8z", base_type: 0x0a, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 37, name: "odometer_rollover", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 38, name: "front_gear_num", field_type: "uint8z", base_type: 0x0a, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 39, name: "front_gear", field_type: "uint8z", base_type: 0x0a, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 40, name: "rear_gear_num", field_type: "uint8z", base_type: 0x0a, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 41, name: "rear_gear", field_type: "uint8z", base_type: 0x0a, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 44, name: "shimano_di2_enabled", field_type: "bool", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 254, name: "message_index", field_type: "message_index", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 7, name: "zones_target", fields: &[
+        FieldProfile { field_num: 1, name: "max_heart_rate", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "threshold_heart_rate", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "functional_threshold_power", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "hr_calc_type", field_type: "hr_zone_calc", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 7, name: "pwr_calc_type", field_type: "pwr_zone_calc", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 8, name: "hr_zone", fields: &[
+        FieldProfile { field_num: 1, name: "high_bpm", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "bpm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "name", field_type: "string", base_type: 0x07, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 254, name: "message_index", field_type: "message_index", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 9, name: "power_zone", fields: &[
+        FieldProfile { field_num: 1, name: "high_value", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "watts", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "name", field_type: "string", base_type: 0x07, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 254, name: "message_index", field_type: "message_index", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 10, name: "met_zone", fields: &[
+        FieldProfile { field_num: 1, name: "high_bpm", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "calories", field_type: "uint16", base_type: 0x84, scale: 10.0, offset: 0.0, units: "kcal / min", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "fat_calories", field_type: "uint8", base_type: 0x02, scale: 10.0, offset: 0.0, units: "kcal / min", components: &[], subfields: &[] },
+        FieldProfile { field_num: 254, name: "message_index", field_type: "message_index", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 12, name: "sport", fields: &[
+        FieldProfile { field_num: 0, name: "sport", field_type: "sport", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "sub_sport", field_type: "sub_sport", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "name", field_type: "string", base_type: 0x07, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 15, name: "goal", fields: &[
+        FieldProfile { field_num: 0, name: "sport", field_type: "sport", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "sub_sport", field_type: "sub_sport", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "start_date", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "end_date", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "type", field_type: "goal", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "value", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 6, name: "repeat", field_type: "bool", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 7, name: "target_value", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 8, name: "recurrence", field_type: "goal_recurrence", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 9, name: "recurrence_value", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 10, name: "enabled", field_type: "bool", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 11, name: "source", field_type: "goal_source", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 254, name: "message_index", field_type: "message_index", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 18, name: "session", fields: &[
+        FieldProfile { field_num: 0, name: "event", field_type: "event", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "event_type", field_type: "event_type", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "start_time", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "start_position_lat", field_type: "sint32", base_type: 0x85, scale: 1.0, offset: 0.0, units: "semicircles", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "start_position_long", field_type: "sint32", base_type: 0x85, scale: 1.0, offset: 0.0, units: "semicircles", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "sport", field_type: "sport", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 6, name: "sub_sport", field_type: "sub_sport", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 7, name: "total_elapsed_time", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 8, name: "total_timer_time", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 9, name: "total_distance", field_type: "uint32", base_type: 0x86, scale: 100.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 10, name: "total_cycles", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "cycles", components: &[], subfields: &[
+            SubfieldProfile { references: &[SubfieldReference { field_num: 5, value: 1 }, SubfieldReference { field_num: 5, value: 11 }], field: FieldProfile { field_num: 10, name: "total_strides", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "strides", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 5, value: 2 }, SubfieldReference { field_num: 5, value: 5 }, SubfieldReference { field_num: 5, value: 15 }, SubfieldReference { field_num: 5, value: 37 }], field: FieldProfile { field_num: 10, name: "total_strokes", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "strokes", components: &[], subfields: &[] } },
+        ] },
+        FieldProfile { field_num: 11, name: "total_calories", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "kcal", components: &[], subfields: &[] },
+        FieldProfile { field_num: 13, name: "total_fat_calories", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "kcal", components: &[], subfields: &[] },
+        FieldProfile { field_num: 14, name: "avg_speed", field_type: "uint16", base_type: 0x84, scale: 1000.0, offset: 0.0, units: "m/s", components: &[ComponentProfile { field_num: 124, bits: 16, scale: 1000.0, offset: 0.0, accumulate: false }], subfields: &[] },
+        FieldProfile { field_num: 15, name: "max_speed", field_type: "uint16", base_type: 0x84, scale: 1000.0, offset: 0.0, units: "m/s", components: &[ComponentProfile { field_num: 125, bits: 16, scale: 1000.0, offset: 0.0, accumulate: false }], subfields: &[] },
+        FieldProfile { field_num: 16, name: "avg_heart_rate", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "bpm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 17, name: "max_heart_rate", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "bpm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 18, name: "avg_cadence", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "rpm", components: &[], subfields: &[
+            SubfieldProfile { references: &[SubfieldReference { field_num: 5, value: 1 }], field: FieldProfile { field_num: 18, name: "avg_running_cadence", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "strides/min", components: &[], subfields: &[] } },
+        ] },
+        FieldProfile { field_num: 19, name: "max_cadence", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "rpm", components: &[], subfields: &[
+            SubfieldProfile { references: &[SubfieldReference { field_num: 5, value: 1 }], field: FieldProfile { field_num: 19, name: "max_running_cadence", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "strides/min", components: &[], subfields: &[] } },
+        ] },
+        FieldProfile { field_num: 20, name: "avg_power", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "watts", components: &[], subfields: &[] },
+        FieldProfile { field_num: 21, name: "max_power", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "watts", components: &[], subfields: &[] },
+        FieldProfile { field_num: 22, name: "total_ascent", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 23, name: "total_descent", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 24, name: "total_training_effect", field_type: "uint8", base_type: 0x02, scale: 10.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 25, name: "first_lap_index", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 26, name: "num_laps", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 27, name: "event_group", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 28, name: "trigger", field_type: "session_trigger", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 29, name: "nec_lat", field_type: "sint32", base_type: 0x85, scale: 1.0, offset: 0.0, units: "semicircles", components: &[], subfields: &[] },
+        FieldProfile { field_num: 30, name: "nec_long", field_type: "sint32", base_type: 0x85, scale: 1.0, offset: 0.0, units: "semicircles", components: &[], subfields: &[] },
+        FieldProfile { field_num: 31, name: "swc_lat", field_type: "sint32", base_type: 0x85, scale: 1.0, offset: 0.0, units: "semicircles", components: &[], subfields: &[] },
+        FieldProfile { field_num: 32, name: "swc_long", field_type: "sint32", base_type: 0x85, scale: 1.0, offset: 0.0, units: "semicircles", components: &[], subfields: &[] },
+        FieldProfile { field_num: 33, name: "num_lengths", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "lengths", components: &[], subfields: &[] },
+        FieldProfile { field_num: 34, name: "normalized_power", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "watts", components: &[], subfields: &[] },
+        FieldProfile { field_num: 35, name: "training_stress_score", field_type: "uint16", base_type: 0x84, scale: 10.0, offset: 0.0, units: "tss", components: &[], subfields: &[] },
+        FieldProfile { field_num: 36, name: "intensity_factor", field_type: "uint16", base_type: 0x84, scale: 1000.0, offset: 0.0, units: "if", components: &[], subfields: &[] },
+        FieldProfile { field_num: 37, name: "left_right_balance", field_type: "left_right_balance_100", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 41, name: "avg_stroke_count", field_type: "uint32", base_type: 0x86, scale: 10.0, offset: 0.0, units: "strokes/lap", components: &[], subfields: &[] },
+        FieldProfile { field_num: 42, name: "avg_stroke_distance", field_type: "uint16", base_type: 0x84, scale: 100.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 43, name: "swim_stroke", field_type: "swim_stroke", base_type: 0x00, scale: 1.0, offset: 0.0, units: "swim_stroke", components: &[], subfields: &[] },
+        FieldProfile { field_num: 44, name: "pool_length", field_type: "uint16", base_type: 0x84, scale: 100.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 45, name: "threshold_power", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "watts", components: &[], subfields: &[] },
+        FieldProfile { field_num: 46, name: "pool_length_unit", field_type: "display_measure", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 47, name: "num_active_lengths", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "lengths", components: &[], subfields: &[] },
+        FieldProfile { field_num: 48, name: "total_work", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "J", components: &[], subfields: &[] },
+        FieldProfile { field_num: 49, name: "avg_altitude", field_type: "uint16", base_type: 0x84, scale: 5.0, offset: 500.0, units: "m", components: &[ComponentProfile { field_num: 126, bits: 16, scale: 5.0, offset: 500.0, accumulate: false }], subfields: &[] },
+        FieldProfile { field_num: 50, name: "max_altitude", field_type: "uint16", base_type: 0x84, scale: 5.0, offset: 500.0, units: "m", components: &[ComponentProfile { field_num: 128, bits: 16, scale: 5.0, offset: 500.0, accumulate: false }], subfields: &[] },
+        FieldProfile { field_num: 51, name: "gps_accuracy", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 52, name: "avg_grade", field_type: "sint16", base_type: 0x83, scale: 100.0, offset: 0.0, units: "%", components: &[], subfields: &[] },
+        FieldProfile { field_num: 53, name: "avg_pos_grade", field_type: "sint16", base_type: 0x83, scale: 100.0, offset: 0.0, units: "%", components: &[], subfields: &[] },
+        FieldProfile { field_num: 54, name: "avg_neg_grade", field_type: "sint16", base_type: 0x83, scale: 100.0, offset: 0.0, units: "%", components: &[], subfields: &[] },
+        FieldProfile { field_num: 55, name: "max_pos_grade", field_type: "sint16", base_type: 0x83, scale: 100.0, offset: 0.0, units: "%", components: &[], subfields: &[] },
+        FieldProfile { field_num: 56, name: "max_neg_grade", field_type: "sint16", base_type: 0x83, scale: 100.0, offset: 0.0, units: "%", components: &[], subfields: &[] },
+        FieldProfile { field_num: 57, name: "avg_temperature", field_type: "sint8", base_type: 0x01, scale: 1.0, offset: 0.0, units: "C", components: &[], subfields: &[] },
+        FieldProfile { field_num: 58, name: "max_temperature", field_type: "sint8", base_type: 0x01, scale: 1.0, offset: 0.0, units: "C", components: &[], subfields: &[] },
+        FieldProfile { field_num: 59, name: "total_moving_time", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 60, name: "avg_pos_vertical_speed", field_type: "sint16", base_type: 0x83, scale: 1000.0, offset: 0.0, units: "m/s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 61, name: "avg_neg_vertical_speed", field_type: "sint16", base_type: 0x83, scale: 1000.0, offset: 0.0, units: "m/s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 62, name: "max_pos_vertical_speed", field_type: "sint16", base_type: 0x83, scale: 1000.0, offset: 0.0, units: "m/s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 63, name: "max_neg_vertical_speed", field_type: "sint16", base_type: 0x83, scale: 1000.0, offset: 0.0, units: "m/s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 64, name: "min_heart_rate", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "bpm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 65, name: "time_in_hr_zone", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 66, name: "time_in_speed_zone", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 67, name: "time_in_cadence_zone", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 68, name: "time_in_power_zone", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 69, name: "avg_lap_time", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 70, name: "best_lap_index", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 71, name: "min_altitude", field_type: "uint16", base_type: 0x84, scale: 5.0, offset: 500.0, units: "m", components: &[ComponentProfile { field_num: 127, bits: 16, scale: 5.0, offset: 500.0, accumulate: false }], subfields: &[] },
+        FieldProfile { field_num: 82, name: "player_score", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 83, name: "opponent_score", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 84, name: "opponent_name", field_type: "string", base_type: 0x07, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 85, name: "stroke_count", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "counts", components: &[], subfields: &[] },
+        FieldProfile { field_num: 86, name: "zone_count", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "counts", components: &[], subfields: &[] },
+        FieldProfile { field_num: 87, name: "max_ball_speed", field_type: "uint16", base_type: 0x84, scale: 100.0, offset: 0.0, units: "m/s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 88, name: "avg_ball_speed", field_type: "uint16", base_type: 0x84, scale: 100.0, offset: 0.0, units: "m/s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 89, name: "avg_vertical_oscillation", field_type: "uint16", base_type: 0x84, scale: 10.0, offset: 0.0, units: "mm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 90, name: "avg_stance_time_percent", field_type: "uint16", base_type: 0x84, scale: 100.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 91, name: "avg_stance_time", field_type: "uint16", base_type: 0x84, scale: 10.0, offset: 0.0, units: "ms", components: &[], subfields: &[] },
+        FieldProfile { field_num: 92, name: "avg_fractional_cadence", field_type: "uint8", base_type: 0x02, scale: 128.0, offset: 0.0, units: "rpm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 93, name: "max_fractional_cadence", field_type: "uint8", base_type: 0x02, scale: 128.0, offset: 0.0, units: "rpm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 94, name: "total_fractional_cycles", field_type: "uint8", base_type: 0x02, scale: 128.0, offset: 0.0, units: "cycles", components: &[], subfields: &[] },
+        FieldProfile { field_num: 95, name: "avg_total_hemoglobin_conc", field_type: "uint16", base_type: 0x84, scale: 100.0, offset: 0.0, units: "g/dL", components: &[], subfields: &[] },
+        FieldProfile { field_num: 96, name: "min_total_hemoglobin_conc", field_type: "uint16", base_type: 0x84, scale: 100.0, offset: 0.0, units: "g/dL", components: &[], subfields: &[] },
+        FieldProfile { field_num: 97, name: "max_total_hemoglobin_conc", field_type: "uint16", base_type: 0x84, scale: 100.0, offset: 0.0, units: "g/dL", components: &[], subfields: &[] },
+        FieldProfile { field_num: 98, name: "avg_saturated_hemoglobin_percent", field_type: "uint16", base_type: 0x84, scale: 10.0, offset: 0.0, units: "%", components: &[], subfields: &[] },
+        FieldProfile { field_num: 99, name: "min_saturated_hemoglobin_percent", field_type: "uint16", base_type: 0x84, scale: 10.0, offset: 0.0, units: "%", components: &[], subfields: &[] },
+        FieldProfile { field_num: 100, name: "max_saturated_hemoglobin_percent", field_type: "uint16", base_type: 0x84, scale: 10.0, offset: 0.0, units: "%", components: &[], subfields: &[] },
+        FieldProfile { field_num: 101, name: "avg_left_torque_effectiveness", field_type: "uint8", base_type: 0x02, scale: 2.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 102, name: "avg_right_torque_effectiveness", field_type: "uint8", base_type: 0x02, scale: 2.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 103, name: "avg_left_pedal_smoothness", field_type: "uint8", base_type: 0x02, scale: 2.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 104, name: "avg_right_pedal_smoothness", field_type: "uint8", base_type: 0x02, scale: 2.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 105, name: "avg_combined_pedal_smoothness", field_type: "uint8", base_type: 0x02, scale: 2.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 111, name: "sport_index", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 112, name: "time_standing", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 113, name: "stand_count", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 114, name: "avg_left_pco", field_type: "sint8", base_type: 0x01, scale: 1.0, offset: 0.0, units: "mm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 115, name: "avg_right_pco", field_type: "sint8", base_type: 0x01, scale: 1.0, offset: 0.0, units: "mm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 116, name: "avg_left_power_phase", field_type: "uint8", base_type: 0x02, scale: 0.7111111, offset: 0.0, units: "degrees", components: &[], subfields: &[] },
+        FieldProfile { field_num: 117, name: "avg_left_power_phase_peak", field_type: "uint8", base_type: 0x02, scale: 0.7111111, offset: 0.0, units: "degrees", components: &[], subfields: &[] },
+        FieldProfile { field_num: 118, name: "avg_right_power_phase", field_type: "uint8", base_type: 0x02, scale: 0.7111111, offset: 0.0, units: "degrees", components: &[], subfields: &[] },
+        FieldProfile { field_num: 119, name: "avg_right_power_phase_peak", field_type: "uint8", base_type: 0x02, scale: 0.7111111, offset: 0.0, units: "degrees", components: &[], subfields: &[] },
+        FieldProfile { field_num: 120, name: "avg_power_position", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "watts", components: &[], subfields: &[] },
+        FieldProfile { field_num: 121, name: "max_power_position", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "watts", components: &[], subfields: &[] },
+        FieldProfile { field_num: 122, name: "avg_cadence_position", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "rpm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 123, name: "max_cadence_position", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "rpm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 124, name: "enhanced_avg_speed", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "m/s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 125, name: "enhanced_max_speed", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "m/s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 126, name: "enhanced_avg_altitude", field_type: "uint32", base_type: 0x86, scale: 5.0, offset: 500.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 127, name: "enhanced_min_altitude", field_type: "uint32", base_type: 0x86, scale: 5.0, offset: 500.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 128, name: "enhanced_max_altitude", field_type: "uint32", base_type: 0x86, scale: 5.0, offset: 500.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 129, name: "avg_lev_motor_power", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "watts", components: &[], subfields: &[] },
+        FieldProfile { field_num: 130, name: "max_lev_motor_power", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "watts", components: &[], subfields: &[] },
+        FieldProfile { field_num: 131, name: "lev_battery_consumption", field_type: "uint8", base_type: 0x02, scale: 2.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 132, name: "avg_vertical_ratio", field_type: "uint16", base_type: 0x84, scale: 100.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 133, name: "avg_stance_time_balance", field_type: "uint16", base_type: 0x84, scale: 100.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 134, name: "avg_step_length", field_type: "uint16", base_type: 0x84, scale: 10.0, offset: 0.0, units: "mm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 137, name: "total_anaerobic_training_effect", field_type: "uint8", base_type: 0x02, scale: 10.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 139, name: "avg_vam", field_type: "uint16", base_type: 0x84, scale: 1000.0, offset: 0.0, units: "m/s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 181, name: "total_grit", field_type: "float32", base_type: 0x88, scale: 1.0, offset: 0.0, units: "kGrit", components: &[], subfields: &[] },
+        FieldProfile { field_num: 182, name: "total_flow", field_type: "float32", base_type: 0x88, scale: 1.0, offset: 0.0, units: "Flow", components: &[], subfields: &[] },
+        FieldProfile { field_num: 183, name: "jump_count", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 186, name: "avg_grit", field_type: "float32", base_type: 0x88, scale: 1.0, offset: 0.0, units: "kGrit", components: &[], subfields: &[] },
+        FieldProfile { field_num: 187, name: "avg_flow", field_type: "float32", base_type: 0x88, scale: 1.0, offset: 0.0, units: "Flow", components: &[], subfields: &[] },
+        FieldProfile { field_num: 199, name: "total_fractional_ascent", field_type: "uint8", base_type: 0x02, scale: 100.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 200, name: "total_fractional_descent", field_type: "uint8", base_type: 0x02, scale: 100.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 253, name: "timestamp", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 254, name: "message_index", field_type: "message_index", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 19, name: "lap", fields: &[
+        FieldProfile { field_num: 0, name: "event", field_type: "event", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "event_type", field_type: "event_type", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "start_time", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "start_position_lat", field_type: "sint32", base_type: 0x85, scale: 1.0, offset: 0.0, units: "semicircles", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "start_position_long", field_type: "sint32", base_type: 0x85, scale: 1.0, offset: 0.0, units: "semicircles", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "end_position_lat", field_type: "sint32", base_type: 0x85, scale: 1.0, offset: 0.0, units: "semicircles", components: &[], subfields: &[] },
+        FieldProfile { field_num: 6, name: "end_position_long", field_type: "sint32", base_type: 0x85, scale: 1.0, offset: 0.0, units: "semicircles", components: &[], subfields: &[] },
+        FieldProfile { field_num: 7, name: "total_elapsed_time", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 8, name: "total_timer_time", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 9, name: "total_distance", field_type: "uint32", base_type: 0x86, scale: 100.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 10, name: "total_cycles", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "cycles", components: &[], subfields: &[
+            SubfieldProfile { references: &[SubfieldReference { field_num: 25, value: 1 }, SubfieldReference { field_num: 25, value: 11 }], field: FieldProfile { field_num: 10, name: "total_strides", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "strides", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 25, value: 2 }, SubfieldReference { field_num: 25, value: 5 }, SubfieldReference { field_num: 25, value: 15 }, SubfieldReference { field_num: 25, value: 37 }], field: FieldProfile { field_num: 10, name: "total_strokes", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "strokes", components: &[], subfields: &[] } },
+        ] },
+        FieldProfile { field_num: 11, name: "total_calories", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "kcal", components: &[], subfields: &[] },
+        FieldProfile { field_num: 12, name: "total_fat_calories", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "kcal", components: &[], subfields: &[] },
+        FieldProfile { field_num: 13, name: "avg_speed", field_type: "uint16", base_type: 0x84, scale: 1000.0, offset: 0.0, units: "m/s", components: &[ComponentProfile { field_num: 110, bits: 16, scale: 1000.0, offset: 0.0, accumulate: false }], subfields: &[] },
+        FieldProfile { field_num: 14, name: "max_speed", field_type: "uint16", base_type: 0x84, scale: 1000.0, offset: 0.0, units: "m/s", components: &[ComponentProfile { field_num: 111, bits: 16, scale: 1000.0, offset: 0.0, accumulate: false }], subfields: &[] },
+        FieldProfile { field_num: 15, name: "avg_heart_rate", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "bpm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 16, name: "max_heart_rate", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "bpm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 17, name: "avg_cadence", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "rpm", components: &[], subfields: &[
+            SubfieldProfile { references: &[SubfieldReference { field_num: 25, value: 1 }], field: FieldProfile { field_num: 17, name: "avg_running_cadence", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "strides/min", components: &[], subfields: &[] } },
+        ] },
+        FieldProfile { field_num: 18, name: "max_cadence", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "rpm", components: &[], subfields: &[
+            SubfieldProfile { references: &[SubfieldReference { field_num: 25, value: 1 }], field: FieldProfile { field_num: 18, name: "max_running_cadence", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "strides/min", components: &[], subfields: &[] } },
+        ] },
+        FieldProfile { field_num: 19, name: "avg_power", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "watts", components: &[], subfields: &[] },
+        FieldProfile { field_num: 20, name: "max_power", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "watts", components: &[], subfields: &[] },
+        FieldProfile { field_num: 21, name: "total_ascent", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 22, name: "total_descent", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 23, name: "intensity", field_type: "intensity", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 24, name: "lap_trigger", field_type: "lap_trigger", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 25, name: "sport", field_type: "sport", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 26, name: "event_group", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 32, name: "num_lengths", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "lengths", components: &[], subfields: &[] },
+        FieldProfile { field_num: 33, name: "normalized_power", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "watts", components: &[], subfields: &[] },
+        FieldProfile { field_num: 34, name: "left_right_balance", field_type: "left_right_balance_100", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 35, name: "first_length_index", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 37, name: "avg_stroke_distance", field_type: "uint16", base_type: 0x84, scale: 100.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 38, name: "swim_stroke", field_type: "swim_stroke", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 39, name: "sub_sport", field_type: "sub_sport", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 40, name: "num_active_lengths", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "lengths", components: &[], subfields: &[] },
+        FieldProfile { field_num: 41, name: "total_work", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "J", components: &[], subfields: &[] },
+        FieldProfile { field_num: 42, name: "avg_altitude", field_type: "uint16", base_type: 0x84, scale: 5.0, offset: 500.0, units: "m", components: &[ComponentProfile { field_num: 112, bits: 16, scale: 5.0, offset: 500.0, accumulate: false }], subfields: &[] },
+        FieldProfile { field_num: 43, name: "max_altitude", field_type: "uint16", base_type: 0x84, scale: 5.0, offset: 500.0, units: "m", components: &[ComponentProfile { field_num: 114, bits: 16, scale: 5.0, offset: 500.0, accumulate: false }], subfields: &[] },
+        FieldProfile { field_num: 44, name: "gps_accuracy", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 45, name: "avg_grade", field_type: "sint16", base_type: 0x83, scale: 100.0, offset: 0.0, units: "%", components: &[], subfields: &[] },
+        FieldProfile { field_num: 46, name: "avg_pos_grade", field_type: "sint16", base_type: 0x83, scale: 100.0, offset: 0.0, units: "%", components: &[], subfields: &[] },
+        FieldProfile { field_num: 47, name: "avg_neg_grade", field_type: "sint16", base_type: 0x83, scale: 100.0, offset: 0.0, units: "%", components: &[], subfields: &[] },
+        FieldProfile { field_num: 48, name: "max_pos_grade", field_type: "sint16", base_type: 0x83, scale: 100.0, offset: 0.0, units: "%", components: &[], subfields: &[] },
+        FieldProfile { field_num: 49, name: "max_neg_grade", field_type: "sint16", base_type: 0x83, scale: 100.0, offset: 0.0, units: "%", components: &[], subfields: &[] },
+        FieldProfile { field_num: 50, name: "avg_temperature", field_type: "sint8", base_type: 0x01, scale: 1.0, offset: 0.0, units: "C", components: &[], subfields: &[] },
+        FieldProfile { field_num: 51, name: "max_temperature", field_type: "sint8", base_type: 0x01, scale: 1.0, offset: 0.0, units: "C", components: &[], subfields: &[] },
+        FieldProfile { field_num: 52, name: "total_moving_time", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 53, name: "avg_pos_vertical_speed", field_type: "sint16", base_type: 0x83, scale: 1000.0, offset: 0.0, units: "m/s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 54, name: "avg_neg_vertical_speed", field_type: "sint16", base_type: 0x83, scale: 1000.0, offset: 0.0, units: "m/s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 55, name: "max_pos_vertical_speed", field_type: "sint16", base_type: 0x83, scale: 1000.0, offset: 0.0, units: "m/s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 56, name: "max_neg_vertical_speed", field_type: "sint16", base_type: 0x83, scale: 1000.0, offset: 0.0, units: "m/s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 57, name: "time_in_hr_zone", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 58, name: "time_in_speed_zone", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 59, name: "time_in_cadence_zone", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 60, name: "time_in_power_zone", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 61, name: "repetition_num", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 62, name: "min_altitude", field_type: "uint16", base_type: 0x84, scale: 5.0, offset: 500.0, units: "m", components: &[ComponentProfile { field_num: 113, bits: 16, scale: 5.0, offset: 500.0, accumulate: false }], subfields: &[] },
+        FieldProfile { field_num: 63, name: "min_heart_rate", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "bpm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 71, name: "wkt_step_index", field_type: "message_index", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 74, name: "opponent_score", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 75, name: "stroke_count", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "counts", components: &[], subfields: &[] },
+        FieldProfile { field_num: 76, name: "zone_count", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "counts", components: &[], subfields: &[] },
+        FieldProfile { field_num: 77, name: "avg_vertical_oscillation", field_type: "uint16", base_type: 0x84, scale: 10.0, offset: 0.0, units: "mm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 78, name: "avg_stance_time_percent", field_type: "uint16", base_type: 0x84, scale: 100.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 79, name: "avg_stance_time", field_type: "uint16", base_type: 0x84, scale: 10.0, offset: 0.0, units: "ms", components: &[], subfields: &[] },
+        FieldProfile { field_num: 80, name: "avg_fractional_cadence", field_type: "uint8", base_type: 0x02, scale: 128.0, offset: 0.0, units: "rpm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 81, name: "max_fractional_cadence", field_type: "uint8", base_type: 0x02, scale: 128.0, offset: 0.0, units: "rpm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 82, name: "total_fractional_cycles", field_type: "uint8", base_type: 0x02, scale: 128.0, offset: 0.0, units: "cycles", components: &[], subfields: &[] },
+        FieldProfile { field_num: 83, name: "player_score", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 84, name: "avg_total_hemoglobin_conc", field_type: "uint16", base_type: 0x84, scale: 100.0, offset: 0.0, units: "g/dL", components: &[], subfields: &[] },
+        FieldProfile { field_num: 85, name: "min_total_hemoglobin_conc", field_type: "uint16", base_type: 0x84, scale: 100.0, offset: 0.0, units: "g/dL", components: &[], subfields: &[] },
+        FieldProfile { field_num: 86, name: "max_total_hemoglobin_conc", field_type: "uint16", base_type: 0x84, scale: 100.0, offset: 0.0, units: "g/dL", components: &[], subfields: &[] },
+        FieldProfile { field_num: 87, name: "avg_saturated_hemoglobin_percent", field_type: "uint16", base_type: 0x84, scale: 10.0, offset: 0.0, units: "%", components: &[], subfields: &[] },
+        FieldProfile { field_num: 88, name: "min_saturated_hemoglobin_percent", field_type: "uint16", base_type: 0x84, scale: 10.0, offset: 0.0, units: "%", components: &[], subfields: &[] },
+        FieldProfile { field_num: 89, name: "max_saturated_hemoglobin_percent", field_type: "uint16", base_type: 0x84, scale: 10.0, offset: 0.0, units: "%", components: &[], subfields: &[] },
+        FieldProfile { field_num: 91, name: "avg_left_torque_effectiveness", field_type: "uint8", base_type: 0x02, scale: 2.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 92, name: "avg_right_torque_effectiveness", field_type: "uint8", base_type: 0x02, scale: 2.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 93, name: "avg_left_pedal_smoothness", field_type: "uint8", base_type: 0x02, scale: 2.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 94, name: "avg_right_pedal_smoothness", field_type: "uint8", base_type: 0x02, scale: 2.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 95, name: "avg_combined_pedal_smoothness", field_type: "uint8", base_type: 0x02, scale: 2.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 98, name: "time_standing", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 99, name: "stand_count", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 100, name: "avg_left_pco", field_type: "sint8", base_type: 0x01, scale: 1.0, offset: 0.0, units: "mm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 101, name: "avg_right_pco", field_type: "sint8", base_type: 0x01, scale: 1.0, offset: 0.0, units: "mm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 102, name: "avg_left_power_phase", field_type: "uint8", base_type: 0x02, scale: 0.7111111, offset: 0.0, units: "degrees", components: &[], subfields: &[] },
+        FieldProfile { field_num: 103, name: "avg_left_power_phase_peak", field_type: "uint8", base_type: 0x02, scale: 0.7111111, offset: 0.0, units: "degrees", components: &[], subfields: &[] },
+        FieldProfile { field_num: 104, name: "avg_right_power_phase", field_type: "uint8", base_type: 0x02, scale: 0.7111111, offset: 0.0, units: "degrees", components: &[], subfields: &[] },
+        FieldProfile { field_num: 105, name: "avg_right_power_phase_peak", field_type: "uint8", base_type: 0x02, scale: 0.7111111, offset: 0.0, units: "degrees", components: &[], subfields: &[] },
+        FieldProfile { field_num: 106, name: "avg_power_position", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "watts", components: &[], subfields: &[] },
+        FieldProfile { field_num: 107, name: "max_power_position", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "watts", components: &[], subfields: &[] },
+        FieldProfile { field_num: 108, name: "avg_cadence_position", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "rpm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 109, name: "max_cadence_position", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "rpm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 110, name: "enhanced_avg_speed", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "m/s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 111, name: "enhanced_max_speed", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "m/s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 112, name: "enhanced_avg_altitude", field_type: "uint32", base_type: 0x86, scale: 5.0, offset: 500.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 113, name: "enhanced_min_altitude", field_type: "uint32", base_type: 0x86, scale: 5.0, offset: 500.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 114, name: "enhanced_max_altitude", field_type: "uint32", base_type: 0x86, scale: 5.0, offset: 500.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 115, name: "avg_lev_motor_power", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "watts", components: &[], subfields: &[] },
+        FieldProfile { field_num: 116, name: "max_lev_motor_power", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "watts", components: &[], subfields: &[] },
+        FieldProfile { field_num: 117, name: "lev_battery_consumption", field_type: "uint8", base_type: 0x02, scale: 2.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 118, name: "avg_vertical_ratio", field_type: "uint16", base_type: 0x84, scale: 100.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 119, name: "avg_stance_time_balance", field_type: "uint16", base_type: 0x84, scale: 100.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 120, name: "avg_step_length", field_type: "uint16", base_type: 0x84, scale: 10.0, offset: 0.0, units: "mm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 121, name: "avg_vam", field_type: "uint16", base_type: 0x84, scale: 1000.0, offset: 0.0, units: "m/s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 149, name: "total_grit", field_type: "float32", base_type: 0x88, scale: 1.0, offset: 0.0, units: "kGrit", components: &[], subfields: &[] },
+        FieldProfile { field_num: 150, name: "total_flow", field_type: "float32", base_type: 0x88, scale: 1.0, offset: 0.0, units: "Flow", components: &[], subfields: &[] },
+        FieldProfile { field_num: 151, name: "jump_count", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 153, name: "avg_grit", field_type: "float32", base_type: 0x88, scale: 1.0, offset: 0.0, units: "kGrit", components: &[], subfields: &[] },
+        FieldProfile { field_num: 154, name: "avg_flow", field_type: "float32", base_type: 0x88, scale: 1.0, offset: 0.0, units: "Flow", components: &[], subfields: &[] },
+        FieldProfile { field_num: 156, name: "total_fractional_ascent", field_type: "uint8", base_type: 0x02, scale: 100.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 157, name: "total_fractional_descent", field_type: "uint8", base_type: 0x02, scale: 100.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 253, name: "timestamp", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 254, name: "message_index", field_type: "message_index", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 20, name: "record", fields: &[
+        FieldProfile { field_num: 0, name: "position_lat", field_type: "sint32", base_type: 0x85, scale: 1.0, offset: 0.0, units: "semicircles", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "position_long", field_type: "sint32", base_type: 0x85, scale: 1.0, offset: 0.0, units: "semicircles", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "altitude", field_type: "uint16", base_type: 0x84, scale: 5.0, offset: 500.0, units: "m", components: &[ComponentProfile { field_num: 78, bits: 16, scale: 5.0, offset: 500.0, accumulate: false }], subfields: &[] },
+        FieldProfile { field_num: 3, name: "heart_rate", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "bpm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "cadence", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "rpm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "distance", field_type: "uint32", base_type: 0x86, scale: 100.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 6, name: "speed", field_type: "uint16", base_type: 0x84, scale: 1000.0, offset: 0.0, units: "m/s", components: &[ComponentProfile { field_num: 73, bits: 16, scale: 1000.0, offset: 0.0, accumulate: false }], subfields: &[] },
+        FieldProfile { field_num: 7, name: "power", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "watts", components: &[], subfields: &[] },
+        FieldProfile { field_num: 8, name: "compressed_speed_distance", field_type: "byte", base_type: 0x0d, scale: 1.0, offset: 0.0, units: "", components: &[ComponentProfile { field_num: 6, bits: 12, scale: 100.0, offset: 0.0, accumulate: false }, ComponentProfile { field_num: 5, bits: 12, scale: 16.0, offset: 0.0, accumulate: true }], subfields: &[] },
+        FieldProfile { field_num: 9, name: "grade", field_type: "sint16", base_type: 0x83, scale: 100.0, offset: 0.0, units: "%", components: &[], subfields: &[] },
+        FieldProfile { field_num: 10, name: "resistance", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 11, name: "time_from_course", field_type: "sint32", base_type: 0x85, scale: 1000.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 12, name: "cycle_length", field_type: "uint8", base_type: 0x02, scale: 100.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 13, name: "temperature", field_type: "sint8", base_type: 0x01, scale: 1.0, offset: 0.0, units: "C", components: &[], subfields: &[] },
+        FieldProfile { field_num: 17, name: "speed_1s", field_type: "uint8", base_type: 0x02, scale: 16.0, offset: 0.0, units: "m/s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 18, name: "cycles", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "cycles", components: &[ComponentProfile { field_num: 19, bits: 8, scale: 1.0, offset: 0.0, accumulate: true }], subfields: &[] },
+        FieldProfile { field_num: 19, name: "total_cycles", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "cycles", components: &[], subfields: &[] },
+        FieldProfile { field_num: 28, name: "compressed_accumulated_power", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "watts", components: &[ComponentProfile { field_num: 29, bits: 16, scale: 1.0, offset: 0.0, accumulate: true }], subfields: &[] },
+        FieldProfile { field_num: 29, name: "accumulated_power", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "watts", components: &[], subfields: &[] },
+        FieldProfile { field_num: 30, name: "left_right_balance", field_type: "left_right_balance", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 31, name: "gps_accuracy", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 32, name: "vertical_speed", field_type: "sint16", base_type: 0x83, scale: 1000.0, offset: 0.0, units: "m/s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 33, name: "calories", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "kcal", components: &[], subfields: &[] },
+        FieldProfile { field_num: 39, name: "vertical_oscillation", field_type: "uint16", base_type: 0x84, scale: 10.0, offset: 0.0, units: "mm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 40, name: "stance_time_percent", field_type: "uint16", base_type: 0x84, scale: 100.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 41, name: "stance_time", field_type: "uint16", base_type: 0x84, scale: 10.0, offset: 0.0, units: "ms", components: &[], subfields: &[] },
+        FieldProfile { field_num: 42, name: "activity_type", field_type: "activity_type", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 43, name: "left_torque_effectiveness", field_type: "uint8", base_type: 0x02, scale: 2.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 44, name: "right_torque_effectiveness", field_type: "uint8", base_type: 0x02, scale: 2.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 45, name: "left_pedal_smoothness", field_type: "uint8", base_type: 0x02, scale: 2.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 46, name: "right_pedal_smoothness", field_type: "uint8", base_type: 0x02, scale: 2.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 47, name: "combined_pedal_smoothness", field_type: "uint8", base_type: 0x02, scale: 2.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 48, name: "time128", field_type: "uint8", base_type: 0x02, scale: 128.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 49, name: "stroke_type", field_type: "stroke_type", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 50, name: "zone", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 51, name: "ball_speed", field_type: "uint16", base_type: 0x84, scale: 100.0, offset: 0.0, units: "m/s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 52, name: "cadence256", field_type: "uint16", base_type: 0x84, scale: 256.0, offset: 0.0, units: "rpm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 53, name: "fractional_cadence", field_type: "uint8", base_type: 0x02, scale: 128.0, offset: 0.0, units: "rpm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 54, name: "total_hemoglobin_conc", field_type: "uint16", base_type: 0x84, scale: 100.0, offset: 0.0, units: "g/dL", components: &[], subfields: &[] },
+        FieldProfile { field_num: 55, name: "total_hemoglobin_conc_min", field_type: "uint16", base_type: 0x84, scale: 100.0, offset: 0.0, units: "g/dL", components: &[], subfields: &[] },
+        FieldProfile { field_num: 56, name: "total_hemoglobin_conc_max", field_type: "uint16", base_type: 0x84, scale: 100.0, offset: 0.0, units: "g/dL", components: &[], subfields: &[] },
+        FieldProfile { field_num: 57, name: "saturated_hemoglobin_percent", field_type: "uint16", base_type: 0x84, scale: 10.0, offset: 0.0, units: "%", components: &[], subfields: &[] },
+        FieldProfile { field_num: 58, name: "saturated_hemoglobin_percent_min", field_type: "uint16", base_type: 0x84, scale: 10.0, offset: 0.0, units: "%", components: &[], subfields: &[] },
+        FieldProfile { field_num: 59, name: "saturated_hemoglobin_percent_max", field_type: "uint16", base_type: 0x84, scale: 10.0, offset: 0.0, units: "%", components: &[], subfields: &[] },
+        FieldProfile { field_num: 62, name: "device_index", field_type: "device_index", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 67, name: "left_pco", field_type: "sint8", base_type: 0x01, scale: 1.0, offset: 0.0, units: "mm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 68, name: "right_pco", field_type: "sint8", base_type: 0x01, scale: 1.0, offset: 0.0, units: "mm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 69, name: "left_power_phase", field_type: "uint8", base_type: 0x02, scale: 0.7111111, offset: 0.0, units: "degrees", components: &[], subfields: &[] },
+        FieldProfile { field_num: 70, name: "left_power_phase_peak", field_type: "uint8", base_type: 0x02, scale: 0.7111111, offset: 0.0, units: "degrees", components: &[], subfields: &[] },
+        FieldProfile { field_num: 71, name: "right_power_phase", field_type: "uint8", base_type: 0x02, scale: 0.7111111, offset: 0.0, units: "degrees", components: &[], subfields: &[] },
+        FieldProfile { field_num: 72, name: "right_power_phase_peak", field_type: "uint8", base_type: 0x02, scale: 0.7111111, offset: 0.0, units: "degrees", components: &[], subfields: &[] },
+        FieldProfile { field_num: 73, name: "enhanced_speed", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "m/s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 78, name: "enhanced_altitude", field_type: "uint32", base_type: 0x86, scale: 5.0, offset: 500.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 81, name: "battery_soc", field_type: "uint8", base_type: 0x02, scale: 2.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 82, name: "motor_power", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "watts", components: &[], subfields: &[] },
+        FieldProfile { field_num: 83, name: "vertical_ratio", field_type: "uint16", base_type: 0x84, scale: 100.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 84, name: "stance_time_balance", field_type: "uint16", base_type: 0x84, scale: 100.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 85, name: "step_length", field_type: "uint16", base_type: 0x84, scale: 10.0, offset: 0.0, units: "mm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 91, name: "absolute_pressure", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "Pa", components: &[], subfields: &[] },
+        FieldProfile { field_num: 92, name: "depth", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 93, name: "next_stop_depth", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 94, name: "next_stop_time", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 95, name: "time_to_surface", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 96, name: "ndl_time", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 97, name: "cns_load", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 98, name: "n2_load", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 114, name: "grit", field_type: "float32", base_type: 0x88, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 115, name: "flow", field_type: "float32", base_type: 0x88, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 117, name: "ebike_travel_range", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "km", components: &[], subfields: &[] },
+        FieldProfile { field_num: 118, name: "ebike_battery_level", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 119, name: "ebike_assist_mode", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "depends on sensor", components: &[], subfields: &[] },
+        FieldProfile { field_num: 120, name: "ebike_assist_level_percent", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 253, name: "timestamp", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 21, name: "event", fields: &[
+        FieldProfile { field_num: 0, name: "event", field_type: "event", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "event_type", field_type: "event_type", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "data16", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[ComponentProfile { field_num: 3, bits: 16, scale: 1.0, offset: 0.0, accumulate: false }], subfields: &[] },
+        FieldProfile { field_num: 3, name: "data", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[
+            SubfieldProfile { references: &[SubfieldReference { field_num: 0, value: 0 }], field: FieldProfile { field_num: 3, name: "timer_trigger", field_type: "timer_trigger", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 0, value: 10 }], field: FieldProfile { field_num: 3, name: "course_point_index", field_type: "message_index", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 0, value: 11 }], field: FieldProfile { field_num: 3, name: "battery_level", field_type: "uint16", base_type: 0x84, scale: 1000.0, offset: 0.0, units: "V", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 0, value: 12 }], field: FieldProfile { field_num: 3, name: "virtual_partner_speed", field_type: "uint16", base_type: 0x84, scale: 1000.0, offset: 0.0, units: "m/s", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 0, value: 13 }], field: FieldProfile { field_num: 3, name: "hr_high_alert", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "bpm", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 0, value: 14 }], field: FieldProfile { field_num: 3, name: "hr_low_alert", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "bpm", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 0, value: 15 }], field: FieldProfile { field_num: 3, name: "speed_high_alert", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "m/s", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 0, value: 16 }], field: FieldProfile { field_num: 3, name: "speed_low_alert", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "m/s", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 0, value: 17 }], field: FieldProfile { field_num: 3, name: "cad_high_alert", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "rpm", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 0, value: 18 }], field: FieldProfile { field_num: 3, name: "cad_low_alert", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "rpm", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 0, value: 19 }], field: FieldProfile { field_num: 3, name: "power_high_alert", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "watts", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 0, value: 20 }], field: FieldProfile { field_num: 3, name: "power_low_alert", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "watts", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 0, value: 23 }], field: FieldProfile { field_num: 3, name: "time_duration_alert", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "s", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 0, value: 24 }], field: FieldProfile { field_num: 3, name: "distance_duration_alert", field_type: "uint32", base_type: 0x86, scale: 100.0, offset: 0.0, units: "m", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 0, value: 25 }], field: FieldProfile { field_num: 3, name: "calorie_duration_alert", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "calories", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 0, value: 27 }], field: FieldProfile { field_num: 3, name: "fitness_equipment_state", field_type: "fitness_equipment_state", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 0, value: 33 }], field: FieldProfile { field_num: 3, name: "sport_point", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[ComponentProfile { field_num: 7, bits: 16, scale: 1.0, offset: 0.0, accumulate: false }, ComponentProfile { field_num: 8, bits: 16, scale: 1.0, offset: 0.0, accumulate: false }], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 0, value: 42 }, SubfieldReference { field_num: 0, value: 43 }], field: FieldProfile { field_num: 3, name: "gear_change_data", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[ComponentProfile { field_num: 11, bits: 8, scale: 1.0, offset: 0.0, accumulate: false }, ComponentProfile { field_num: 12, bits: 8, scale: 1.0, offset: 0.0, accumulate: false }, ComponentProfile { field_num: 9, bits: 8, scale: 1.0, offset: 0.0, accumulate: false }, ComponentProfile { field_num: 10, bits: 8, scale: 1.0, offset: 0.0, accumulate: false }], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 0, value: 44 }], field: FieldProfile { field_num: 3, name: "rider_position", field_type: "rider_position_type", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 0, value: 47 }], field: FieldProfile { field_num: 3, name: "comm_timeout", field_type: "comm_timeout_type", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 0, value: 75 }], field: FieldProfile { field_num: 3, name: "radar_threat_alert", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[ComponentProfile { field_num: 21, bits: 8, scale: 1.0, offset: 0.0, accumulate: false }, ComponentProfile { field_num: 22, bits: 8, scale: 1.0, offset: 0.0, accumulate: false }], subfields: &[] } },
+        ] },
+        FieldProfile { field_num: 4, name: "event_group", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 7, name: "score", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 8, name: "opponent_score", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 9, name: "front_gear_num", field_type: "uint8z", base_type: 0x0a, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 10, name: "front_gear", field_type: "uint8z", base_type: 0x0a, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 11, name: "rear_gear_num", field_type: "uint8z", base_type: 0x0a, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 12, name: "rear_gear", field_type: "uint8z", base_type: 0x0a, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 13, name: "device_index", field_type: "device_index", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 21, name: "radar_threat_level_max", field_type: "radar_threat_level_type", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 22, name: "radar_threat_count", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 253, name: "timestamp", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 23, name: "device_info", fields: &[
+        FieldProfile { field_num: 0, name: "device_index", field_type: "device_index", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "device_type", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[
+            SubfieldProfile { references: &[SubfieldReference { field_num: 25, value: 1 }], field: FieldProfile { field_num: 1, name: "antplus_device_type", field_type: "antplus_device_type", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 25, value: 0 }], field: FieldProfile { field_num: 1, name: "ant_device_type", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] } },
+        ] },
+        FieldProfile { field_num: 2, name: "manufacturer", field_type: "manufacturer", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "serial_number", field_type: "uint32z", base_type: 0x8c, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "product", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[
+            SubfieldProfile { references: &[SubfieldReference { field_num: 2, value: 263 }], field: FieldProfile { field_num: 4, name: "favero_product", field_type: "favero_product", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 2, value: 1 }, SubfieldReference { field_num: 2, value: 15 }, SubfieldReference { field_num: 2, value: 13 }, SubfieldReference { field_num: 2, value: 89 }], field: FieldProfile { field_num: 4, name: "garmin_product", field_type: "garmin_product", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] } },
+        ] },
+        FieldProfile { field_num: 5, name: "software_version", field_type: "uint16", base_type: 0x84, scale: 100.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 6, name: "hardware_version", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 7, name: "cum_operating_time", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 10, name: "battery_voltage", field_type: "uint16", base_type: 0x84, scale: 256.0, offset: 0.0, units: "V", components: &[], subfields: &[] },
+        FieldProfile { field_num: 11, name: "battery_status", field_type: "battery_status", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 18, name: "sensor_position", field_type: "body_location", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 19, name: "descriptor", field_type: "string", base_type: 0x07, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 20, name: "ant_transmission_type", field_type: "uint8z", base_type: 0x0a, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 21, name: "ant_device_number", field_type: "uint16z", base_type: 0x8b, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 22, name: "ant_network", field_type: "ant_network", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 25, name: "source_type", field_type: "source_type", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 27, name: "product_name", field_type: "string", base_type: 0x07, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 253, name: "timestamp", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 26, name: "workout", fields: &[
+        FieldProfile { field_num: 4, name: "sport", field_type: "sport", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "capabilities", field_type: "workout_capabilities", base_type: 0x8c, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 6, name: "num_valid_steps", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 8, name: "wkt_name", field_type: "string", base_type: 0x07, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 11, name: "sub_sport", field_type: "sub_sport", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 14, name: "pool_length", field_type: "uint16", base_type: 0x84, scale: 100.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 15, name: "pool_length_unit", field_type: "display_measure", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 27, name: "workout_step", fields: &[
+        FieldProfile { field_num: 0, name: "wkt_step_name", field_type: "string", base_type: 0x07, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "duration_type", field_type: "wkt_step_duration", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "duration_value", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[
+            SubfieldProfile { references: &[SubfieldReference { field_num: 1, value: 0 }, SubfieldReference { field_num: 1, value: 28 }], field: FieldProfile { field_num: 2, name: "duration_time", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "s", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 1, value: 1 }], field: FieldProfile { field_num: 2, name: "duration_distance", field_type: "uint32", base_type: 0x86, scale: 100.0, offset: 0.0, units: "m", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 1, value: 2 }, SubfieldReference { field_num: 1, value: 3 }], field: FieldProfile { field_num: 2, name: "duration_hr", field_type: "workout_hr", base_type: 0x86, scale: 1.0, offset: 0.0, units: "% or bpm", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 1, value: 4 }], field: FieldProfile { field_num: 2, name: "duration_calories", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "calories", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 1, value: 6 }, SubfieldReference { field_num: 1, value: 7 }, SubfieldReference { field_num: 1, value: 8 }, SubfieldReference { field_num: 1, value: 9 }, SubfieldReference { field_num: 1, value: 10 }, SubfieldReference { field_num: 1, value: 11 }, SubfieldReference { field_num: 1, value: 12 }, SubfieldReference { field_num: 1, value: 13 }], field: FieldProfile { field_num: 2, name: "duration_step", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 1, value: 14 }, SubfieldReference { field_num: 1, value: 15 }], field: FieldProfile { field_num: 2, name: "duration_power", field_type: "workout_power", base_type: 0x86, scale: 1.0, offset: 0.0, units: "% or watts", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 1, value: 29 }], field: FieldProfile { field_num: 2, name: "duration_reps", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] } },
+        ] },
+        FieldProfile { field_num: 3, name: "target_type", field_type: "wkt_step_target", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "target_value", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[
+            SubfieldProfile { references: &[SubfieldReference { field_num: 3, value: 0 }], field: FieldProfile { field_num: 4, name: "target_speed_zone", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 3, value: 1 }], field: FieldProfile { field_num: 4, name: "target_hr_zone", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 3, value: 3 }], field: FieldProfile { field_num: 4, name: "target_cadence_zone", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 3, value: 4 }], field: FieldProfile { field_num: 4, name: "target_power_zone", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 1, value: 6 }], field: FieldProfile { field_num: 4, name: "repeat_steps", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 1, value: 7 }], field: FieldProfile { field_num: 4, name: "repeat_time", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "s", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 1, value: 8 }], field: FieldProfile { field_num: 4, name: "repeat_distance", field_type: "uint32", base_type: 0x86, scale: 100.0, offset: 0.0, units: "m", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 1, value: 9 }], field: FieldProfile { field_num: 4, name: "repeat_calories", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "calories", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 1, value: 10 }, SubfieldReference { field_num: 1, value: 11 }], field: FieldProfile { field_num: 4, name: "repeat_hr", field_type: "workout_hr", base_type: 0x86, scale: 1.0, offset: 0.0, units: "% or bpm", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 1, value: 12 }, SubfieldReference { field_num: 1, value: 13 }], field: FieldProfile { field_num: 4, name: "repeat_power", field_type: "workout_power", base_type: 0x86, scale: 1.0, offset: 0.0, units: "% or watts", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 3, value: 11 }], field: FieldProfile { field_num: 4, name: "target_stroke_type", field_type: "swim_stroke", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] } },
+        ] },
+        FieldProfile { field_num: 5, name: "custom_target_value_low", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[
+            SubfieldProfile { references: &[SubfieldReference { field_num: 3, value: 0 }], field: FieldProfile { field_num: 5, name: "custom_target_speed_low", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "m/s", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 3, value: 1 }], field: FieldProfile { field_num: 5, name: "custom_target_heart_rate_low", field_type: "workout_hr", base_type: 0x86, scale: 1.0, offset: 0.0, units: "% or bpm", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 3, value: 3 }], field: FieldProfile { field_num: 5, name: "custom_target_cadence_low", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "rpm", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 3, value: 4 }], field: FieldProfile { field_num: 5, name: "custom_target_power_low", field_type: "workout_power", base_type: 0x86, scale: 1.0, offset: 0.0, units: "% or watts", components: &[], subfields: &[] } },
+        ] },
+        FieldProfile { field_num: 6, name: "custom_target_value_high", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[
+            SubfieldProfile { references: &[SubfieldReference { field_num: 3, value: 0 }], field: FieldProfile { field_num: 6, name: "custom_target_speed_high", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "m/s", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 3, value: 1 }], field: FieldProfile { field_num: 6, name: "custom_target_heart_rate_high", field_type: "workout_hr", base_type: 0x86, scale: 1.0, offset: 0.0, units: "% or bpm", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 3, value: 3 }], field: FieldProfile { field_num: 6, name: "custom_target_cadence_high", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "rpm", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 3, value: 4 }], field: FieldProfile { field_num: 6, name: "custom_target_power_high", field_type: "workout_power", base_type: 0x86, scale: 1.0, offset: 0.0, units: "% or watts", components: &[], subfields: &[] } },
+        ] },
+        FieldProfile { field_num: 7, name: "intensity", field_type: "intensity", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 8, name: "notes", field_type: "string", base_type: 0x07, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 9, name: "equipment", field_type: "workout_equipment", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 10, name: "exercise_category", field_type: "exercise_category", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 11, name: "exercise_name", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 12, name: "exercise_weight", field_type: "uint16", base_type: 0x84, scale: 100.0, offset: 0.0, units: "kg", components: &[], subfields: &[] },
+        FieldProfile { field_num: 13, name: "weight_display_unit", field_type: "fit_base_unit", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 254, name: "message_index", field_type: "message_index", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 28, name: "schedule", fields: &[
+        FieldProfile { field_num: 0, name: "manufacturer", field_type: "manufacturer", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "product", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[
+            SubfieldProfile { references: &[SubfieldReference { field_num: 0, value: 263 }], field: FieldProfile { field_num: 1, name: "favero_product", field_type: "favero_product", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 0, value: 1 }, SubfieldReference { field_num: 0, value: 15 }, SubfieldReference { field_num: 0, value: 13 }, SubfieldReference { field_num: 0, value: 89 }], field: FieldProfile { field_num: 1, name: "garmin_product", field_type: "garmin_product", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] } },
+        ] },
+        FieldProfile { field_num: 2, name: "serial_number", field_type: "uint32z", base_type: 0x8c, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "time_created", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "completed", field_type: "bool", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "type", field_type: "schedule", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 6, name: "scheduled_time", field_type: "local_date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 30, name: "weight_scale", fields: &[
+        FieldProfile { field_num: 0, name: "weight", field_type: "weight", base_type: 0x84, scale: 100.0, offset: 0.0, units: "kg", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "percent_fat", field_type: "uint16", base_type: 0x84, scale: 100.0, offset: 0.0, units: "%", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "percent_hydration", field_type: "uint16", base_type: 0x84, scale: 100.0, offset: 0.0, units: "%", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "visceral_fat_mass", field_type: "uint16", base_type: 0x84, scale: 100.0, offset: 0.0, units: "kg", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "bone_mass", field_type: "uint16", base_type: 0x84, scale: 100.0, offset: 0.0, units: "kg", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "muscle_mass", field_type: "uint16", base_type: 0x84, scale: 100.0, offset: 0.0, units: "kg", components: &[], subfields: &[] },
+        FieldProfile { field_num: 7, name: "basal_met", field_type: "uint16", base_type: 0x84, scale: 4.0, offset: 0.0, units: "kcal/day", components: &[], subfields: &[] },
+        FieldProfile { field_num: 8, name: "physique_rating", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 9, name: "active_met", field_type: "uint16", base_type: 0x84, scale: 4.0, offset: 0.0, units: "kcal/day", components: &[], subfields: &[] },
+        FieldProfile { field_num: 10, name: "metabolic_age", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "years", components: &[], subfields: &[] },
+        FieldProfile { field_num: 11, name: "visceral_fat_rating", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 12, name: "user_profile_index", field_type: "message_index", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 253, name: "timestamp", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 31, name: "course", fields: &[
+        FieldProfile { field_num: 4, name: "sport", field_type: "sport", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "name", field_type: "string", base_type: 0x07, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 6, name: "capabilities", field_type: "course_capabilities", base_type: 0x8c, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 7, name: "sub_sport", field_type: "sub_sport", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 32, name: "course_point", fields: &[
+        FieldProfile { field_num: 1, name: "timestamp", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "position_lat", field_type: "sint32", base_type: 0x85, scale: 1.0, offset: 0.0, units: "semicircles", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "position_long", field_type: "sint32", base_type: 0x85, scale: 1.0, offset: 0.0, units: "semicircles", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "distance", field_type: "uint32", base_type: 0x86, scale: 100.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "type", field_type: "course_point", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 6, name: "name", field_type: "string", base_type: 0x07, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 8, name: "favorite", field_type: "bool", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 254, name: "message_index", field_type: "message_index", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 33, name: "totals", fields: &[
+        FieldProfile { field_num: 0, name: "timer_time", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "distance", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "calories", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "kcal", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "sport", field_type: "sport", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "elapsed_time", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "sessions", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 6, name: "active_time", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 9, name: "sport_index", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 253, name: "timestamp", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 254, name: "message_index", field_type: "message_index", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 34, name: "activity", fields: &[
+        FieldProfile { field_num: 0, name: "total_timer_time", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "num_sessions", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "type", field_type: "activity", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "event", field_type: "event", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "event_type", field_type: "event_type", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "local_timestamp", field_type: "local_date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 6, name: "event_group", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 253, name: "timestamp", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 35, name: "software", fields: &[
+        FieldProfile { field_num: 3, name: "version", field_type: "uint16", base_type: 0x84, scale: 100.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "part_number", field_type: "string", base_type: 0x07, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 254, name: "message_index", field_type: "message_index", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 37, name: "file_capabilities", fields: &[
+        FieldProfile { field_num: 0, name: "type", field_type: "file", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "flags", field_type: "file_flags", base_type: 0x0a, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "directory", field_type: "string", base_type: 0x07, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "max_count", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "max_size", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "bytes", components: &[], subfields: &[] },
+        FieldProfile { field_num: 254, name: "message_index", field_type: "message_index", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 38, name: "mesg_capabilities", fields: &[
+        FieldProfile { field_num: 0, name: "file", field_type: "file", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "mesg_num", field_type: "mesg_num", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "count_type", field_type: "mesg_count", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "count", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[
+            SubfieldProfile { references: &[SubfieldReference { field_num: 2, value: 0 }], field: FieldProfile { field_num: 3, name: "num_per_file", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 2, value: 1 }], field: FieldProfile { field_num: 3, name: "max_per_file", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 2, value: 2 }], field: FieldProfile { field_num: 3, name: "max_per_file_type", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] } },
+        ] },
+        FieldProfile { field_num: 254, name: "message_index", field_type: "message_index", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 39, name: "field_capabilities", fields: &[
+        FieldProfile { field_num: 0, name: "file", field_type: "file", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "mesg_num", field_type: "mesg_num", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "field_num", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "count", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 254, name: "message_index", field_type: "message_index", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 49, name: "file_creator", fields: &[
+        FieldProfile { field_num: 0, name: "software_version", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "hardware_version", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 51, name: "blood_pressure", fields: &[
+        FieldProfile { field_num: 0, name: "systolic_pressure", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "mmHg", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "diastolic_pressure", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "mmHg", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "mean_arterial_pressure", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "mmHg", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "map_3_sample_mean", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "mmHg", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "map_morning_values", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "mmHg", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "map_evening_values", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "mmHg", components: &[], subfields: &[] },
+        FieldProfile { field_num: 6, name: "heart_rate", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "bpm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 7, name: "heart_rate_type", field_type: "hr_type", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 8, name: "status", field_type: "bp_status", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 9, name: "user_profile_index", field_type: "message_index", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 253, name: "timestamp", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 53, name: "speed_zone", fields: &[
+        FieldProfile { field_num: 0, name: "high_value", field_type: "uint16", base_type: 0x84, scale: 1000.0, offset: 0.0, units: "m/s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "name", field_type: "string", base_type: 0x07, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 254, name: "message_index", field_type: "message_index", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 55, name: "monitoring", fields: &[
+        FieldProfile { field_num: 0, name: "device_index", field_type: "device_index", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "calories", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "kcal", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "distance", field_type: "uint32", base_type: 0x86, scale: 100.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "cycles", field_type: "uint32", base_type: 0x86, scale: 2.0, offset: 0.0, units: "cycles", components: &[], subfields: &[
+            SubfieldProfile { references: &[SubfieldReference { field_num: 5, value: 6 }, SubfieldReference { field_num: 5, value: 1 }], field: FieldProfile { field_num: 3, name: "steps", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "steps", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 5, value: 2 }, SubfieldReference { field_num: 5, value: 5 }], field: FieldProfile { field_num: 3, name: "strokes", field_type: "uint32", base_type: 0x86, scale: 2.0, offset: 0.0, units: "strokes", components: &[], subfields: &[] } },
+        ] },
+        FieldProfile { field_num: 4, name: "active_time", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "activity_type", field_type: "activity_type", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 6, name: "activity_subtype", field_type: "activity_subtype", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 7, name: "activity_level", field_type: "activity_level", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 8, name: "distance_16", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "100 * m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 9, name: "cycles_16", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "2 * cycles (steps)", components: &[], subfields: &[] },
+        FieldProfile { field_num: 10, name: "active_time_16", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 11, name: "local_timestamp", field_type: "local_date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 12, name: "temperature", field_type: "sint16", base_type: 0x83, scale: 100.0, offset: 0.0, units: "C", components: &[], subfields: &[] },
+        FieldProfile { field_num: 14, name: "temperature_min", field_type: "sint16", base_type: 0x83, scale: 100.0, offset: 0.0, units: "C", components: &[], subfields: &[] },
+        FieldProfile { field_num: 15, name: "temperature_max", field_type: "sint16", base_type: 0x83, scale: 100.0, offset: 0.0, units: "C", components: &[], subfields: &[] },
+        FieldProfile { field_num: 16, name: "activity_time", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "minutes", components: &[], subfields: &[] },
+        FieldProfile { field_num: 19, name: "active_calories", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "kcal", components: &[], subfields: &[] },
+        FieldProfile { field_num: 24, name: "current_activity_type_intensity", field_type: "byte", base_type: 0x0d, scale: 1.0, offset: 0.0, units: "", components: &[ComponentProfile { field_num: 5, bits: 5, scale: 1.0, offset: 0.0, accumulate: false }, ComponentProfile { field_num: 28, bits: 3, scale: 1.0, offset: 0.0, accumulate: false }], subfields: &[] },
+        FieldProfile { field_num: 25, name: "timestamp_min_8", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "min", components: &[], subfields: &[] },
+        FieldProfile { field_num: 26, name: "timestamp_16", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 27, name: "heart_rate", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "bpm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 28, name: "intensity", field_type: "uint8", base_type: 0x02, scale: 10.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 29, name: "duration_min", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "min", components: &[], subfields: &[] },
+        FieldProfile { field_num: 30, name: "duration", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 31, name: "ascent", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 32, name: "descent", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 33, name: "moderate_activity_minutes", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "minutes", components: &[], subfields: &[] },
+        FieldProfile { field_num: 34, name: "vigorous_activity_minutes", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "minutes", components: &[], subfields: &[] },
+        FieldProfile { field_num: 253, name: "timestamp", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 72, name: "training_file", fields: &[
+        FieldProfile { field_num: 0, name: "type", field_type: "file", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "manufacturer", field_type: "manufacturer", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "product", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[
+            SubfieldProfile { references: &[SubfieldReference { field_num: 1, value: 263 }], field: FieldProfile { field_num: 2, name: "favero_product", field_type: "favero_product", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 1, value: 1 }, SubfieldReference { field_num: 1, value: 15 }, SubfieldReference { field_num: 1, value: 13 }, SubfieldReference { field_num: 1, value: 89 }], field: FieldProfile { field_num: 2, name: "garmin_product", field_type: "garmin_product", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] } },
+        ] },
+        FieldProfile { field_num: 3, name: "serial_number", field_type: "uint32z", base_type: 0x8c, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "time_created", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 253, name: "timestamp", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 78, name: "hrv", fields: &[
+        FieldProfile { field_num: 0, name: "time", field_type: "uint16", base_type: 0x84, scale: 1000.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 80, name: "ant_rx", fields: &[
+        FieldProfile { field_num: 0, name: "fractional_timestamp", field_type: "uint16", base_type: 0x84, scale: 32768.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "mesg_id", field_type: "byte", base_type: 0x0d, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "mesg_data", field_type: "byte", base_type: 0x0d, scale: 1.0, offset: 0.0, units: "", components: &[ComponentProfile { field_num: 3, bits: 8, scale: 1.0, offset: 0.0, accumulate: false }, ComponentProfile { field_num: 4, bits: 8, scale: 1.0, offset: 0.0, accumulate: false }, ComponentProfile { field_num: 4, bits: 8, scale: 1.0, offset: 0.0, accumulate: false }, ComponentProfile { field_num: 4, bits: 8, scale: 1.0, offset: 0.0, accumulate: false }, ComponentProfile { field_num: 4, bits: 8, scale: 1.0, offset: 0.0, accumulate: false }, ComponentProfile { field_num: 4, bits: 8, scale: 1.0, offset: 0.0, accumulate: false }, ComponentProfile { field_num: 4, bits: 8, scale: 1.0, offset: 0.0, accumulate: false }, ComponentProfile { field_num: 4, bits: 8, scale: 1.0, offset: 0.0, accumulate: false }, ComponentProfile { field_num: 4, bits: 8, scale: 1.0, offset: 0.0, accumulate: false }], subfields: &[] },
+        FieldProfile { field_num: 3, name: "channel_number", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "data", field_type: "byte", base_type: 0x0d, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 253, name: "timestamp", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 81, name: "ant_tx", fields: &[
+        FieldProfile { field_num: 0, name: "fractional_timestamp", field_type: "uint16", base_type: 0x84, scale: 32768.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "mesg_id", field_type: "byte", base_type: 0x0d, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "mesg_data", field_type: "byte", base_type: 0x0d, scale: 1.0, offset: 0.0, units: "", components: &[ComponentProfile { field_num: 3, bits: 8, scale: 1.0, offset: 0.0, accumulate: false }, ComponentProfile { field_num: 4, bits: 8, scale: 1.0, offset: 0.0, accumulate: false }, ComponentProfile { field_num: 4, bits: 8, scale: 1.0, offset: 0.0, accumulate: false }, ComponentProfile { field_num: 4, bits: 8, scale: 1.0, offset: 0.0, accumulate: false }, ComponentProfile { field_num: 4, bits: 8, scale: 1.0, offset: 0.0, accumulate: false }, ComponentProfile { field_num: 4, bits: 8, scale: 1.0, offset: 0.0, accumulate: false }, ComponentProfile { field_num: 4, bits: 8, scale: 1.0, offset: 0.0, accumulate: false }, ComponentProfile { field_num: 4, bits: 8, scale: 1.0, offset: 0.0, accumulate: false }, ComponentProfile { field_num: 4, bits: 8, scale: 1.0, offset: 0.0, accumulate: false }], subfields: &[] },
+        FieldProfile { field_num: 3, name: "channel_number", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "data", field_type: "byte", base_type: 0x0d, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 253, name: "timestamp", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 82, name: "ant_channel_id", fields: &[
+        FieldProfile { field_num: 0, name: "channel_number", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "device_type", field_type: "uint8z", base_type: 0x0a, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "device_number", field_type: "uint16z", base_type: 0x8b, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "transmission_type", field_type: "uint8z", base_type: 0x0a, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "device_index", field_type: "device_index", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 101, name: "length", fields: &[
+        FieldProfile { field_num: 0, name: "event", field_type: "event", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "event_type", field_type: "event_type", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "start_time", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "total_elapsed_time", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "total_timer_time", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "total_strokes", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "strokes", components: &[], subfields: &[] },
+        FieldProfile { field_num: 6, name: "avg_speed", field_type: "uint16", base_type: 0x84, scale: 1000.0, offset: 0.0, units: "m/s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 7, name: "swim_stroke", field_type: "swim_stroke", base_type: 0x00, scale: 1.0, offset: 0.0, units: "swim_stroke", components: &[], subfields: &[] },
+        FieldProfile { field_num: 9, name: "avg_swimming_cadence", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "strokes/min", components: &[], subfields: &[] },
+        FieldProfile { field_num: 10, name: "event_group", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 11, name: "total_calories", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "kcal", components: &[], subfields: &[] },
+        FieldProfile { field_num: 12, name: "length_type", field_type: "length_type", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 18, name: "player_score", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 19, name: "opponent_score", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 20, name: "stroke_count", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "counts", components: &[], subfields: &[] },
+        FieldProfile { field_num: 21, name: "zone_count", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "counts", components: &[], subfields: &[] },
+        FieldProfile { field_num: 253, name: "timestamp", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 254, name: "message_index", field_type: "message_index", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 103, name: "monitoring_info", fields: &[
+        FieldProfile { field_num: 0, name: "local_timestamp", field_type: "local_date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "activity_type", field_type: "activity_type", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "cycles_to_distance", field_type: "uint16", base_type: 0x84, scale: 5000.0, offset: 0.0, units: "m/cycle", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "cycles_to_calories", field_type: "uint16", base_type: 0x84, scale: 5000.0, offset: 0.0, units: "kcal/cycle", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "resting_metabolic_rate", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "kcal / day", components: &[], subfields: &[] },
+        FieldProfile { field_num: 253, name: "timestamp", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 106, name: "slave_device", fields: &[
+        FieldProfile { field_num: 0, name: "manufacturer", field_type: "manufacturer", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "product", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[
+            SubfieldProfile { references: &[SubfieldReference { field_num: 0, value: 263 }], field: FieldProfile { field_num: 1, name: "favero_product", field_type: "favero_product", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 0, value: 1 }, SubfieldReference { field_num: 0, value: 15 }, SubfieldReference { field_num: 0, value: 13 }, SubfieldReference { field_num: 0, value: 89 }], field: FieldProfile { field_num: 1, name: "garmin_product", field_type: "garmin_product", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] } },
+        ] },
+    ] },
+    MessageProfile { global_message_num: 127, name: "connectivity", fields: &[
+        FieldProfile { field_num: 0, name: "bluetooth_enabled", field_type: "bool", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "bluetooth_le_enabled", field_type: "bool", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "ant_enabled", field_type: "bool", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "name", field_type: "string", base_type: 0x07, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "live_tracking_enabled", field_type: "bool", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "weather_conditions_enabled", field_type: "bool", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 6, name: "weather_alerts_enabled", field_type: "bool", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 7, name: "auto_activity_upload_enabled", field_type: "bool", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 8, name: "course_download_enabled", field_type: "bool", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 9, name: "workout_download_enabled", field_type: "bool", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 10, name: "gps_ephemeris_download_enabled", field_type: "bool", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 11, name: "incident_detection_enabled", field_type: "bool", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 12, name: "grouptrack_enabled", field_type: "bool", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 128, name: "weather_conditions", fields: &[
+        FieldProfile { field_num: 0, name: "weather_report", field_type: "weather_report", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "temperature", field_type: "sint8", base_type: 0x01, scale: 1.0, offset: 0.0, units: "C", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "condition", field_type: "weather_status", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "wind_direction", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "degrees", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "wind_speed", field_type: "uint16", base_type: 0x84, scale: 1000.0, offset: 0.0, units: "m/s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "precipitation_probability", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 6, name: "temperature_feels_like", field_type: "sint8", base_type: 0x01, scale: 1.0, offset: 0.0, units: "C", components: &[], subfields: &[] },
+        FieldProfile { field_num: 7, name: "relative_humidity", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 8, name: "location", field_type: "string", base_type: 0x07, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 9, name: "observed_at_time", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 10, name: "observed_location_lat", field_type: "sint32", base_type: 0x85, scale: 1.0, offset: 0.0, units: "semicircles", components: &[], subfields: &[] },
+        FieldProfile { field_num: 11, name: "observed_location_long", field_type: "sint32", base_type: 0x85, scale: 1.0, offset: 0.0, units: "semicircles", components: &[], subfields: &[] },
+        FieldProfile { field_num: 12, name: "day_of_week", field_type: "day_of_week", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 13, name: "high_temperature", field_type: "sint8", base_type: 0x01, scale: 1.0, offset: 0.0, units: "C", components: &[], subfields: &[] },
+        FieldProfile { field_num: 14, name: "low_temperature", field_type: "sint8", base_type: 0x01, scale: 1.0, offset: 0.0, units: "C", components: &[], subfields: &[] },
+        FieldProfile { field_num: 253, name: "timestamp", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 129, name: "weather_alert", fields: &[
+        FieldProfile { field_num: 0, name: "report_id", field_type: "string", base_type: 0x07, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "issue_time", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "expire_time", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "severity", field_type: "weather_severity", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "type", field_type: "weather_severe_type", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 253, name: "timestamp", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 131, name: "cadence_zone", fields: &[
+        FieldProfile { field_num: 0, name: "high_value", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "rpm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "name", field_type: "string", base_type: 0x07, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 254, name: "message_index", field_type: "message_index", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 132, name: "hr", fields: &[
+        FieldProfile { field_num: 0, name: "fractional_timestamp", field_type: "uint16", base_type: 0x84, scale: 32768.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "time256", field_type: "uint8", base_type: 0x02, scale: 256.0, offset: 0.0, units: "s", components: &[ComponentProfile { field_num: 0, bits: 8, scale: 256.0, offset: 0.0, accumulate: false }], subfields: &[] },
+        FieldProfile { field_num: 6, name: "filtered_bpm", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "bpm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 9, name: "event_timestamp", field_type: "uint32", base_type: 0x86, scale: 1024.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 10, name: "event_timestamp_12", field_type: "byte", base_type: 0x0d, scale: 1.0, offset: 0.0, units: "", components: &[ComponentProfile { field_num: 9, bits: 12, scale: 1024.0, offset: 0.0, accumulate: true }, ComponentProfile { field_num: 9, bits: 12, scale: 1024.0, offset: 0.0, accumulate: true }, ComponentProfile { field_num: 9, bits: 12, scale: 1024.0, offset: 0.0, accumulate: true }, ComponentProfile { field_num: 9, bits: 12, scale: 1024.0, offset: 0.0, accumulate: true }, ComponentProfile { field_num: 9, bits: 12, scale: 1024.0, offset: 0.0, accumulate: true }, ComponentProfile { field_num: 9, bits: 12, scale: 1024.0, offset: 0.0, accumulate: true }, ComponentProfile { field_num: 9, bits: 12, scale: 1024.0, offset: 0.0, accumulate: true }, ComponentProfile { field_num: 9, bits: 12, scale: 1024.0, offset: 0.0, accumulate: true }, ComponentProfile { field_num: 9, bits: 12, scale: 1024.0, offset: 0.0, accumulate: true }, ComponentProfile { field_num: 9, bits: 12, scale: 1024.0, offset: 0.0, accumulate: true }], subfields: &[] },
+        FieldProfile { field_num: 253, name: "timestamp", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 142, name: "segment_lap", fields: &[
+        FieldProfile { field_num: 0, name: "event", field_type: "event", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "event_type", field_type: "event_type", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "start_time", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "start_position_lat", field_type: "sint32", base_type: 0x85, scale: 1.0, offset: 0.0, units: "semicircles", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "start_position_long", field_type: "sint32", base_type: 0x85, scale: 1.0, offset: 0.0, units: "semicircles", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "end_position_lat", field_type: "sint32", base_type: 0x85, scale: 1.0, offset: 0.0, units: "semicircles", components: &[], subfields: &[] },
+        FieldProfile { field_num: 6, name: "end_position_long", field_type: "sint32", base_type: 0x85, scale: 1.0, offset: 0.0, units: "semicircles", components: &[], subfields: &[] },
+        FieldProfile { field_num: 7, name: "total_elapsed_time", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 8, name: "total_timer_time", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 9, name: "total_distance", field_type: "uint32", base_type: 0x86, scale: 100.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 10, name: "total_cycles", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "cycles", components: &[], subfields: &[
+            SubfieldProfile { references: &[SubfieldReference { field_num: 23, value: 2 }], field: FieldProfile { field_num: 10, name: "total_strokes", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "strokes", components: &[], subfields: &[] } },
+        ] },
+        FieldProfile { field_num: 11, name: "total_calories", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "kcal", components: &[], subfields: &[] },
+        FieldProfile { field_num: 12, name: "total_fat_calories", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "kcal", components: &[], subfields: &[] },
+        FieldProfile { field_num: 13, name: "avg_speed", field_type: "uint16", base_type: 0x84, scale: 1000.0, offset: 0.0, units: "m/s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 14, name: "max_speed", field_type: "uint16", base_type: 0x84, scale: 1000.0, offset: 0.0, units: "m/s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 15, name: "avg_heart_rate", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "bpm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 16, name: "max_heart_rate", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "bpm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 17, name: "avg_cadence", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "rpm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 18, name: "max_cadence", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "rpm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 19, name: "avg_power", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "watts", components: &[], subfields: &[] },
+        FieldProfile { field_num: 20, name: "max_power", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "watts", components: &[], subfields: &[] },
+        FieldProfile { field_num: 21, name: "total_ascent", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 22, name: "total_descent", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 23, name: "sport", field_type: "sport", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 24, name: "event_group", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 25, name: "nec_lat", field_type: "sint32", base_type: 0x85, scale: 1.0, offset: 0.0, units: "semicircles", components: &[], subfields: &[] },
+        FieldProfile { field_num: 26, name: "nec_long", field_type: "sint32", base_type: 0x85, scale: 1.0, offset: 0.0, units: "semicircles", components: &[], subfields: &[] },
+        FieldProfile { field_num: 27, name: "swc_lat", field_type: "sint32", base_type: 0x85, scale: 1.0, offset: 0.0, units: "semicircles", components: &[], subfields: &[] },
+        FieldProfile { field_num: 28, name: "swc_long", field_type: "sint32", base_type: 0x85, scale: 1.0, offset: 0.0, units: "semicircles", components: &[], subfields: &[] },
+        FieldProfile { field_num: 29, name: "name", field_type: "string", base_type: 0x07, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 30, name: "normalized_power", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "watts", components: &[], subfields: &[] },
+        FieldProfile { field_num: 31, name: "left_right_balance", field_type: "left_right_balance_100", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 32, name: "sub_sport", field_type: "sub_sport", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 33, name: "total_work", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "J", components: &[], subfields: &[] },
+        FieldProfile { field_num: 34, name: "avg_altitude", field_type: "uint16", base_type: 0x84, scale: 5.0, offset: 500.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 35, name: "max_altitude", field_type: "uint16", base_type: 0x84, scale: 5.0, offset: 500.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 36, name: "gps_accuracy", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 37, name: "avg_grade", field_type: "sint16", base_type: 0x83, scale: 100.0, offset: 0.0, units: "%", components: &[], subfields: &[] },
+        FieldProfile { field_num: 38, name: "avg_pos_grade", field_type: "sint16", base_type: 0x83, scale: 100.0, offset: 0.0, units: "%", components: &[], subfields: &[] },
+        FieldProfile { field_num: 39, name: "avg_neg_grade", field_type: "sint16", base_type: 0x83, scale: 100.0, offset: 0.0, units: "%", components: &[], subfields: &[] },
+        FieldProfile { field_num: 40, name: "max_pos_grade", field_type: "sint16", base_type: 0x83, scale: 100.0, offset: 0.0, units: "%", components: &[], subfields: &[] },
+        FieldProfile { field_num: 41, name: "max_neg_grade", field_type: "sint16", base_type: 0x83, scale: 100.0, offset: 0.0, units: "%", components: &[], subfields: &[] },
+        FieldProfile { field_num: 42, name: "avg_temperature", field_type: "sint8", base_type: 0x01, scale: 1.0, offset: 0.0, units: "C", components: &[], subfields: &[] },
+        FieldProfile { field_num: 43, name: "max_temperature", field_type: "sint8", base_type: 0x01, scale: 1.0, offset: 0.0, units: "C", components: &[], subfields: &[] },
+        FieldProfile { field_num: 44, name: "total_moving_time", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 45, name: "avg_pos_vertical_speed", field_type: "sint16", base_type: 0x83, scale: 1000.0, offset: 0.0, units: "m/s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 46, name: "avg_neg_vertical_speed", field_type: "sint16", base_type: 0x83, scale: 1000.0, offset: 0.0, units: "m/s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 47, name: "max_pos_vertical_speed", field_type: "sint16", base_type: 0x83, scale: 1000.0, offset: 0.0, units: "m/s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 48, name: "max_neg_vertical_speed", field_type: "sint16", base_type: 0x83, scale: 1000.0, offset: 0.0, units: "m/s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 49, name: "time_in_hr_zone", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 50, name: "time_in_speed_zone", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 51, name: "time_in_cadence_zone", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 52, name: "time_in_power_zone", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 53, name: "repetition_num", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 54, name: "min_altitude", field_type: "uint16", base_type: 0x84, scale: 5.0, offset: 500.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 55, name: "min_heart_rate", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "bpm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 56, name: "active_time", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 57, name: "wkt_step_index", field_type: "message_index", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 58, name: "sport_event", field_type: "sport_event", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 59, name: "avg_left_torque_effectiveness", field_type: "uint8", base_type: 0x02, scale: 2.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 60, name: "avg_right_torque_effectiveness", field_type: "uint8", base_type: 0x02, scale: 2.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 61, name: "avg_left_pedal_smoothness", field_type: "uint8", base_type: 0x02, scale: 2.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 62, name: "avg_right_pedal_smoothness", field_type: "uint8", base_type: 0x02, scale: 2.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 63, name: "avg_combined_pedal_smoothness", field_type: "uint8", base_type: 0x02, scale: 2.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 64, name: "status", field_type: "segment_lap_status", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 65, name: "uuid", field_type: "string", base_type: 0x07, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 66, name: "avg_fractional_cadence", field_type: "uint8", base_type: 0x02, scale: 128.0, offset: 0.0, units: "rpm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 67, name: "max_fractional_cadence", field_type: "uint8", base_type: 0x02, scale: 128.0, offset: 0.0, units: "rpm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 68, name: "total_fractional_cycles", field_type: "uint8", base_type: 0x02, scale: 128.0, offset: 0.0, units: "cycles", components: &[], subfields: &[] },
+        FieldProfile { field_num: 69, name: "front_gear_shift_count", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 70, name: "rear_gear_shift_count", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 71, name: "time_standing", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 72, name: "stand_count", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 73, name: "avg_left_pco", field_type: "sint8", base_type: 0x01, scale: 1.0, offset: 0.0, units: "mm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 74, name: "avg_right_pco", field_type: "sint8", base_type: 0x01, scale: 1.0, offset: 0.0, units: "mm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 75, name: "avg_left_power_phase", field_type: "uint8", base_type: 0x02, scale: 0.7111111, offset: 0.0, units: "degrees", components: &[], subfields: &[] },
+        FieldProfile { field_num: 76, name: "avg_left_power_phase_peak", field_type: "uint8", base_type: 0x02, scale: 0.7111111, offset: 0.0, units: "degrees", components: &[], subfields: &[] },
+        FieldProfile { field_num: 77, name: "avg_right_power_phase", field_type: "uint8", base_type: 0x02, scale: 0.7111111, offset: 0.0, units: "degrees", components: &[], subfields: &[] },
+        FieldProfile { field_num: 78, name: "avg_right_power_phase_peak", field_type: "uint8", base_type: 0x02, scale: 0.7111111, offset: 0.0, units: "degrees", components: &[], subfields: &[] },
+        FieldProfile { field_num: 79, name: "avg_power_position", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "watts", components: &[], subfields: &[] },
+        FieldProfile { field_num: 80, name: "max_power_position", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "watts", components: &[], subfields: &[] },
+        FieldProfile { field_num: 81, name: "avg_cadence_position", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "rpm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 82, name: "max_cadence_position", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "rpm", components: &[], subfields: &[] },
+        FieldProfile { field_num: 83, name: "manufacturer", field_type: "manufacturer", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 84, name: "total_grit", field_type: "float32", base_type: 0x88, scale: 1.0, offset: 0.0, units: "kGrit", components: &[], subfields: &[] },
+        FieldProfile { field_num: 85, name: "total_flow", field_type: "float32", base_type: 0x88, scale: 1.0, offset: 0.0, units: "Flow", components: &[], subfields: &[] },
+        FieldProfile { field_num: 86, name: "avg_grit", field_type: "float32", base_type: 0x88, scale: 1.0, offset: 0.0, units: "kGrit", components: &[], subfields: &[] },
+        FieldProfile { field_num: 87, name: "avg_flow", field_type: "float32", base_type: 0x88, scale: 1.0, offset: 0.0, units: "Flow", components: &[], subfields: &[] },
+        FieldProfile { field_num: 89, name: "total_fractional_ascent", field_type: "uint8", base_type: 0x02, scale: 100.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 90, name: "total_fractional_descent", field_type: "uint8", base_type: 0x02, scale: 100.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 253, name: "timestamp", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 254, name: "message_index", field_type: "message_index", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 145, name: "memo_glob", fields: &[
+        FieldProfile { field_num: 0, name: "memo", field_type: "byte", base_type: 0x0d, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "message_number", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "message_index", field_type: "message_index", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 250, name: "part_index", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 148, name: "segment_id", fields: &[
+        FieldProfile { field_num: 0, name: "name", field_type: "string", base_type: 0x07, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "uuid", field_type: "string", base_type: 0x07, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "sport", field_type: "sport", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "enabled", field_type: "bool", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "user_profile_primary_key", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "device_id", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 6, name: "default_race_leader", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 7, name: "delete_status", field_type: "segment_delete_status", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 8, name: "selection_type", field_type: "segment_selection_type", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 149, name: "segment_leaderboard_entry", fields: &[
+        FieldProfile { field_num: 0, name: "name", field_type: "string", base_type: 0x07, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "type", field_type: "segment_leaderboard_type", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "group_primary_key", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "activity_id", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "segment_time", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "activity_id_string", field_type: "string", base_type: 0x07, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 254, name: "message_index", field_type: "message_index", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 150, name: "segment_point", fields: &[
+        FieldProfile { field_num: 1, name: "position_lat", field_type: "sint32", base_type: 0x85, scale: 1.0, offset: 0.0, units: "semicircles", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "position_long", field_type: "sint32", base_type: 0x85, scale: 1.0, offset: 0.0, units: "semicircles", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "distance", field_type: "uint32", base_type: 0x86, scale: 100.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "altitude", field_type: "uint16", base_type: 0x84, scale: 5.0, offset: 500.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "leader_time", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 254, name: "message_index", field_type: "message_index", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 151, name: "segment_file", fields: &[
+        FieldProfile { field_num: 1, name: "file_uuid", field_type: "string", base_type: 0x07, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "enabled", field_type: "bool", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "user_profile_primary_key", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 7, name: "leader_type", field_type: "segment_leaderboard_type", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 8, name: "leader_group_primary_key", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 9, name: "leader_activity_id", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 10, name: "leader_activity_id_string", field_type: "string", base_type: 0x07, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 11, name: "default_race_leader", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 254, name: "message_index", field_type: "message_index", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 158, name: "workout_session", fields: &[
+        FieldProfile { field_num: 0, name: "sport", field_type: "sport", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "sub_sport", field_type: "sub_sport", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "num_valid_steps", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "first_step_index", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "pool_length", field_type: "uint16", base_type: 0x84, scale: 100.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "pool_length_unit", field_type: "display_measure", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 254, name: "message_index", field_type: "message_index", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 159, name: "watchface_settings", fields: &[
+        FieldProfile { field_num: 0, name: "mode", field_type: "watchface_mode", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "layout", field_type: "byte", base_type: 0x0d, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[
+            SubfieldProfile { references: &[SubfieldReference { field_num: 0, value: 0 }], field: FieldProfile { field_num: 1, name: "digital_layout", field_type: "digital_watchface_layout", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 0, value: 1 }], field: FieldProfile { field_num: 1, name: "analog_layout", field_type: "analog_watchface_layout", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] } },
+        ] },
+        FieldProfile { field_num: 254, name: "message_index", field_type: "message_index", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 160, name: "gps_metadata", fields: &[
+        FieldProfile { field_num: 0, name: "timestamp_ms", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "ms", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "position_lat", field_type: "sint32", base_type: 0x85, scale: 1.0, offset: 0.0, units: "semicircles", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "position_long", field_type: "sint32", base_type: 0x85, scale: 1.0, offset: 0.0, units: "semicircles", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "enhanced_altitude", field_type: "uint32", base_type: 0x86, scale: 5.0, offset: 500.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "enhanced_speed", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "m/s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "heading", field_type: "uint16", base_type: 0x84, scale: 100.0, offset: 0.0, units: "degrees", components: &[], subfields: &[] },
+        FieldProfile { field_num: 6, name: "utc_timestamp", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 7, name: "velocity", field_type: "sint16", base_type: 0x83, scale: 100.0, offset: 0.0, units: "m/s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 253, name: "timestamp", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 161, name: "camera_event", fields: &[
+        FieldProfile { field_num: 0, name: "timestamp_ms", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "ms", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "camera_event_type", field_type: "camera_event_type", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "camera_file_uuid", field_type: "string", base_type: 0x07, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "camera_orientation", field_type: "camera_orientation_type", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 253, name: "timestamp", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 162, name: "timestamp_correlation", fields: &[
+        FieldProfile { field_num: 0, name: "fractional_timestamp", field_type: "uint16", base_type: 0x84, scale: 32768.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "system_timestamp", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "fractional_system_timestamp", field_type: "uint16", base_type: 0x84, scale: 32768.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "local_timestamp", field_type: "local_date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "timestamp_ms", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "ms", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "system_timestamp_ms", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "ms", components: &[], subfields: &[] },
+        FieldProfile { field_num: 253, name: "timestamp", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 164, name: "gyroscope_data", fields: &[
+        FieldProfile { field_num: 0, name: "timestamp_ms", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "ms", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "sample_time_offset", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "ms", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "gyro_x", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "counts", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "gyro_y", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "counts", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "gyro_z", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "counts", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "calibrated_gyro_x", field_type: "float32", base_type: 0x88, scale: 1.0, offset: 0.0, units: "deg/s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 6, name: "calibrated_gyro_y", field_type: "float32", base_type: 0x88, scale: 1.0, offset: 0.0, units: "deg/s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 7, name: "calibrated_gyro_z", field_type: "float32", base_type: 0x88, scale: 1.0, offset: 0.0, units: "deg/s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 253, name: "timestamp", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 165, name: "accelerometer_data", fields: &[
+        FieldProfile { field_num: 0, name: "timestamp_ms", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "ms", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "sample_time_offset", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "ms", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "accel_x", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "counts", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "accel_y", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "counts", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "accel_z", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "counts", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "calibrated_accel_x", field_type: "float32", base_type: 0x88, scale: 1.0, offset: 0.0, units: "g", components: &[], subfields: &[] },
+        FieldProfile { field_num: 6, name: "calibrated_accel_y", field_type: "float32", base_type: 0x88, scale: 1.0, offset: 0.0, units: "g", components: &[], subfields: &[] },
+        FieldProfile { field_num: 7, name: "calibrated_accel_z", field_type: "float32", base_type: 0x88, scale: 1.0, offset: 0.0, units: "g", components: &[], subfields: &[] },
+        FieldProfile { field_num: 8, name: "compressed_calibrated_accel_x", field_type: "sint16", base_type: 0x83, scale: 1.0, offset: 0.0, units: "mG", components: &[], subfields: &[] },
+        FieldProfile { field_num: 9, name: "compressed_calibrated_accel_y", field_type: "sint16", base_type: 0x83, scale: 1.0, offset: 0.0, units: "mG", components: &[], subfields: &[] },
+        FieldProfile { field_num: 10, name: "compressed_calibrated_accel_z", field_type: "sint16", base_type: 0x83, scale: 1.0, offset: 0.0, units: "mG", components: &[], subfields: &[] },
+        FieldProfile { field_num: 253, name: "timestamp", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 167, name: "three_d_sensor_calibration", fields: &[
+        FieldProfile { field_num: 0, name: "sensor_type", field_type: "sensor_type", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "calibration_factor", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[
+            SubfieldProfile { references: &[SubfieldReference { field_num: 0, value: 0 }], field: FieldProfile { field_num: 1, name: "accel_cal_factor", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "g", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 0, value: 1 }], field: FieldProfile { field_num: 1, name: "gyro_cal_factor", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "deg/s", components: &[], subfields: &[] } },
+        ] },
+        FieldProfile { field_num: 2, name: "calibration_divisor", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "counts", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "level_shift", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "offset_cal", field_type: "sint32", base_type: 0x85, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "orientation_matrix", field_type: "sint32", base_type: 0x85, scale: 65535.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 253, name: "timestamp", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 169, name: "video_frame", fields: &[
+        FieldProfile { field_num: 0, name: "timestamp_ms", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "ms", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "frame_number", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 253, name: "timestamp", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 174, name: "obdii_data", fields: &[
+        FieldProfile { field_num: 0, name: "timestamp_ms", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "ms", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "time_offset", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "ms", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "pid", field_type: "byte", base_type: 0x0d, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "raw_data", field_type: "byte", base_type: 0x0d, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "pid_data_size", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "system_time", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 6, name: "start_timestamp", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 7, name: "start_timestamp_ms", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "ms", components: &[], subfields: &[] },
+        FieldProfile { field_num: 253, name: "timestamp", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 177, name: "nmea_sentence", fields: &[
+        FieldProfile { field_num: 0, name: "timestamp_ms", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "ms", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "sentence", field_type: "string", base_type: 0x07, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 253, name: "timestamp", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 178, name: "aviation_attitude", fields: &[
+        FieldProfile { field_num: 0, name: "timestamp_ms", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "ms", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "system_time", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "ms", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "pitch", field_type: "sint16", base_type: 0x83, scale: 10430.38, offset: 0.0, units: "radians", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "roll", field_type: "sint16", base_type: 0x83, scale: 10430.38, offset: 0.0, units: "radians", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "accel_lateral", field_type: "sint16", base_type: 0x83, scale: 100.0, offset: 0.0, units: "m/s^2", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "accel_normal", field_type: "sint16", base_type: 0x83, scale: 100.0, offset: 0.0, units: "m/s^2", components: &[], subfields: &[] },
+        FieldProfile { field_num: 6, name: "turn_rate", field_type: "sint16", base_type: 0x83, scale: 1024.0, offset: 0.0, units: "radians/second", components: &[], subfields: &[] },
+        FieldProfile { field_num: 7, name: "stage", field_type: "attitude_stage", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 8, name: "attitude_stage_complete", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "%", components: &[], subfields: &[] },
+        FieldProfile { field_num: 9, name: "track", field_type: "uint16", base_type: 0x84, scale: 10430.38, offset: 0.0, units: "radians", components: &[], subfields: &[] },
+        FieldProfile { field_num: 10, name: "validity", field_type: "attitude_validity", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 253, name: "timestamp", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 184, name: "video", fields: &[
+        FieldProfile { field_num: 0, name: "url", field_type: "string", base_type: 0x07, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "hosting_provider", field_type: "string", base_type: 0x07, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "duration", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "ms", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 185, name: "video_title", fields: &[
+        FieldProfile { field_num: 0, name: "message_count", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "text", field_type: "string", base_type: 0x07, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 254, name: "message_index", field_type: "message_index", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 186, name: "video_description", fields: &[
+        FieldProfile { field_num: 0, name: "message_count", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "text", field_type: "string", base_type: 0x07, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 254, name: "message_index", field_type: "message_index", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 187, name: "video_clip", fields: &[
+        FieldProfile { field_num: 0, name: "clip_number", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "start_timestamp", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "start_timestamp_ms", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "end_timestamp", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "end_timestamp_ms", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 6, name: "clip_start", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "ms", components: &[], subfields: &[] },
+        FieldProfile { field_num: 7, name: "clip_end", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "ms", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 188, name: "ohr_settings", fields: &[
+        FieldProfile { field_num: 0, name: "enabled", field_type: "switch", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 253, name: "timestamp", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 200, name: "exd_screen_configuration", fields: &[
+        FieldProfile { field_num: 0, name: "screen_index", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "field_count", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "layout", field_type: "exd_layout", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "screen_enabled", field_type: "bool", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 201, name: "exd_data_field_configuration", fields: &[
+        FieldProfile { field_num: 0, name: "screen_index", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "concept_field", field_type: "byte", base_type: 0x0d, scale: 1.0, offset: 0.0, units: "", components: &[ComponentProfile { field_num: 2, bits: 4, scale: 1.0, offset: 0.0, accumulate: false }, ComponentProfile { field_num: 3, bits: 4, scale: 1.0, offset: 0.0, accumulate: false }], subfields: &[] },
+        FieldProfile { field_num: 2, name: "field_id", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "concept_count", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "display_type", field_type: "exd_display_type", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "title", field_type: "string", base_type: 0x07, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 202, name: "exd_data_concept_configuration", fields: &[
+        FieldProfile { field_num: 0, name: "screen_index", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "concept_field", field_type: "byte", base_type: 0x0d, scale: 1.0, offset: 0.0, units: "", components: &[ComponentProfile { field_num: 2, bits: 4, scale: 1.0, offset: 0.0, accumulate: false }, ComponentProfile { field_num: 3, bits: 4, scale: 1.0, offset: 0.0, accumulate: false }], subfields: &[] },
+        FieldProfile { field_num: 2, name: "field_id", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "concept_index", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "data_page", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "concept_key", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 6, name: "scaling", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 8, name: "data_units", field_type: "exd_data_units", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 9, name: "qualifier", field_type: "exd_qualifiers", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 10, name: "descriptor", field_type: "exd_descriptors", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 11, name: "is_signed", field_type: "bool", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 206, name: "field_description", fields: &[
+        FieldProfile { field_num: 0, name: "developer_data_index", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "field_definition_number", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "fit_base_type_id", field_type: "fit_base_type", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "field_name", field_type: "string", base_type: 0x07, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "array", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "components", field_type: "string", base_type: 0x07, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 6, name: "scale", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 7, name: "offset", field_type: "sint8", base_type: 0x01, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 8, name: "units", field_type: "string", base_type: 0x07, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 9, name: "bits", field_type: "string", base_type: 0x07, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 10, name: "accumulate", field_type: "string", base_type: 0x07, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 13, name: "fit_base_unit_id", field_type: "fit_base_unit", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 14, name: "native_mesg_num", field_type: "mesg_num", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 15, name: "native_field_num", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 207, name: "developer_data_id", fields: &[
+        FieldProfile { field_num: 0, name: "developer_id", field_type: "byte", base_type: 0x0d, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "application_id", field_type: "byte", base_type: 0x0d, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "manufacturer_id", field_type: "manufacturer", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "developer_data_index", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "application_version", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 208, name: "magnetometer_data", fields: &[
+        FieldProfile { field_num: 0, name: "timestamp_ms", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "ms", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "sample_time_offset", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "ms", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "mag_x", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "counts", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "mag_y", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "counts", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "mag_z", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "counts", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "calibrated_mag_x", field_type: "float32", base_type: 0x88, scale: 1.0, offset: 0.0, units: "G", components: &[], subfields: &[] },
+        FieldProfile { field_num: 6, name: "calibrated_mag_y", field_type: "float32", base_type: 0x88, scale: 1.0, offset: 0.0, units: "G", components: &[], subfields: &[] },
+        FieldProfile { field_num: 7, name: "calibrated_mag_z", field_type: "float32", base_type: 0x88, scale: 1.0, offset: 0.0, units: "G", components: &[], subfields: &[] },
+        FieldProfile { field_num: 253, name: "timestamp", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 209, name: "barometer_data", fields: &[
+        FieldProfile { field_num: 0, name: "timestamp_ms", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "ms", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "sample_time_offset", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "ms", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "baro_pres", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "Pa", components: &[], subfields: &[] },
+        FieldProfile { field_num: 253, name: "timestamp", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 210, name: "one_d_sensor_calibration", fields: &[
+        FieldProfile { field_num: 0, name: "sensor_type", field_type: "sensor_type", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "calibration_factor", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[
+            SubfieldProfile { references: &[SubfieldReference { field_num: 0, value: 3 }], field: FieldProfile { field_num: 1, name: "baro_cal_factor", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "Pa", components: &[], subfields: &[] } },
+        ] },
+        FieldProfile { field_num: 2, name: "calibration_divisor", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "counts", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "level_shift", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "offset_cal", field_type: "sint32", base_type: 0x85, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 253, name: "timestamp", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 225, name: "set", fields: &[
+        FieldProfile { field_num: 0, name: "duration", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "repetitions", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "weight", field_type: "uint16", base_type: 0x84, scale: 16.0, offset: 0.0, units: "kg", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "set_type", field_type: "set_type", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 6, name: "start_time", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 7, name: "category", field_type: "exercise_category", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 8, name: "category_subtype", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 9, name: "weight_display_unit", field_type: "fit_base_unit", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 10, name: "message_index", field_type: "message_index", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 11, name: "wkt_step_index", field_type: "message_index", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 254, name: "timestamp", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 227, name: "stress_level", fields: &[
+        FieldProfile { field_num: 0, name: "stress_level_value", field_type: "sint16", base_type: 0x83, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "stress_level_time", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 258, name: "dive_settings", fields: &[
+        FieldProfile { field_num: 0, name: "name", field_type: "string", base_type: 0x07, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "model", field_type: "tissue_model_type", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "gf_low", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "gf_high", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "water_type", field_type: "water_type", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "water_density", field_type: "float32", base_type: 0x88, scale: 1.0, offset: 0.0, units: "kg/m^3", components: &[], subfields: &[] },
+        FieldProfile { field_num: 6, name: "po2_warn", field_type: "uint8", base_type: 0x02, scale: 100.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 7, name: "po2_critical", field_type: "uint8", base_type: 0x02, scale: 100.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 8, name: "po2_deco", field_type: "uint8", base_type: 0x02, scale: 100.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 9, name: "safety_stop_enabled", field_type: "bool", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 10, name: "bottom_depth", field_type: "float32", base_type: 0x88, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 11, name: "bottom_time", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 12, name: "apnea_countdown_enabled", field_type: "bool", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 13, name: "apnea_countdown_time", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 14, name: "backlight_mode", field_type: "dive_backlight_mode", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 15, name: "backlight_brightness", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 16, name: "backlight_timeout", field_type: "backlight_timeout", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 17, name: "repeat_dive_interval", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 18, name: "safety_stop_time", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 19, name: "heart_rate_source_type", field_type: "source_type", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 20, name: "heart_rate_source", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[
+            SubfieldProfile { references: &[SubfieldReference { field_num: 19, value: 1 }], field: FieldProfile { field_num: 20, name: "heart_rate_antplus_device_type", field_type: "antplus_device_type", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] } },
+            SubfieldProfile { references: &[SubfieldReference { field_num: 19, value: 5 }], field: FieldProfile { field_num: 20, name: "heart_rate_local_device_type", field_type: "local_device_type", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] } },
+        ] },
+        FieldProfile { field_num: 254, name: "message_index", field_type: "message_index", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 259, name: "dive_gas", fields: &[
+        FieldProfile { field_num: 0, name: "helium_content", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "oxygen_content", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "status", field_type: "dive_gas_status", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 254, name: "message_index", field_type: "message_index", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 262, name: "dive_alarm", fields: &[
+        FieldProfile { field_num: 0, name: "depth", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "time", field_type: "sint32", base_type: 0x85, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "enabled", field_type: "bool", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "alarm_type", field_type: "dive_alarm_type", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "sound", field_type: "tone", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "dive_types", field_type: "sub_sport", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 254, name: "message_index", field_type: "message_index", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 264, name: "exercise_title", fields: &[
+        FieldProfile { field_num: 0, name: "exercise_category", field_type: "exercise_category", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "exercise_name", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "wkt_step_name", field_type: "string", base_type: 0x07, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 254, name: "message_index", field_type: "message_index", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 268, name: "dive_summary", fields: &[
+        FieldProfile { field_num: 0, name: "reference_mesg", field_type: "mesg_num", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "reference_index", field_type: "message_index", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "avg_depth", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "max_depth", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "surface_interval", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "start_cns", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 6, name: "end_cns", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 7, name: "start_n2", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 8, name: "end_n2", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "percent", components: &[], subfields: &[] },
+        FieldProfile { field_num: 9, name: "o2_toxicity", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "OTUs", components: &[], subfields: &[] },
+        FieldProfile { field_num: 10, name: "dive_number", field_type: "uint32", base_type: 0x86, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 11, name: "bottom_time", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 253, name: "timestamp", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 285, name: "jump", fields: &[
+        FieldProfile { field_num: 0, name: "distance", field_type: "float32", base_type: 0x88, scale: 1.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "height", field_type: "float32", base_type: 0x88, scale: 1.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "rotations", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "hang_time", field_type: "float32", base_type: 0x88, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "score", field_type: "float32", base_type: 0x88, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "position_lat", field_type: "sint32", base_type: 0x85, scale: 1.0, offset: 0.0, units: "semicircles", components: &[], subfields: &[] },
+        FieldProfile { field_num: 6, name: "position_long", field_type: "sint32", base_type: 0x85, scale: 1.0, offset: 0.0, units: "semicircles", components: &[], subfields: &[] },
+        FieldProfile { field_num: 7, name: "speed", field_type: "uint16", base_type: 0x84, scale: 1000.0, offset: 0.0, units: "m/s", components: &[ComponentProfile { field_num: 8, bits: 16, scale: 1000.0, offset: 0.0, accumulate: false }], subfields: &[] },
+        FieldProfile { field_num: 8, name: "enhanced_speed", field_type: "uint32", base_type: 0x86, scale: 1000.0, offset: 0.0, units: "m/s", components: &[], subfields: &[] },
+        FieldProfile { field_num: 253, name: "timestamp", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+    ] },
+    MessageProfile { global_message_num: 317, name: "climb_pro", fields: &[
+        FieldProfile { field_num: 0, name: "position_lat", field_type: "sint32", base_type: 0x85, scale: 1.0, offset: 0.0, units: "semicircles", components: &[], subfields: &[] },
+        FieldProfile { field_num: 1, name: "position_long", field_type: "sint32", base_type: 0x85, scale: 1.0, offset: 0.0, units: "semicircles", components: &[], subfields: &[] },
+        FieldProfile { field_num: 2, name: "climb_pro_event", field_type: "climb_pro_event", base_type: 0x00, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 3, name: "climb_number", field_type: "uint16", base_type: 0x84, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 4, name: "climb_category", field_type: "uint8", base_type: 0x02, scale: 1.0, offset: 0.0, units: "", components: &[], subfields: &[] },
+        FieldProfile { field_num: 5, name: "current_dist", field_type: "float32", base_type: 0x88, scale: 1.0, offset: 0.0, units: "m", components: &[], subfields: &[] },
+        FieldProfile { field_num: 253, name: "timestamp", field_type: "date_time", base_type: 0x86, scale: 1.0, offset: 0.0, units: "s", components: &[], subfields: &[] },
+    ] },
+];
+// END GENERATED CODE
@@ -18,42 +18,40 @@
 // LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
- #![allow(dead_code)]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 
 pub mod fit_file;
+pub mod fit_io;
+pub mod fit_profile;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod activity_tests {
     use std::collections::HashMap;
     extern crate csv;
 
     /// Called for each record message as it is processed.
-    fn callback(timestamp: u32, global_message_num: u16, local_msg_type: u8, _message_index: u16, fields: Vec<crate::fit_file::FitFieldValue>, data: &mut Context) {
+    fn callback(timestamp: Option<crate::fit_file::FitDateTime>, global_message_num: u16, local_msg_type: u8, _message_index: crate::fit_file::MessageIndex, _segment: usize, fields: Vec<crate::fit_file::FitFieldValue>, data: &mut Context) {
         if global_message_num == crate::fit_file::GLOBAL_MSG_NUM_SESSION {
-            let msg = crate::fit_file::FitSessionMsg::new(fields);
+            let msg = crate::fit_file::FitSessionMsg::new(&fields);
             let sport_names = crate::fit_file::init_sport_name_map();
             let sport_id = msg.sport.unwrap();
 
             println!("[Sport Message] {}", sport_names.get(&sport_id).unwrap());
         }
         else if global_message_num == crate::fit_file::GLOBAL_MSG_NUM_RECORD {
-            let msg = crate::fit_file::FitRecordMsg::new(fields);
+            let msg = crate::fit_file::FitRecordMsg::new(&fields);
             let mut latitude = 0.0;
             let mut longitude = 0.0;
             let mut altitude = 0.0;
             let mut power = 0;
             let mut valid_location = true;
 
+            // Make sure we have a valid reading.
             match msg.position_lat {
                 Some(res) => {
-
-                    // Make sure we have a valid reading.
-                    if res != 0x7FFFFFFF {
-                        latitude = crate::fit_file::semicircles_to_degrees(res);
-                    }
-                    else {
-                        valid_location = false;
-                    }
+                    latitude = crate::fit_file::semicircles_to_degrees(res);
                 }
                 None => {
                     valid_location = false;
@@ -61,46 +59,25 @@ mod activity_tests {
             }
             match msg.position_long {
                 Some(res) => {
-
-                    // Make sure we have a valid reading.
-                    if res != 0x7FFFFFFF {
-                        longitude = crate::fit_file::semicircles_to_degrees(res);
-                    }
-                    else {
-                        valid_location = false;
-                    }
+                    longitude = crate::fit_file::semicircles_to_degrees(res);
                 }
                 None => {
                     valid_location = false;
                 }
             }
-            match msg.altitude {
-                Some(res) => {
-
-                    // Make sure we have a valid reading.
-                    if res != 0xFFFF {
-                        altitude = (res as f64 / 5.0) - 500.0;
-                    }
-                }
-                None => {
-                }
+            if let Some(res) = msg.altitude_m() {
+                altitude = res;
             }
-            match msg.power {
-                Some(res) => {
-                    if res != 0xFFFF {
-                        power = res;
-                    }
-                }
-                None => {
-                }
+            if let Some(res) = msg.power {
+                power = res;
             }
 
             // Increment the number of records processed.
-            data.num_records_processed = data.num_records_processed + 1;
-            data.accumulated_power = data.accumulated_power + power as u64;
+            data.num_records_processed += 1;
+            data.accumulated_power += power as u64;
 
             if valid_location {
-                println!("[Record Message] Timestamp: {} Latitude: {} Longitude: {} Altitude: {}", timestamp, latitude, longitude, altitude);
+                println!("[Record Message] Timestamp: {:?} Latitude: {} Longitude: {} Altitude: {}", timestamp, latitude, longitude, altitude);
             }
             else {
                 println!("[Record Message] Invalid location data");
@@ -108,37 +85,39 @@ mod activity_tests {
         }
         else if global_message_num == crate::fit_file::GLOBAL_MSG_NUM_LENGTH {
             // Increment the number of records processed.
-            data.num_length_msgs_processed = data.num_length_msgs_processed + 1;
+            data.num_length_msgs_processed += 1;
         }
         else {
             let global_message_names = crate::fit_file::init_global_msg_name_map();
-            let mut field_num = 1;
 
             match global_message_names.get(&global_message_num) {
-                Some(name) => println!("[{} Message] Timestamp {}, Values: ", name, timestamp),
-                None => println!("[Global Message Num {} Local Message Type {}] Timestamp {}, Values: ", global_message_num, local_msg_type, timestamp)
+                Some(name) => println!("[{} Message] Timestamp {:?}, Values: ", name, timestamp),
+                None => println!("[Global Message Num {} Local Message Type {}] Timestamp {:?}, Values: ", global_message_num, local_msg_type, timestamp)
             }
 
-            for field in fields {
-                print!("   ({}) Base Type: {}, Value: ", field_num, field.base_type);
+            for (field_num, field) in fields.iter().enumerate() {
+                print!("   ({}) Base Type: {}, Value: ", field_num + 1, field.base_type);
 
-                match field.type_enum {
-                    crate::fit_file::FieldType::FieldTypeNotSet => { print!("[not set] "); },
-                    crate::fit_file::FieldType::FieldTypeUInt => { print!("{} ", field.value_uint); },
-                    crate::fit_file::FieldType::FieldTypeSInt => { print!("{} ", field.value_sint); },
-                    crate::fit_file::FieldType::FieldTypeFloat => { print!("{} ", field.value_float); },
-                    crate::fit_file::FieldType::FieldTypeByteArray => {
-                        for byte in field.value_byte_array.iter() {
+                match &field.value {
+                    crate::fit_file::FitValue::NotSet => { print!("[not set] "); },
+                    crate::fit_file::FitValue::UInt(value) => { print!("{} ", value); },
+                    crate::fit_file::FitValue::SInt(value) => { print!("{} ", value); },
+                    crate::fit_file::FitValue::Float(value) => { print!("{} ", value); },
+                    crate::fit_file::FitValue::ByteArray(bytes) => {
+                        for byte in bytes.iter() {
                             print!("{:#04x} ", byte);
                         }
                     },
-                    crate::fit_file::FieldType::FieldTypeStr => { print!("\"{}\" ", field.value_string); },
+                    crate::fit_file::FitValue::Str(value) => { print!("\"{}\" ", value); },
+                    crate::fit_file::FitValue::UIntArray(values) => { print!("{:?} ", values); },
+                    crate::fit_file::FitValue::SIntArray(values) => { print!("{:?} ", values); },
+                    crate::fit_file::FitValue::FloatArray(values) => { print!("{:?} ", values); },
+                    crate::fit_file::FitValue::StrArray(values) => { print!("{:?} ", values); },
                 }
 
-                field_num = field_num + 1;
-                println!("");
+                println!();
             }
-            println!("");
+            println!();
         }
     }
 
@@ -151,8 +130,7 @@ mod activity_tests {
 
     impl Context {
         pub fn new() -> Self {
-            let context = Context{ num_records_processed: 0, num_length_msgs_processed: 0, accumulated_power: 0 };
-            context
+            Context{ num_records_processed: 0, num_length_msgs_processed: 0, accumulated_power: 0 }
         }
     }
 
@@ -161,18 +139,13 @@ mod activity_tests {
         let file = std::fs::File::open("tests/20210218_zwift.fit").unwrap();
         let mut reader = std::io::BufReader::new(file);
         let mut context = Context::new();
-        let fit = crate::fit_file::read(&mut reader, callback, &mut context);
+        let fit = crate::fit_file::read(&mut reader, callback, &mut context).unwrap();
 
-        match fit {
-            Ok(fit) => {
-                print!("FIT File Header: ");
-                fit.header.print();
-                println!("");
-                println!("Num records processed: {}", context.num_records_processed);
-                assert!(context.num_records_processed == 1163);
-            }
-            _ => { println!("Error"); },
-        }
+        print!("FIT File Header: ");
+        fit.header.print();
+        println!();
+        println!("Num records processed: {}", context.num_records_processed);
+        assert!(context.num_records_processed == 1163);
     }
 
     #[test]
@@ -180,18 +153,13 @@ mod activity_tests {
         let file = std::fs::File::open("tests/20191117_bike_wahoo_elemnt.fit").unwrap();
         let mut reader = std::io::BufReader::new(file);
         let mut context = Context::new();
-        let fit = crate::fit_file::read(&mut reader, callback, &mut context);
+        let fit = crate::fit_file::read(&mut reader, callback, &mut context).unwrap();
 
-        match fit {
-            Ok(fit) => {
-                print!("FIT File Header: ");
-                fit.header.print();
-                println!("");
-                println!("Num records processed: {}", context.num_records_processed);
-                assert!(context.num_records_processed == 4876);
-            }
-            _ => { println!("Error"); },
-        }
+        print!("FIT File Header: ");
+        fit.header.print();
+        println!();
+        println!("Num records processed: {}", context.num_records_processed);
+        assert!(context.num_records_processed == 4876);
     }
 
     #[test]
@@ -199,18 +167,13 @@ mod activity_tests {
         let file = std::fs::File::open("tests/20200529_short_ocean_swim.fit").unwrap();
         let mut reader = std::io::BufReader::new(file);
         let mut context = Context::new();
-        let fit = crate::fit_file::read(&mut reader, callback, &mut context);
+        let fit = crate::fit_file::read(&mut reader, callback, &mut context).unwrap();
 
-        match fit {
-            Ok(fit) => {
-                print!("FIT File Header: ");
-                fit.header.print();
-                println!("");
-                println!("Num records processed: {}", context.num_records_processed);
-                assert!(context.num_records_processed == 179);
-            }
-            _ => (),
-        }
+        print!("FIT File Header: ");
+        fit.header.print();
+        println!();
+        println!("Num records processed: {}", context.num_records_processed);
+        assert!(context.num_records_processed == 179);
     }
 
     #[test]
@@ -218,20 +181,15 @@ mod activity_tests {
         let file = std::fs::File::open("tests/20210507_run_coros_pace_2.fit").unwrap();
         let mut reader = std::io::BufReader::new(file);
         let mut context = Context::new();
-        let fit = crate::fit_file::read(&mut reader, callback, &mut context);
+        let fit = crate::fit_file::read(&mut reader, callback, &mut context).unwrap();
 
-        match fit {
-            Ok(fit) => {
-                print!("FIT File Header: ");
-                fit.header.print();
-                println!("");
-                println!("Num records processed: {}", context.num_records_processed);
-                println!("Accumulated power: {}", context.accumulated_power);
-                assert!(context.num_records_processed == 2364);
-                assert!(context.accumulated_power == 634203);
-            }
-            _ => (),
-        }
+        print!("FIT File Header: ");
+        fit.header.print();
+        println!();
+        println!("Num records processed: {}", context.num_records_processed);
+        println!("Accumulated power: {}", context.accumulated_power);
+        assert!(context.num_records_processed == 2364);
+        assert!(context.accumulated_power == 634203);
     }
 
     #[test]
@@ -239,18 +197,13 @@ mod activity_tests {
         let file = std::fs::File::open("tests/20210610_track_garmin_fenix_6.fit").unwrap();
         let mut reader = std::io::BufReader::new(file);
         let mut context = Context::new();
-        let fit = crate::fit_file::read(&mut reader, callback, &mut context);
+        let fit = crate::fit_file::read(&mut reader, callback, &mut context).unwrap();
 
-        match fit {
-            Ok(fit) => {
-                print!("FIT File Header: ");
-                fit.header.print();
-                println!("");
-                println!("Num records processed: {}", context.num_records_processed);
-                assert!(context.num_records_processed == 1672);
-            }
-            _ => (),
-        }
+        print!("FIT File Header: ");
+        fit.header.print();
+        println!();
+        println!("Num records processed: {}", context.num_records_processed);
+        assert!(context.num_records_processed == 1672);
     }
 
     #[test]
@@ -258,21 +211,1151 @@ mod activity_tests {
         let file = std::fs::File::open("tests/20210709_pool_swim.fit").unwrap();
         let mut reader = std::io::BufReader::new(file);
         let mut context = Context::new();
-        let fit = crate::fit_file::read(&mut reader, callback, &mut context);
+        let fit = crate::fit_file::read(&mut reader, callback, &mut context).unwrap();
 
-        match fit {
-            Ok(fit) => {
-                print!("FIT File Header: ");
-                fit.header.print();
-                println!("");
-                println!("Num records processed: {}", context.num_length_msgs_processed);
-                assert!(context.num_length_msgs_processed == 55);
+        print!("FIT File Header: ");
+        fit.header.print();
+        println!();
+        println!("Num records processed: {}", context.num_length_msgs_processed);
+        assert!(context.num_length_msgs_processed == 55);
+    }
+
+    #[test]
+    fn crc_valid() {
+        let file_names = [ "tests/20210218_zwift.fit", "tests/20191117_bike_wahoo_elemnt.fit", "tests/20200529_short_ocean_swim.fit",
+            "tests/20210507_run_coros_pace_2.fit", "tests/20210610_track_garmin_fenix_6.fit", "tests/20210709_pool_swim.fit" ];
+
+        for file_name in file_names.iter() {
+            let file = std::fs::File::open(file_name).unwrap();
+            let mut reader = std::io::BufReader::new(file);
+            let mut fit = crate::fit_file::Fit::new();
+            fit.strict_crc = true;
+            fit.read(&mut reader, &mut |_msg: &crate::fit_file::FitMessage| {}).unwrap();
+
+            assert!(fit.header_crc_valid);
+            assert!(fit.crc_valid);
+        }
+    }
+
+    #[test]
+    fn crc_mismatch() {
+        let mut bytes = std::fs::read("tests/20210709_pool_swim.fit").unwrap();
+        let last_byte = bytes.len() - 1;
+        bytes[last_byte] ^= 0xff;
+
+        // Lenient: the file is parsed and the mismatch is reported.
+        let mut reader = std::io::BufReader::new(std::io::Cursor::new(bytes.clone()));
+        let mut context = Context::new();
+        let fit = crate::fit_file::read(&mut reader, callback, &mut context).unwrap();
+        assert!(fit.header_crc_valid);
+        assert!(!fit.crc_valid);
+        assert!(context.num_length_msgs_processed == 55);
+
+        // Strict: the mismatch is an error.
+        let mut reader = std::io::BufReader::new(std::io::Cursor::new(bytes.clone()));
+        let mut fit = crate::fit_file::Fit::new();
+        fit.strict_crc = true;
+        match fit.read(&mut reader, &mut |_msg: &crate::fit_file::FitMessage| {}) {
+            Err(crate::fit_file::FitError::CrcMismatch { offset, .. }) => assert_eq!(offset, last_byte as u64 - 1),
+            _ => panic!("Expected a CRC mismatch."),
+        }
+
+        // Corrupt the header CRC as well.
+        bytes[13] ^= 0xff;
+        let mut reader = std::io::BufReader::new(std::io::Cursor::new(bytes.clone()));
+        let mut context = Context::new();
+        let fit = crate::fit_file::read(&mut reader, callback, &mut context).unwrap();
+        assert!(!fit.header_crc_valid);
+
+        // Strict: a header CRC mismatch is reported as a CRC mismatch at the header CRC, not as a bad header.
+        let mut reader = std::io::BufReader::new(std::io::Cursor::new(bytes));
+        let mut fit = crate::fit_file::Fit::new();
+        fit.strict_crc = true;
+        match fit.read(&mut reader, &mut |_msg: &crate::fit_file::FitMessage| {}) {
+            Err(crate::fit_file::FitError::CrcMismatch { offset, .. }) => assert_eq!(offset, 12),
+            _ => panic!("Expected a header CRC mismatch."),
+        }
+    }
+
+    #[test]
+    fn malformed_files() {
+        let bytes = std::fs::read("tests/20210709_pool_swim.fit").unwrap();
+
+        // Truncated in the middle of a record.
+        let mut reader = std::io::BufReader::new(std::io::Cursor::new(bytes[..1000].to_vec()));
+        let mut context = Context::new();
+        match crate::fit_file::read(&mut reader, callback, &mut context) {
+            Err(e @ crate::fit_file::FitError::TruncatedData { .. }) => {
+                assert!(e.offset().unwrap() <= 1000);
+                assert!(e.local_msg_type().is_some());
             }
-            _ => (),
+            _ => panic!("Expected truncated data."),
         }
+
+        // Not a FIT file.
+        let mut reader = std::io::BufReader::new(std::io::Cursor::new(b"this is not a FIT file".to_vec()));
+        let mut context = Context::new();
+        assert!(matches!(crate::fit_file::read(&mut reader, callback, &mut context), Err(crate::fit_file::FitError::BadHeader { offset: 0 })));
+
+        // A data message for a local message type that was never defined.
+        let mut data = bytes[..14].to_vec();
+        data.push(0x05);
+        let mut reader = std::io::BufReader::new(std::io::Cursor::new(data));
+        let mut context = Context::new();
+        assert!(matches!(crate::fit_file::read(&mut reader, callback, &mut context), Err(crate::fit_file::FitError::MissingDefinition { offset: 15, local_msg_type: 5 })));
+
+        // A field with a base type that doesn't exist.
+        let mut data = bytes[..14].to_vec();
+        data.extend_from_slice(&[0x42, 0x00, 0x00, 0x14, 0x00, 0x01, 0x03, 0x01, 0x0F, 0x02, 0xAA]);
+        let mut reader = std::io::BufReader::new(std::io::Cursor::new(data));
+        let mut context = Context::new();
+        assert!(matches!(crate::fit_file::read(&mut reader, callback, &mut context), Err(crate::fit_file::FitError::UnsupportedBaseType { offset: 24, local_msg_type: 2, field_def: 3, base_type: 0x0F })));
+
+        // A data size far bigger than the data, which can't even be added to the offset on 32 bit targets.
+        let mut data = synthetic_fit(&[]);
+        data[4..8].copy_from_slice(&[0xFF, 0xFF, 0xFF, 0xFF]);
+        data.truncate(12);
+        let result = crate::fit_file::Fit::new().read_slice(&data, &mut |_msg: &crate::fit_file::FitMessage| {});
+        assert!(matches!(result, Err(crate::fit_file::FitError::TruncatedData { .. })));
     }
 
-    fn convert_to_camel_case(name: &String) -> String {
+    /// Implements only the handler methods for the messages we care about.
+    #[derive(Default)]
+    struct MessageCounter {
+        num_definitions: usize,
+        num_records: usize,
+        num_laps: usize,
+        sports: Vec<u8>,
+        accumulated_power: u64
+    }
+
+    impl crate::fit_file::FitMessageHandler for MessageCounter {
+        fn on_definition(&mut self, _msg: &crate::fit_file::FitDefinitionMessage) {
+            self.num_definitions += 1;
+        }
+        fn on_record(&mut self, _msg: &crate::fit_file::FitDataMessage, record: &crate::fit_file::FitRecordMsg) {
+            self.num_records += 1;
+            if let Some(power) = record.power {
+                self.accumulated_power += power as u64;
+            }
+        }
+        fn on_lap(&mut self, _msg: &crate::fit_file::FitDataMessage, _lap: &crate::fit_file::FitLapMsg) {
+            self.num_laps += 1;
+        }
+        fn on_session(&mut self, _msg: &crate::fit_file::FitDataMessage, session: &crate::fit_file::FitSessionMsg) {
+            self.sports.push(session.sport.unwrap());
+        }
+    }
+
+    #[test]
+    fn message_handler() {
+        let file = std::fs::File::open("tests/20210507_run_coros_pace_2.fit").unwrap();
+        let mut reader = std::io::BufReader::new(file);
+        let mut counter = MessageCounter::default();
+        let mut fit = crate::fit_file::Fit::new();
+        fit.read(&mut reader, &mut counter).unwrap();
+
+        assert!(counter.num_definitions > 0);
+        assert_eq!(counter.num_records, 2364);
+        assert!(counter.num_laps > 0);
+        assert_eq!(counter.sports, vec![crate::fit_file::FIT_SPORT_RUNNING]);
+        assert_eq!(counter.accumulated_power, 634203);
+
+        // Trait objects work too.
+        let file = std::fs::File::open("tests/20210507_run_coros_pace_2.fit").unwrap();
+        let mut reader = std::io::BufReader::new(file);
+        let mut counter = MessageCounter::default();
+        let handler: &mut dyn crate::fit_file::FitMessageHandler = &mut counter;
+        fit.read(&mut reader, handler).unwrap();
+        assert_eq!(counter.num_records, 2364);
+    }
+
+    #[test]
+    fn closure_handler() {
+        let file = std::fs::File::open("tests/20210218_zwift.fit").unwrap();
+        let mut reader = std::io::BufReader::new(file);
+        let mut num_records = 0;
+        let mut last_timestamp = None;
+        let mut fit = crate::fit_file::Fit::new();
+        fit.read(&mut reader, &mut |msg: &crate::fit_file::FitMessage| {
+            if let crate::fit_file::FitMessage::Data(data) = msg {
+                if data.global_message_num == crate::fit_file::GLOBAL_MSG_NUM_RECORD {
+                    assert!(data.timestamp >= last_timestamp);
+                    last_timestamp = data.timestamp;
+                    num_records += 1;
+                }
+            }
+        }).unwrap();
+
+        assert_eq!(num_records, 1163);
+    }
+
+    #[test]
+    fn message_iterator() {
+        let file = std::fs::File::open("tests/20210218_zwift.fit").unwrap();
+        let reader = crate::fit_file::FitReader::new(std::io::BufReader::new(file));
+        let num_records = reader
+            .map(|msg| msg.unwrap())
+            .filter(|msg| matches!(msg, crate::fit_file::FitMessage::Data(data) if data.global_message_num == crate::fit_file::GLOBAL_MSG_NUM_RECORD))
+            .count();
+        assert_eq!(num_records, 1163);
+
+        // Stop as soon as the session message is found.
+        let file = std::fs::File::open("tests/20210709_pool_swim.fit").unwrap();
+        let mut reader = crate::fit_file::FitReader::new(std::io::BufReader::new(file));
+        let mut session = None;
+        for msg in &mut reader {
+            if let crate::fit_file::FitMessage::Data(data) = msg.unwrap() {
+                if data.global_message_num == crate::fit_file::GLOBAL_MSG_NUM_SESSION {
+                    session = Some(crate::fit_file::FitSessionMsg::new(&data.fields));
+                    break;
+                }
+            }
+        }
+        assert_eq!(session.unwrap().sport, Some(crate::fit_file::FIT_SPORT_SWIMMING));
+
+        // The header CRC has been checked, but the CRC at the end of the file hasn't been read yet.
+        assert!(reader.header_crc_valid);
+        assert!(!reader.crc_valid);
+        assert!(reader.next().is_some());
+        assert!(reader.by_ref().all(|msg| msg.is_ok()));
+        assert!(reader.crc_valid);
+
+        // Iteration stops after an error.
+        let bytes = std::fs::read("tests/20210709_pool_swim.fit").unwrap();
+        let mut reader = crate::fit_file::FitReader::new(std::io::Cursor::new(bytes[..1000].to_vec()));
+        assert!(reader.by_ref().any(|msg| msg.is_err()));
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn array_fields() {
+        let file = std::fs::File::open("tests/20191117_bike_wahoo_elemnt.fit").unwrap();
+        let reader = crate::fit_file::FitReader::new(std::io::BufReader::new(file));
+        let mut laps = Vec::new();
+        for msg in reader {
+            if let crate::fit_file::FitMessage::Data(data) = msg.unwrap() {
+                if data.global_message_num == crate::fit_file::GLOBAL_MSG_NUM_LAP {
+                    let field = data.fields.iter().find(|field| field.field_def == 60).unwrap();
+                    assert!(field.is_array());
+                    assert_eq!(field.get_u32(), 362164);
+                    laps.push(crate::fit_file::FitLapMsg::new(&data.fields));
+                }
+            }
+        }
+
+        assert_eq!(laps.len(), 1);
+        assert_eq!(laps[0].time_in_power_zone, Some(vec![362164, 1664002, 478596, 50780, 36734, 19268]));
+    }
+
+    #[test]
+    fn invalid_fields() {
+        // A few records in the swim file don't have a GPS position.
+        let file = std::fs::File::open("tests/20200529_short_ocean_swim.fit").unwrap();
+        let reader = crate::fit_file::FitReader::new(std::io::BufReader::new(file));
+        let mut num_invalid_positions = 0;
+        let mut num_records = 0;
+        for msg in reader {
+            if let crate::fit_file::FitMessage::Data(data) = msg.unwrap() {
+                if data.global_message_num == crate::fit_file::GLOBAL_MSG_NUM_RECORD {
+                    let lat_field = data.fields.iter().find(|field| field.field_def == 0);
+                    let record = crate::fit_file::FitRecordMsg::new(&data.fields);
+                    match lat_field {
+                        Some(field) if field.is_invalid => {
+                            assert_eq!(field.get_i32(), 0x7FFFFFFF);
+                            assert_eq!(record.position_lat, None);
+                            num_invalid_positions += 1;
+                        }
+                        Some(_) => assert!(record.position_lat.is_some()),
+                        None => assert_eq!(record.position_lat, None),
+                    }
+                    num_records += 1;
+                }
+            }
+        }
+        assert_eq!(num_records, 179);
+        assert_eq!(num_invalid_positions, 3);
+    }
+
+    #[test]
+    fn scaled_fields() {
+        let file = std::fs::File::open("tests/20191117_bike_wahoo_elemnt.fit").unwrap();
+        let reader = crate::fit_file::FitReader::new(std::io::BufReader::new(file));
+        let mut session = None;
+        let mut activity = None;
+        let mut num_altitudes = 0;
+        for msg in reader {
+            if let crate::fit_file::FitMessage::Data(data) = msg.unwrap() {
+                if data.global_message_num == crate::fit_file::GLOBAL_MSG_NUM_RECORD {
+                    let record = crate::fit_file::FitRecordMsg::new(&data.fields);
+                    if let Some(field) = data.fields.iter().find(|field| field.name() == Some("altitude")) {
+                        assert_eq!(field.units(), "m");
+                        assert_eq!(field.scaled(), record.altitude_m());
+                        assert_eq!(field.scaled(), record.altitude.map(|altitude| altitude as f64 / 5.0 - 500.0));
+                        num_altitudes += 1;
+                    }
+                }
+                else if data.global_message_num == crate::fit_file::GLOBAL_MSG_NUM_SESSION {
+                    session = Some(crate::fit_file::FitSessionMsg::new(&data.fields));
+                }
+                else if data.global_message_num == crate::fit_file::GLOBAL_MSG_NUM_ACTIVITY {
+                    activity = Some(crate::fit_file::FitActivityMsg::new(&data.fields));
+                }
+            }
+        }
+        assert!(num_altitudes > 0);
+
+        let session = session.unwrap();
+        assert!(session.total_distance.is_some() && session.avg_speed.is_some());
+        assert_eq!(session.total_distance_m(), session.total_distance.map(|distance| distance as f64 / 100.0));
+        assert_eq!(session.total_elapsed_time_s(), session.total_elapsed_time.map(|time| time as f64 / 1000.0));
+        assert_eq!(session.avg_speed_m_per_s(), session.avg_speed.map(|speed| speed as f64 / 1000.0));
+
+        let activity = activity.unwrap();
+        assert!(activity.total_timer_time.is_some());
+        assert_eq!(activity.total_timer_time_s(), activity.total_timer_time.map(|time| time as f64 / 1000.0));
+    }
+
+    #[test]
+    fn component_expansion() {
+        // Speed and altitude are also unpacked into enhanced_speed and enhanced_altitude.
+        let file = std::fs::File::open("tests/20210218_zwift.fit").unwrap();
+        let reader = crate::fit_file::FitReader::new(std::io::BufReader::new(file));
+        let mut num_speeds = 0;
+        for msg in reader {
+            if let crate::fit_file::FitMessage::Data(data) = msg.unwrap() {
+                if data.global_message_num == crate::fit_file::GLOBAL_MSG_NUM_RECORD {
+                    let record = crate::fit_file::FitRecordMsg::new(&data.fields);
+                    if let Some(speed) = record.speed {
+                        assert_eq!(record.enhanced_speed, Some(speed as u32));
+                        assert_eq!(record.enhanced_speed_m_per_s(), record.speed_m_per_s());
+                        num_speeds += 1;
+                    }
+                    if let Some(altitude) = record.altitude {
+                        assert_eq!(record.enhanced_altitude, Some(altitude as u32));
+                    }
+                }
+            }
+        }
+        assert!(num_speeds > 0);
+
+        // A record with only compressed_speed_distance: 5 m/s (500 / 100) and 50 m (800 / 16), packed into 12 bits each.
+        let records = read_synthetic_records(&[
+            &[0x40, 0x00, 0x00, 0x14, 0x00, 0x01, 0x08, 0x03, 0x0D],
+            &[0x00, 0xF4, 0x01, 0x32],
+        ]);
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].compressed_speed_distance, Some(vec![0xF4, 0x01, 0x32]));
+        assert_eq!(records[0].speed_m_per_s(), Some(5.0));
+        assert_eq!(records[0].enhanced_speed_m_per_s(), Some(5.0));
+        assert_eq!(records[0].distance_m(), Some(50.0));
+    }
+
+    #[test]
+    fn accumulated_fields() {
+        // Records with only the rolling counters: cycles (8 bits) and compressed_accumulated_power (16 bits), both of which wrap around.
+        let records = read_synthetic_records(&[
+            &[0x40, 0x00, 0x00, 0x14, 0x00, 0x02, 0x12, 0x01, 0x02, 0x1C, 0x02, 0x84],
+            &[0x00, 250, 0xE8, 0xFD], // 250 cycles, 65000 J
+            &[0x00, 4, 0xF4, 0x01], // 4 cycles, 500 J
+            &[0x00, 10, 0xE8, 0x03], // 10 cycles, 1000 J
+        ]);
+        let total_cycles: Vec<Option<u32>> = records.iter().map(|record| record.total_cycles).collect();
+        let accumulated_power: Vec<Option<u32>> = records.iter().map(|record| record.accumulated_power).collect();
+        assert_eq!(total_cycles, vec![Some(250), Some(260), Some(266)]);
+        assert_eq!(accumulated_power, vec![Some(65000), Some(66036), Some(66536)]);
+
+        // The compressed distance is accumulated too.
+        let records = read_synthetic_records(&[
+            &[0x40, 0x00, 0x00, 0x14, 0x00, 0x01, 0x08, 0x03, 0x0D],
+            &[0x00, 0x00, 0x00, 0xF0], // 240 m (3840 / 16)
+            &[0x00, 0x00, 0x00, 0x01], // 16 counts = 1 m, after wrapping around at 4096
+        ]);
+        let distances: Vec<Option<f64>> = records.iter().map(|record| record.distance_m()).collect();
+        assert_eq!(distances, vec![Some(240.0), Some(257.0)]);
+
+        // When the total is written in full, it is used as is.
+        let file = std::fs::File::open("tests/20200529_short_ocean_swim.fit").unwrap();
+        let reader = crate::fit_file::FitReader::new(std::io::BufReader::new(file));
+        for msg in reader {
+            if let crate::fit_file::FitMessage::Data(data) = msg.unwrap() {
+                if data.global_message_num == crate::fit_file::GLOBAL_MSG_NUM_RECORD {
+                    let written = data.fields.iter().find(|field| field.field_def == 19).unwrap().get_u32();
+                    let record = crate::fit_file::FitRecordMsg::new(&data.fields);
+                    assert_eq!(record.total_cycles, Some(written));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn subfields() {
+        // The data field of a gear change event is unpacked into the gear fields. The event field comes after the data field.
+        let messages = read_synthetic_messages(&[
+            &[0x40, 0x00, 0x00, 0x15, 0x00, 0x02, 0x03, 0x04, 0x86, 0x00, 0x01, 0x00],
+            &[0x00, 3, 28, 2, 50, 43], // rear gear 3 (28 teeth), front gear 2 (50 teeth), rear_gear_change
+        ]);
+        let data = messages[0].fields.iter().find(|field| field.field_def == 3).unwrap();
+        assert_eq!(data.name(), Some("gear_change_data"));
+        let event = crate::fit_file::FitEventMsg::new(&messages[0].fields);
+        assert_eq!((event.rear_gear_num, event.rear_gear, event.front_gear_num, event.front_gear), (Some(3), Some(28), Some(2), Some(50)));
+        assert_eq!(event.battery_level_v(), None);
+
+        // A battery event, the same data field is now a voltage.
+        let messages = read_synthetic_messages(&[
+            &[0x40, 0x00, 0x00, 0x15, 0x00, 0x02, 0x00, 0x01, 0x00, 0x03, 0x04, 0x86],
+            &[0x00, 11, 0xB8, 0x0B, 0x00, 0x00], // battery, 3000 mV
+        ]);
+        let data = messages[0].fields.iter().find(|field| field.field_def == 3).unwrap();
+        assert_eq!(data.name(), Some("battery_level"));
+        assert_eq!(data.units(), "V");
+        let event = crate::fit_file::FitEventMsg::new(&messages[0].fields);
+        assert_eq!(event.battery_level_v(), Some(3.0));
+        assert_eq!(event.rear_gear, None);
+
+        // Garmin products.
+        let file = std::fs::File::open("tests/20210610_track_garmin_fenix_6.fit").unwrap();
+        let reader = crate::fit_file::FitReader::new(std::io::BufReader::new(file));
+        for msg in reader {
+            if let crate::fit_file::FitMessage::Data(data) = msg.unwrap() {
+                assert_eq!(data.global_message_num, crate::fit_file::GLOBAL_MSG_NUM_FILE_ID);
+                assert_eq!(data.fields.iter().find(|field| field.field_def == 2).unwrap().name(), Some("garmin_product"));
+                let file_id = crate::fit_file::FitFileIdMsg::new(&data.fields);
+                assert!(file_id.product.is_some());
+                assert_eq!(file_id.garmin_product(), file_id.product);
+                break;
+            }
+        }
+    }
+
+    #[test]
+    fn developer_fields() {
+        // The Wahoo file describes the battery charge that it adds to device info messages.
+        let file = std::fs::File::open("tests/20191117_bike_wahoo_elemnt.fit").unwrap();
+        let reader = crate::fit_file::FitReader::new(std::io::BufReader::new(file));
+        let mut charges = Vec::new();
+        for msg in reader {
+            if let crate::fit_file::FitMessage::Data(data) = msg.unwrap() {
+                for field in data.fields.iter().filter(|field| field.is_dev_field) {
+                    assert_eq!(data.global_message_num, crate::fit_file::GLOBAL_MSG_NUM_DEVICE_INFO);
+                    assert_eq!(field.name(), Some("charge"));
+                    assert_eq!(field.units(), "%");
+                    if let Some(charge) = field.scaled() {
+                        charges.push(charge);
+                    }
+                }
+            }
+        }
+        assert_eq!(charges, vec![90.0, 89.0, 88.0, 87.0, 86.0, 85.0, 84.0, 83.0]);
+
+        // A scaled developer field, followed by one that was never described.
+        let messages = read_synthetic_messages(&[
+            &[0x40, 0x00, 0x00, 0xCE, 0x00, 0x07, 0x00, 0x01, 0x02, 0x01, 0x01, 0x02, 0x02, 0x01, 0x02, 0x03, 0x06, 0x07, 0x06, 0x01, 0x02, 0x07, 0x01, 0x01, 0x08, 0x02, 0x07],
+            &[0x00, 0x00, 0x05, 0x84, b'P', b'o', b'w', b'e', b'r', 0x00, 0x0A, 0x00, b'W', 0x00],
+            &[0x61, 0x00, 0x00, 0x14, 0x00, 0x01, 0x07, 0x02, 0x84, 0x02, 0x05, 0x02, 0x00, 0x09, 0x03, 0x00],
+            &[0x01, 0xFA, 0x00, 0xC9, 0x09, 0x01, 0x02, 0x03],
+        ]);
+        let record = &messages[1];
+        let described = record.fields.iter().find(|field| field.is_dev_field && field.field_def == 5).unwrap();
+        assert_eq!(described.name(), Some("Power"));
+        assert_eq!(described.units(), "W");
+        assert_eq!(described.scaled(), Some(250.5));
+        let undescribed = record.fields.iter().find(|field| field.is_dev_field && field.field_def == 9).unwrap();
+        assert_eq!(undescribed.name(), None);
+        assert_eq!(undescribed.value, crate::fit_file::FitValue::ByteArray(vec![0x01, 0x02, 0x03].into()));
+        assert_eq!(crate::fit_file::FitRecordMsg::new(&record.fields).power, Some(250));
+
+        // A field description with a scale of zero is treated as having no scale.
+        let messages = read_synthetic_messages(&[
+            &[0x40, 0x00, 0x00, 0xCE, 0x00, 0x07, 0x00, 0x01, 0x02, 0x01, 0x01, 0x02, 0x02, 0x01, 0x02, 0x03, 0x06, 0x07, 0x06, 0x01, 0x02, 0x07, 0x01, 0x01, 0x08, 0x02, 0x07],
+            &[0x00, 0x00, 0x05, 0x84, b'P', b'o', b'w', b'e', b'r', 0x00, 0x00, 0x00, b'W', 0x00],
+            &[0x61, 0x00, 0x00, 0x14, 0x00, 0x01, 0x07, 0x02, 0x84, 0x01, 0x05, 0x02, 0x00],
+            &[0x01, 0xFA, 0x00, 0xC9, 0x09],
+        ]);
+        let described = messages[1].fields.iter().find(|field| field.is_dev_field).unwrap();
+        assert_eq!(described.developer_field.as_ref().unwrap().scale, None);
+        assert_eq!(described.scaled(), Some(2505.0));
+    }
+
+    #[test]
+    fn float_fields() {
+        for is_big_endian in [false, true] {
+            // Records with grit (float32) and a field that isn't in the profile (float64). The second record only has invalid values.
+            let (global_message_num, float32, float64) = if is_big_endian {
+                ([0x00, 0x14], 1.5f32.to_be_bytes(), (-1234.5678f64).to_be_bytes())
+            }
+            else {
+                ([0x14, 0x00], 1.5f32.to_le_bytes(), (-1234.5678f64).to_le_bytes())
+            };
+            let mut definition = vec![0x40, 0x00, is_big_endian as u8];
+            definition.extend_from_slice(&global_message_num);
+            definition.extend_from_slice(&[0x02, 0x72, 0x04, 0x88, 0xC8, 0x08, 0x89]);
+            let mut valid = vec![0x00];
+            valid.extend_from_slice(&float32);
+            valid.extend_from_slice(&float64);
+            let invalid = [0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF];
+
+            let messages = read_synthetic_messages(&[&definition, &valid, &invalid]);
+            let record = crate::fit_file::FitRecordMsg::new(&messages[0].fields);
+            assert_eq!(record.grit, Some(1.5));
+            let field = messages[0].fields.iter().find(|field| field.field_def == 0xC8).unwrap();
+            assert_eq!(field.get_f64(), -1234.5678);
+            assert_eq!(field.scaled(), Some(-1234.5678));
+
+            // The invalid value is a NaN.
+            assert!(messages[1].fields.iter().all(|field| field.is_invalid && field.get_f64().is_nan() && field.scaled().is_none()));
+            assert_eq!(crate::fit_file::FitRecordMsg::new(&messages[1].fields).grit, None);
+        }
+    }
+
+    #[test]
+    fn timestamp_and_message_index_fields() {
+        let file = std::fs::File::open("tests/20210709_pool_swim.fit").unwrap();
+        let reader = crate::fit_file::FitReader::new(std::io::BufReader::new(file));
+        let mut length_indexes = Vec::new();
+        for msg in reader {
+            if let crate::fit_file::FitMessage::Data(data) = msg.unwrap() {
+                if data.global_message_num == crate::fit_file::GLOBAL_MSG_NUM_RECORD {
+                    let record = crate::fit_file::FitRecordMsg::new(&data.fields);
+                    assert_eq!(record.timestamp, data.timestamp.map(|timestamp| timestamp.value()));
+                }
+                else if data.global_message_num == crate::fit_file::GLOBAL_MSG_NUM_LENGTH {
+                    let length = crate::fit_file::FitLengthMsg::new(&data.fields);
+                    assert_eq!(length.message_index, Some(data.message_index.value()));
+                    length_indexes.push(data.message_index.index());
+                }
+            }
+        }
+        assert_eq!(length_indexes, (0..55).collect::<Vec<u16>>());
+
+        // A record with a compressed timestamp header gets a timestamp field too: 1000 rounded down to a multiple of 32, plus 10.
+        let records = read_synthetic_records(&[
+            &[0x40, 0x00, 0x00, 0x14, 0x00, 0x02, 0xFD, 0x04, 0x86, 0x07, 0x02, 0x84],
+            &[0x41, 0x00, 0x00, 0x14, 0x00, 0x01, 0x07, 0x02, 0x84],
+            &[0x00, 0xE8, 0x03, 0x00, 0x00, 0xC8, 0x00],
+            &[0xAA, 0xD2, 0x00],
+        ]);
+        assert_eq!(records[0].timestamp, Some(1000));
+        assert_eq!(records[1].timestamp, Some(1002));
+        assert_eq!(records[1].power, Some(210));
+    }
+
+    #[test]
+    fn relative_timestamps() {
+        // An hr message with a full timestamp, fractional timestamp and event timestamp, then one with two 12 bit event timestamps,
+        // then monitoring messages that only have the low 16 bits of the timestamp.
+        let messages = read_synthetic_messages(&[
+            &[0x40, 0x00, 0x00, 0x84, 0x00, 0x03, 0xFD, 0x04, 0x86, 0x00, 0x02, 0x84, 0x09, 0x04, 0x86],
+            &[0x00, 0xE8, 0x03, 0x00, 0x00, 0x00, 0x40, 0x00, 0x08, 0x00, 0x00],
+            &[0x41, 0x00, 0x00, 0x84, 0x00, 0x01, 0x0A, 0x03, 0x0D],
+            &[0x01, 0x00, 0x0A, 0xC0],
+            &[0x42, 0x00, 0x00, 0x37, 0x00, 0x01, 0x1A, 0x02, 0x84],
+            &[0x02, 0x00, 0x04],
+            &[0x02, 0x10, 0x00],
+        ]);
+        assert_eq!(messages[0].timestamp, Some(crate::fit_file::FitDateTime::System(1000)));
+        assert_eq!(messages[0].event_timestamps, vec![1000.5]);
+        assert_eq!(messages[1].event_timestamps, vec![1001.0, 1001.5]);
+
+        assert_eq!(messages[2].timestamp, Some(crate::fit_file::FitDateTime::System(1024)));
+        assert_eq!(messages[3].timestamp, Some(crate::fit_file::FitDateTime::System(0x10010)));
+        let timestamp = messages[3].fields.iter().find(|field| field.field_def == 253).unwrap();
+        assert_eq!(timestamp.get_u32(), 0x10010);
+        assert!(messages[3].event_timestamps.is_empty());
+
+        // Anchored to a UTC timestamp, the event timestamps are UNIX timestamps.
+        let messages = read_synthetic_messages(&[
+            &[0x40, 0x00, 0x00, 0x84, 0x00, 0x03, 0xFD, 0x04, 0x86, 0x00, 0x02, 0x84, 0x09, 0x04, 0x86],
+            &[0x00, 0x00, 0x00, 0x00, 0x40, 0x00, 0x40, 0x00, 0x08, 0x00, 0x00],
+        ]);
+        assert_eq!(messages[0].timestamp, Some(crate::fit_file::FitDateTime::Utc(0x40000000)));
+        assert_eq!(messages[0].event_timestamps, vec![631065600.0 + 0x40000000 as f64 + 0.5]);
+    }
+
+    /// Collects activity messages.
+    #[derive(Default)]
+    struct ActivityCollector {
+        activities: Vec<(Option<crate::fit_file::FitDateTime>, Option<i32>)>
+    }
+
+    impl crate::fit_file::FitMessageHandler for ActivityCollector {
+        fn on_activity(&mut self, msg: &crate::fit_file::FitDataMessage, activity: &crate::fit_file::FitActivityMsg) {
+            self.activities.push((msg.timestamp, activity.timezone_offset_s()));
+        }
+    }
+
+    #[test]
+    fn date_times() {
+        let file = std::fs::File::open("tests/20191117_bike_wahoo_elemnt.fit").unwrap();
+        let mut reader = std::io::BufReader::new(file);
+        let mut collector = ActivityCollector::default();
+        crate::fit_file::Fit::new().read(&mut reader, &mut collector).unwrap();
+        let timestamp = crate::fit_file::FitDateTime::Utc(942934363);
+        assert_eq!(collector.activities, vec![(Some(timestamp), Some(-5 * 3600))]);
+        assert_eq!(timestamp.unix_timestamp(), Some(1573999963));
+        assert_eq!(timestamp.to_system_time(), Some(std::time::UNIX_EPOCH + std::time::Duration::from_secs(1573999963)));
+
+        // Zwift doesn't record the local time.
+        let file = std::fs::File::open("tests/20210218_zwift.fit").unwrap();
+        let mut reader = std::io::BufReader::new(file);
+        let mut collector = ActivityCollector::default();
+        crate::fit_file::Fit::new().read(&mut reader, &mut collector).unwrap();
+        assert_eq!(collector.activities, vec![(Some(crate::fit_file::FitDateTime::Utc(982601531)), None)]);
+
+        let system_time = crate::fit_file::FitDateTime::from_fit(3600);
+        assert_eq!(system_time, crate::fit_file::FitDateTime::System(3600));
+        assert!(!system_time.is_utc());
+        assert_eq!(system_time.unix_timestamp(), None);
+        assert_eq!(system_time.to_system_time(), None);
+        assert_eq!(timestamp.local_offset_s(3600), None);
+        assert_eq!(timestamp.local_offset_s(942934363 + 5400), Some(5400));
+
+        // The latest FIT times are past the end of a u32 once converted to UNIX time.
+        assert_eq!(crate::fit_file::FitDateTime::Utc(0xFFFFFFFF).unix_timestamp(), Some(0xFFFFFFFF + 631065600));
+
+        // A record with an invalid timestamp keeps the timestamp of the record before it.
+        let messages = read_synthetic_messages(&[
+            &[0x40, 0x00, 0x00, 0x14, 0x00, 0x01, 0xFD, 0x04, 0x86],
+            &[0x00, 0x5B, 0x09, 0x34, 0x38],
+            &[0x00, 0xFF, 0xFF, 0xFF, 0xFF],
+        ]);
+        assert_eq!(messages[0].timestamp, Some(timestamp));
+        assert_eq!(messages[1].timestamp, Some(timestamp));
+        assert!(messages[1].fields[0].is_invalid);
+    }
+
+    #[test]
+    fn message_index_selected_flag() {
+        // Two workout steps, the second is selected and repeats the first.
+        let messages = read_synthetic_messages(&[
+            &[0x40, 0x00, 0x00, 0x1B, 0x00, 0x03, 0xFE, 0x02, 0x84, 0x01, 0x01, 0x00, 0x02, 0x04, 0x86],
+            &[0x00, 0x00, 0x00, 0x00, 0x0A, 0x00, 0x00, 0x00],
+            &[0x00, 0x01, 0x80, 0x06, 0x00, 0x00, 0x00, 0x00],
+        ]);
+        assert!(!messages[0].message_index.is_selected());
+        assert!(messages[1].message_index.is_selected());
+        assert_eq!(messages[1].message_index.value(), 0x8001);
+        assert_eq!(messages[1].message_index.index(), 1);
+
+        let steps: Vec<crate::fit_file::FitWorkoutStepMsg> = messages.iter().map(|msg| crate::fit_file::FitWorkoutStepMsg::new(msg.message_index, &msg.fields)).collect();
+        assert_eq!(steps[1].message_index, 1);
+        assert_eq!(steps[1].duration_step(), Some(steps[0].message_index as u32));
+    }
+
+    /// Null pads a string to the size of its field.
+    fn padded_string(string: &str, size: usize) -> Vec<u8> {
+        let mut bytes = string.as_bytes().to_vec();
+        bytes.resize(size, 0);
+        bytes
+    }
+
+    #[test]
+    fn utf8_strings() {
+        // A workout whose name and step are in German and Japanese. The workout also has a field that isn't in the profile,
+        // holding two strings, and another holding a byte that isn't valid UTF-8.
+        let mut workout = vec![0x00];
+        workout.extend(padded_string("Intervalle für Läufer", 32));
+        workout.extend(padded_string("abc\0def", 10));
+        workout.extend_from_slice(&[b'a', 0xFF, b'b', 0x00]);
+        let mut step = vec![0x01, 0x00, 0x00];
+        step.extend(padded_string("ペース走", 16));
+        step.extend(padded_string("Schön locker", 32));
+        let messages = read_synthetic_messages(&[
+            &[0x40, 0x00, 0x00, 0x1A, 0x00, 0x03, 0x08, 0x20, 0x07, 0xC8, 0x0A, 0x07, 0xC9, 0x04, 0x07],
+            &workout,
+            &[0x41, 0x00, 0x00, 0x1B, 0x00, 0x03, 0xFE, 0x02, 0x84, 0x00, 0x10, 0x07, 0x08, 0x20, 0x07],
+            &step,
+        ]);
+
+        let workout = crate::fit_file::FitWorkoutMsg::new(&messages[0].fields);
+        assert_eq!(workout.workout_name, Some("Intervalle für Läufer".to_string()));
+        let strings = messages[0].fields.iter().find(|field| field.field_def == 0xC8).unwrap();
+        assert!(strings.is_array());
+        assert_eq!(strings.get_string(), "abc");
+        assert_eq!(strings.get_string_array(), vec!["abc".to_string(), "def".to_string()]);
+        let lossy = messages[0].fields.iter().find(|field| field.field_def == 0xC9).unwrap();
+        assert_eq!(lossy.value, crate::fit_file::FitValue::Str("a\u{FFFD}b".into()));
+        assert!(!lossy.is_array());
+
+        let step = crate::fit_file::FitWorkoutStepMsg::new(messages[1].message_index, &messages[1].fields);
+        assert_eq!(step.step_name, Some("ペース走".to_string()));
+        assert_eq!(step.notes, Some("Schön locker".to_string()));
+    }
+
+    /// Collects memo_glob parts.
+    #[derive(Default)]
+    struct MemoCollector {
+        memos: crate::fit_file::FitMemoGlobAssembler,
+        num_parts: usize
+    }
+
+    impl crate::fit_file::FitMessageHandler for MemoCollector {
+        fn on_memo_glob(&mut self, _msg: &crate::fit_file::FitDataMessage, memo_glob: &crate::fit_file::FitMemoGlobMsg) {
+            self.num_parts += 1;
+            self.memos.add(memo_glob);
+        }
+    }
+
+    #[test]
+    fn part_index() {
+        // A memo for the first session, split into three parts that arrive out of order. The last one is padded.
+        let data = synthetic_fit(&[
+            &[0x40, 0x00, 0x00, 0x91, 0x00, 0x04, 0xFA, 0x04, 0x86, 0x00, 0x07, 0x0D, 0x01, 0x02, 0x84, 0x02, 0x02, 0x84],
+            &[0x00, 0x01, 0x00, 0x00, 0x00, b' ', b'a', b' ', b'l', b'o', b'n', b'g', 0x12, 0x00, 0x00, 0x00],
+            &[0x00, 0x00, 0x00, 0x00, 0x00, b'T', b'h', b'i', b's', b' ', b'i', b's', 0x12, 0x00, 0x00, 0x00],
+            &[0x00, 0x02, 0x00, 0x00, 0x00, b' ', b'm', b'e', b'm', b'o', 0x00, 0x00, 0x12, 0x00, 0x00, 0x00],
+            // A part that doesn't say which message it belongs to, so it is ignored.
+            &[0x41, 0x00, 0x00, 0x91, 0x00, 0x02, 0xFA, 0x04, 0x86, 0x00, 0x07, 0x0D],
+            &[0x01, 0x00, 0x00, 0x00, 0x00, b'O', b'r', b'p', b'h', b'a', b'n', 0x00],
+        ]);
+        let mut reader = std::io::BufReader::new(std::io::Cursor::new(data));
+        let mut collector = MemoCollector::default();
+        let mut fit = crate::fit_file::Fit::new();
+        fit.read(&mut reader, &mut collector).unwrap();
+
+        assert_eq!(collector.num_parts, 4);
+        assert_eq!(collector.memos.keys(), vec![(crate::fit_file::GLOBAL_MSG_NUM_SESSION, 0)]);
+        assert_eq!(collector.memos.memo_string(crate::fit_file::GLOBAL_MSG_NUM_SESSION, 0), Some("This is a long memo".to_string()));
+        assert_eq!(collector.memos.memo(crate::fit_file::GLOBAL_MSG_NUM_SESSION, 0).unwrap().len(), 21);
+        assert_eq!(collector.memos.memo(crate::fit_file::GLOBAL_MSG_NUM_SESSION, 1), None);
+    }
+
+    /// Wraps the given definition and data messages in a FIT file.
+    fn synthetic_fit(messages: &[&[u8]]) -> Vec<u8> {
+        let data_size: usize = messages.iter().map(|msg| msg.len()).sum();
+        let mut data = vec![12, 0x10, 0x00, 0x00];
+        data.extend_from_slice(&(data_size as u32).to_le_bytes());
+        data.extend_from_slice(b".FIT");
+        for msg in messages {
+            data.extend_from_slice(msg);
+        }
+        data.extend_from_slice(&[0x00, 0x00]); // CRC isn't checked unless strict_crc is set
+        data
+    }
+
+    /// Wraps the given definition and data messages in a FIT file, then returns the data messages read from it.
+    fn read_synthetic_messages(messages: &[&[u8]]) -> Vec<crate::fit_file::FitDataMessage<'static>> {
+        let data = synthetic_fit(messages);
+        let reader = crate::fit_file::FitReader::new(std::io::BufReader::new(std::io::Cursor::new(data)));
+        let mut data_messages = Vec::new();
+        for msg in reader {
+            if let crate::fit_file::FitMessage::Data(data) = msg.unwrap() {
+                data_messages.push(data);
+            }
+        }
+        data_messages
+    }
+
+    /// Same as read_synthetic_messages(), for files that only contain records.
+    fn read_synthetic_records(messages: &[&[u8]]) -> Vec<crate::fit_file::FitRecordMsg> {
+        read_synthetic_messages(messages).into_iter().map(|msg| crate::fit_file::FitRecordMsg::new(&msg.fields)).collect()
+    }
+
+    #[test]
+    fn local_message_types() {
+        // Local message types 0 and 15 are both records, with different fields, then local message type 0 is redefined.
+        let messages = read_synthetic_messages(&[
+            &[0x40, 0x00, 0x00, 20, 0, 1, 7, 2, 0x84],
+            &[0x4F, 0x00, 0x01, 0, 20, 1, 3, 1, 0x02],
+            &[0x00, 0xFA, 0x00],
+            &[0x0F, 150],
+            &[0x40, 0x00, 0x00, 20, 0, 1, 4, 1, 0x02],
+            &[0x00, 90],
+        ]);
+
+        assert_eq!(messages.len(), 3);
+        assert_eq!(messages[0].fields[0].value, crate::fit_file::FitValue::UInt(250));
+        assert_eq!(messages[1].fields[0].value, crate::fit_file::FitValue::UInt(150));
+        assert_eq!(messages[2].fields[0].field_def, 4);
+        assert_eq!(messages[2].fields[0].value, crate::fit_file::FitValue::UInt(90));
+        assert_eq!(messages[2].fields[0].name(), Some("cadence"));
+    }
+
+    /// Counts the record messages in each segment of a chained FIT file.
+    fn segment_callback(_timestamp: Option<crate::fit_file::FitDateTime>, global_message_num: u16, _local_msg_type: u8, _message_index: crate::fit_file::MessageIndex, segment: usize, _fields: Vec<crate::fit_file::FitFieldValue>, data: &mut Vec<u16>) {
+        if global_message_num == crate::fit_file::GLOBAL_MSG_NUM_RECORD {
+            if data.len() <= segment {
+                data.resize(segment + 1, 0);
+            }
+            data[segment] += 1;
+        }
+    }
+
+    #[test]
+    fn chained_files() {
+        let mut bytes = std::fs::read("tests/20200529_short_ocean_swim.fit").unwrap();
+        bytes.append(&mut std::fs::read("tests/20210218_zwift.fit").unwrap());
+
+        let mut reader = std::io::BufReader::new(std::io::Cursor::new(bytes));
+        let mut records_per_segment = Vec::new();
+        let fit = crate::fit_file::read(&mut reader, segment_callback, &mut records_per_segment).unwrap();
+
+        assert_eq!(fit.segment_headers.len(), 2);
+        assert_eq!(fit.segment_headers[0].header_len, 14);
+        assert_eq!(fit.segment_headers[1].header_len, 12);
+        assert_eq!(fit.header.header_len, 14);
+        assert!(fit.header_crc_valid);
+        assert!(fit.crc_valid);
+        assert_eq!(records_per_segment, vec![179, 1163]);
+        assert_eq!(fit.trailing_bytes, 0);
+    }
+
+    #[test]
+    fn trailing_bytes() {
+        // Padding after the CRC isn't another FIT file, so it is counted and ignored rather than read as a header.
+        let mut bytes = std::fs::read("tests/20210218_zwift.fit").unwrap();
+        let mut num_expected = 0;
+        crate::fit_file::Fit::new().read_slice(&bytes, &mut |_msg: &crate::fit_file::FitMessage| num_expected += 1).unwrap();
+        for padding in [1, 11, 12, 100] {
+            let mut padded = bytes.clone();
+            padded.resize(bytes.len() + padding, 0);
+
+            let mut num_messages = 0;
+            let mut reader = std::io::BufReader::new(std::io::Cursor::new(padded.clone()));
+            let mut fit = crate::fit_file::Fit::new();
+            fit.read(&mut reader, &mut |_msg: &crate::fit_file::FitMessage| num_messages += 1).unwrap();
+            assert_eq!(num_messages, num_expected);
+            assert_eq!(fit.trailing_bytes, padding as u64);
+            assert_eq!(fit.segment_headers.len(), 1);
+            assert!(fit.crc_valid);
+
+            let mut fit = crate::fit_file::Fit::new();
+            fit.read_slice(&padded, &mut |_msg: &crate::fit_file::FitMessage| {}).unwrap();
+            assert_eq!(fit.trailing_bytes, padding as u64);
+            assert_eq!(fit.segment_headers.len(), 1);
+
+            let mut decoder = crate::fit_file::FitStreamDecoder::new();
+            for chunk in padded.chunks(5) {
+                decoder.feed(chunk, &mut |_msg: &crate::fit_file::FitMessage| {}).unwrap();
+            }
+            decoder.finish().unwrap();
+            assert_eq!(decoder.trailing_bytes, padding as u64);
+        }
+
+        // Padding between two chained files hides the second one.
+        let file_len = bytes.len();
+        bytes.resize(file_len + 4, 0);
+        bytes.append(&mut std::fs::read("tests/20200529_short_ocean_swim.fit").unwrap());
+        let mut fit = crate::fit_file::Fit::new();
+        fit.read_slice(&bytes, &mut |_msg: &crate::fit_file::FitMessage| {}).unwrap();
+        assert_eq!(fit.segment_headers.len(), 1);
+        assert_eq!(fit.trailing_bytes, (bytes.len() - file_len) as u64);
+    }
+
+    #[test]
+    fn read_slice() {
+        // Decoding from a slice gives the same messages as decoding from a reader.
+        for entry in std::fs::read_dir("tests").unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension().map_or(true, |extension| extension != "fit") {
+                continue;
+            }
+            let bytes = std::fs::read(&path).unwrap();
+
+            let mut from_reader = Vec::new();
+            let mut reader = std::io::BufReader::new(std::io::Cursor::new(bytes.clone()));
+            let mut fit = crate::fit_file::Fit::new();
+            fit.read(&mut reader, &mut |msg: &crate::fit_file::FitMessage| from_reader.push(format!("{:?}", msg))).unwrap();
+
+            let mut from_slice = Vec::new();
+            let mut slice_fit = crate::fit_file::Fit::new();
+            slice_fit.read_slice(&bytes, &mut |msg: &crate::fit_file::FitMessage| from_slice.push(format!("{:?}", msg))).unwrap();
+
+            assert!(!from_slice.is_empty());
+            assert_eq!(from_slice, from_reader, "{:?}", path);
+            assert_eq!(slice_fit.segment_headers.len(), fit.segment_headers.len());
+            assert_eq!(slice_fit.crc_valid, fit.crc_valid);
+            assert_eq!(slice_fit.header_crc_valid, fit.header_crc_valid);
+        }
+
+        // Truncated data is still an error, after the messages that could be read.
+        let bytes = std::fs::read("tests/20210218_zwift.fit").unwrap();
+        let mut num_messages = 0;
+        let result = crate::fit_file::Fit::new().read_slice(&bytes[..1000], &mut |_msg: &crate::fit_file::FitMessage| num_messages += 1);
+        assert!(matches!(result, Err(crate::fit_file::FitError::TruncatedData { .. })));
+        assert!(num_messages > 0);
+    }
+
+    /// Copies each message passed to it by read_slice_ref(), and counts the strings that were borrowed from the data.
+    struct RefCollector<'a> {
+        data: &'a [u8],
+        messages: Vec<String>,
+        num_borrowed_strings: usize
+    }
+
+    impl<'a> crate::fit_file::FitMessageRefHandler<'a> for RefCollector<'a> {
+        fn on_definition(&mut self, msg: &crate::fit_file::FitDefinitionMessage) {
+            self.messages.push(format!("{:?}", crate::fit_file::FitMessage::Definition(msg.clone())));
+        }
+
+        fn on_data(&mut self, msg: &crate::fit_file::FitDataMessage<'a>) {
+            self.messages.push(format!("{:?}", crate::fit_file::FitMessage::Data(msg.clone().into_owned())));
+            for field in msg.fields.iter() {
+                if let crate::fit_file::FitValue::Str(std::borrow::Cow::Borrowed(string)) = field.value {
+                    assert!(self.data.as_ptr_range().contains(&string.as_ptr()));
+                    self.num_borrowed_strings += 1;
+                }
+            }
+        }
+    }
+
+    /// Returns the names of the steps of a workout file. They borrow from the data, so outlive the handler.
+    fn workout_step_names<'a>(bytes: &'a [u8]) -> Vec<std::borrow::Cow<'a, str>> {
+        let mut names = Vec::new();
+        crate::fit_file::Fit::new().read_slice_ref(bytes, &mut |msg: &crate::fit_file::FitDataMessage<'a>| {
+            if msg.global_message_num == crate::fit_file::GLOBAL_MSG_NUM_WORKOUT_STEP {
+                for field in msg.fields.iter().filter(|field| field.field_def == 0 && !field.is_invalid) {
+                    if let crate::fit_file::FitValue::Str(name) = &field.value {
+                        names.push(name.clone());
+                    }
+                }
+            }
+        }).unwrap();
+        names
+    }
+
+    #[test]
+    fn read_slice_ref() {
+        // The borrowed messages hold the same values as the messages that read_slice() copies.
+        let mut num_borrowed_strings = 0;
+        for entry in std::fs::read_dir("tests").unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension().map_or(true, |extension| extension != "fit") {
+                continue;
+            }
+            let bytes = std::fs::read(&path).unwrap();
+
+            let mut from_slice = Vec::new();
+            let mut fit = crate::fit_file::Fit::new();
+            fit.read_slice(&bytes, &mut |msg: &crate::fit_file::FitMessage| from_slice.push(format!("{:?}", msg))).unwrap();
+
+            let mut collector = RefCollector { data: &bytes, messages: Vec::new(), num_borrowed_strings: 0 };
+            let mut ref_fit = crate::fit_file::Fit::new();
+            ref_fit.read_slice_ref(&bytes, &mut collector).unwrap();
+
+            assert_eq!(collector.messages, from_slice, "{:?}", path);
+            assert_eq!(ref_fit.crc_valid, fit.crc_valid);
+            num_borrowed_strings += collector.num_borrowed_strings;
+        }
+        assert!(num_borrowed_strings > 0);
+
+        // Values can be kept for as long as the data.
+        let bytes = std::fs::read("tests/trainingpeaks_export.fit").unwrap();
+        let names = workout_step_names(&bytes);
+        assert_eq!(names, ["Warm up", "Hard", "Easy", "Cool Down"]);
+        assert!(names.iter().all(|name| matches!(name, std::borrow::Cow::Borrowed(_))));
+    }
+
+    #[test]
+    fn stream_decoder() {
+        // Feeding the data in chunks gives the same messages as decoding it all at once, whatever the chunk size.
+        for entry in std::fs::read_dir("tests").unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension().map_or(true, |extension| extension != "fit") {
+                continue;
+            }
+            let bytes = std::fs::read(&path).unwrap();
+
+            let mut from_slice = Vec::new();
+            let mut fit = crate::fit_file::Fit::new();
+            fit.read_slice(&bytes, &mut |msg: &crate::fit_file::FitMessage| from_slice.push(format!("{:?}", msg))).unwrap();
+
+            for chunk_size in [1, 7, 64, 4096] {
+                let mut decoder = crate::fit_file::FitStreamDecoder::new();
+                let mut from_stream = Vec::new();
+                let mut num_fed = 0;
+                for chunk in bytes.chunks(chunk_size) {
+                    let num_messages = from_stream.len();
+                    decoder.feed(chunk, &mut |msg: &crate::fit_file::FitMessage| from_stream.push(format!("{:?}", msg))).unwrap();
+                    num_fed += chunk.len() as u64;
+
+                    // Messages are passed on as soon as the last byte of the record arrives.
+                    if chunk_size == 1 && from_stream.len() > num_messages {
+                        assert_eq!(decoder.bytes_decoded(), num_fed);
+                    }
+                    assert!(decoder.bytes_decoded() <= num_fed);
+                }
+                decoder.finish().unwrap();
+
+                assert_eq!(from_stream, from_slice, "{:?} in chunks of {}", path, chunk_size);
+                assert_eq!(decoder.bytes_decoded(), bytes.len() as u64);
+                assert_eq!(decoder.expected_len(), Some(bytes.len() as u64));
+                assert_eq!(decoder.segment_headers.len(), fit.segment_headers.len());
+                assert_eq!(decoder.crc_valid, fit.crc_valid);
+            }
+        }
+
+        // Progress can be reported part way through, and stopping part way through a record is an error.
+        let bytes = std::fs::read("tests/20210218_zwift.fit").unwrap();
+        let mut decoder = crate::fit_file::FitStreamDecoder::new();
+        let mut num_messages = 0;
+        decoder.feed(&bytes[..1000], &mut |_msg: &crate::fit_file::FitMessage| num_messages += 1).unwrap();
+        assert!(num_messages > 0);
+        assert!(decoder.bytes_decoded() > 900 && decoder.bytes_decoded() <= 1000);
+        assert_eq!(decoder.expected_len(), Some(bytes.len() as u64));
+        assert!(matches!(decoder.finish(), Err(crate::fit_file::FitError::TruncatedData { .. })));
+
+        // The CRC is only valid once it has been received.
+        let mut decoder = crate::fit_file::FitStreamDecoder::new();
+        assert!(!decoder.header_crc_valid && !decoder.crc_valid);
+        decoder.feed(&bytes[..bytes.len() / 2], &mut |_msg: &crate::fit_file::FitMessage| {}).unwrap();
+        assert!(decoder.header_crc_valid);
+        assert!(!decoder.crc_valid);
+        decoder.feed(&bytes[bytes.len() / 2..], &mut |_msg: &crate::fit_file::FitMessage| {}).unwrap();
+        assert!(decoder.crc_valid);
+    }
+
+    /// An in-memory async reader that returns a few bytes at a time, and is only ready every other time it is polled.
+    #[cfg(feature = "async")]
+    struct SlowAsyncReader {
+        bytes: Vec<u8>,
+        offset: usize,
+        chunk_size: usize,
+        is_ready: bool,
+    }
+
+    #[cfg(feature = "async")]
+    impl tokio::io::AsyncRead for SlowAsyncReader {
+        fn poll_read(mut self: std::pin::Pin<&mut Self>, cx: &mut std::task::Context<'_>, buf: &mut tokio::io::ReadBuf<'_>) -> std::task::Poll<std::io::Result<()>> {
+            self.is_ready = !self.is_ready;
+            if !self.is_ready {
+                cx.waker().wake_by_ref();
+                return std::task::Poll::Pending;
+            }
+            let end = self.bytes.len().min(self.offset + self.chunk_size.min(buf.remaining()));
+            buf.put_slice(&self.bytes[self.offset..end]);
+            self.offset = end;
+            std::task::Poll::Ready(Ok(()))
+        }
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn read_async() {
+        use futures_core::Stream;
+
+        // Reading asynchronously gives the same messages as decoding it all at once.
+        for entry in std::fs::read_dir("tests").unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension().map_or(true, |extension| extension != "fit") {
+                continue;
+            }
+            let bytes = std::fs::read(&path).unwrap();
+
+            let mut from_slice = Vec::new();
+            let mut fit = crate::fit_file::Fit::new();
+            fit.read_slice(&bytes, &mut |msg: &crate::fit_file::FitMessage| from_slice.push(format!("{:?}", msg))).unwrap();
+
+            let mut from_async = Vec::new();
+            let mut async_fit = crate::fit_file::Fit::new();
+            async_fit.read_async(&mut &bytes[..], &mut |msg: &crate::fit_file::FitMessage| from_async.push(format!("{:?}", msg))).await.unwrap();
+            assert_eq!(from_async, from_slice, "{:?}", path);
+            assert_eq!(async_fit.segment_headers.len(), fit.segment_headers.len());
+            assert_eq!(async_fit.crc_valid, fit.crc_valid);
+            assert_eq!(async_fit.header_crc_valid, fit.header_crc_valid);
+
+            let mut stream = crate::fit_file::FitAsyncReader::new(SlowAsyncReader{ bytes: bytes.clone(), offset: 0, chunk_size: 13, is_ready: false });
+            let mut from_stream = Vec::new();
+            while let Some(msg) = std::future::poll_fn(|cx| std::pin::Pin::new(&mut stream).poll_next(cx)).await {
+                from_stream.push(format!("{:?}", msg.unwrap()));
+            }
+            assert_eq!(from_stream, from_slice, "{:?}", path);
+            assert_eq!(stream.decoder.bytes_decoded(), bytes.len() as u64);
+            assert_eq!(stream.decoder.crc_valid, fit.crc_valid);
+        }
+
+        // Truncated data is an error from both, after the messages that could be read.
+        let bytes = std::fs::read("tests/20210218_zwift.fit").unwrap();
+        let mut num_messages = 0;
+        let result = crate::fit_file::Fit::new().read_async(&mut &bytes[..1000], &mut |_msg: &crate::fit_file::FitMessage| num_messages += 1).await;
+        assert!(matches!(result, Err(crate::fit_file::FitError::TruncatedData { .. })));
+        assert!(num_messages > 0);
+
+        let mut stream = crate::fit_file::FitAsyncReader::new(&bytes[..1000]);
+        let mut results = Vec::new();
+        while let Some(msg) = std::future::poll_fn(|cx| std::pin::Pin::new(&mut stream).poll_next(cx)).await {
+            results.push(msg);
+        }
+        assert!(results.len() > 1);
+        assert!(matches!(results.last(), Some(Err(crate::fit_file::FitError::TruncatedData { .. }))));
+        assert!(results[..results.len() - 1].iter().all(|msg| msg.is_ok()));
+    }
+
+    /// Reads a FIT file in lenient mode, returning the power of each record.
+    fn read_lenient_powers(bytes: Vec<u8>) -> (crate::fit_file::Fit, Vec<Option<u16>>) {
+        let mut reader = std::io::BufReader::new(std::io::Cursor::new(bytes));
+        let mut fit = crate::fit_file::Fit::new();
+        let mut powers = Vec::new();
+        fit.lenient = true;
+        fit.read(&mut reader, &mut |msg: &crate::fit_file::FitMessage| {
+            if let crate::fit_file::FitMessage::Data(data) = msg {
+                powers.push(crate::fit_file::FitRecordMsg::new(&data.fields).power);
+            }
+        }).unwrap();
+        (fit, powers)
+    }
+
+    #[test]
+    fn lenient_mode() {
+        let definition: &[u8] = &[0x40, 0x00, 0x00, 0x14, 0x00, 0x02, 0xFD, 0x04, 0x86, 0x07, 0x02, 0x84];
+
+        // A file that was never closed: the data size is zero, there's no CRC, and the last record was cut short.
+        let mut bytes = synthetic_fit(&[definition, &[0x00, 0xE8, 0x03, 0x00, 0x00, 0x64, 0x00], &[0x00, 0xE9, 0x03, 0x00, 0x00, 0x6E, 0x00], &[0x00, 0xEA, 0x03]]);
+        bytes[4..8].copy_from_slice(&[0, 0, 0, 0]);
+        bytes.truncate(bytes.len() - 2);
+        let mut reader = std::io::BufReader::new(std::io::Cursor::new(bytes.clone()));
+        let mut fit = crate::fit_file::Fit::new();
+        let mut num_messages = 0;
+        fit.read(&mut reader, &mut |_msg: &crate::fit_file::FitMessage| num_messages += 1).unwrap();
+        assert_eq!(num_messages, 0);
+        assert!(!fit.crc_valid);
+        assert_eq!(fit.trailing_bytes, bytes.len() as u64 - 14); // All but the 12 byte header and the two bytes taken for the CRC
+        let (fit, powers) = read_lenient_powers(bytes);
+        assert_eq!(powers, vec![Some(100), Some(110)]);
+        assert_eq!(fit.skipped_ranges.len(), 1);
+        assert_eq!(fit.skipped_ranges[0].range, 38..41);
+        assert!(matches!(fit.skipped_ranges[0].error, crate::fit_file::FitError::TruncatedData { .. }));
+        assert!(!fit.crc_valid);
+
+        // Garbage in the middle of the data is skipped up to the next record.
+        let bytes = synthetic_fit(&[definition, &[0x00, 0xE8, 0x03, 0x00, 0x00, 0x64, 0x00], &[0x0F, 0xFF, 0xFF], &[0x00, 0xE9, 0x03, 0x00, 0x00, 0x6E, 0x00], &[0x00, 0xEA, 0x03, 0x00, 0x00, 0x78, 0x00]]);
+        let (fit, powers) = read_lenient_powers(bytes);
+        assert_eq!(powers, vec![Some(100), Some(110), Some(120)]);
+        assert_eq!(fit.skipped_ranges.len(), 1);
+        assert_eq!(fit.skipped_ranges[0].range, 31..34);
+        assert!(matches!(fit.skipped_ranges[0].error, crate::fit_file::FitError::MissingDefinition { local_msg_type: 15, .. }));
+
+        // Files that are fine read the same as they do normally, including chained files.
+        let mut bytes = std::fs::read("tests/20200529_short_ocean_swim.fit").unwrap();
+        bytes.append(&mut std::fs::read("tests/20210218_zwift.fit").unwrap());
+        let mut reader = std::io::BufReader::new(std::io::Cursor::new(bytes));
+        let mut records_per_segment = Vec::new();
+        let mut fit = crate::fit_file::Fit::new();
+        fit.lenient = true;
+        fit.read(&mut reader, &mut |msg: &crate::fit_file::FitMessage| {
+            if let crate::fit_file::FitMessage::Data(data) = msg {
+                segment_callback(data.timestamp, data.global_message_num, data.local_message_type, data.message_index, data.segment, Vec::new(), &mut records_per_segment);
+            }
+        }).unwrap();
+        assert_eq!(records_per_segment, vec![179, 1163]);
+        assert!(fit.skipped_ranges.is_empty());
+        assert!(fit.header_crc_valid);
+        assert!(fit.crc_valid);
+
+        // Lenient mode doesn't override strict_crc, a CRC mismatch is still an error.
+        let mut bytes = std::fs::read("tests/20210709_pool_swim.fit").unwrap();
+        let last_byte = bytes.len() - 1;
+        bytes[last_byte] ^= 0xff;
+        let mut fit = crate::fit_file::Fit::new();
+        fit.lenient = true;
+        fit.read_slice(&bytes, &mut |_msg: &crate::fit_file::FitMessage| {}).unwrap();
+        assert!(!fit.crc_valid);
+        fit.strict_crc = true;
+        let result = fit.read_slice(&bytes, &mut |_msg: &crate::fit_file::FitMessage| {});
+        assert!(matches!(result, Err(crate::fit_file::FitError::CrcMismatch { offset, .. }) if offset == last_byte as u64 - 1));
+    }
+
+    fn convert_to_camel_case(name: &str) -> String {
         let mut new_name = String::new();
         let mut need_upper_case = true;
 
@@ -291,131 +1374,354 @@ mod activity_tests {
         new_name
     }
 
-    fn print_message_struct(name: String, field_map: &HashMap::<String, (u8, String)>) {
+    fn print_message_struct(name: String, field_map: &HashMap::<String, (u8, String, bool)>) {
         let mut struct_name: String = "Fit".to_string();
         struct_name.push_str(&convert_to_camel_case(&name));
         struct_name.push_str("Msg");
 
         println!("pub struct {} {{", struct_name);
-        for (field_name, (_field_id, field_type)) in field_map {
-            println!("    pub {}: Option<{}>,", field_name, *field_type);
+        for (field_name, (_field_id, field_type, is_array)) in field_map {
+            if *is_array {
+                println!("    pub {}: Option<Vec<{}>>,", field_name, *field_type);
+            }
+            else {
+                println!("    pub {}: Option<{}>,", field_name, *field_type);
+            }
         }
         println!("}}");
-        println!("");
+        println!();
         println!("impl {} {{", struct_name);
-        println!("");
+        println!();
         println!("    /// Constructor: Takes the fields that were read by the file parser and puts them into a structure.");
-        println!("    pub fn new(fields: Vec<FitFieldValue>) -> Self {{");
+        println!("    pub fn new(fields: &[FitFieldValue]) -> Self {{");
         print!("        let mut msg = {} {{ ", struct_name);
-        let mut split_count = 0;
-        for (field_name, _field_details) in field_map {
+        for (split_count, field_name) in field_map.keys().enumerate() {
             print!("{}: None, ", field_name);
             if split_count % 3 == 0 {
-                println!("");
+                println!();
                 print!("            ");
             }
-            split_count = split_count + 1;
         }
-        println!("");
+        println!();
         println!("        }};");
-        println!("");
+        println!();
         println!("        for field in fields {{");
-        println!("            if !field.is_dev_field {{");
+        println!("            if !field.is_dev_field && !field.is_invalid {{");
         println!("                match field.field_def {{");
-        for (field_name, (field_id, field_type)) in field_map.iter() {
-            println!("                    {} => {{ msg.{} = Some(field.get_{}()); }},", field_id, field_name, *field_type);
+        for (field_name, (field_id, field_type, is_array)) in field_map.iter() {
+            if *is_array {
+                println!("                    {} => {{ msg.{} = Some(field.get_{}_array()); }},", field_id, field_name, *field_type);
+            }
+            else {
+                println!("                    {} => {{ msg.{} = Some(field.get_{}()); }},", field_id, field_name, *field_type);
+            }
         }
-        println!("");
+        println!();
         println!("                }}");
         println!("            }}");
         println!("        }}");
         println!("        msg");
         println!("    }}");
         println!("}}");
-        println!("");
+        println!();
+    }
+
+    /// Reads tests/Types-Table.csv and returns each type's base type and values, keyed by type name.
+    fn read_types_table() -> HashMap<String, (String, HashMap<String, u64>)> {
+        let mut reader = csv::Reader::from_path("tests/Types-Table.csv").unwrap();
+        let mut types = HashMap::<String, (String, HashMap<String, u64>)>::new();
+        let mut current_type_name = String::new();
+
+        for record in reader.records() {
+            let record = record.unwrap();
+
+            if !record[0].is_empty() {
+                current_type_name = record[0].to_string();
+                types.insert(current_type_name.clone(), (record[1].to_string(), HashMap::new()));
+            }
+            else if !record[2].is_empty() {
+                let value_str = record[3].trim();
+                let value = match value_str.strip_prefix("0x") {
+                    Some(hex) => u64::from_str_radix(hex, 16).unwrap(),
+                    None => value_str.parse::<u64>().unwrap(),
+                };
+                types.get_mut(&current_type_name).unwrap().1.insert(record[2].to_string(), value);
+            }
+        }
+        types
+    }
+
+    /// Splits a multi-line, comma separated cell from the messages table into its values.
+    fn split_cell(cell: &str) -> Vec<String> {
+        if cell.trim().is_empty() {
+            return Vec::new();
+        }
+        cell.split(',').map(|value| value.trim().to_string()).collect()
+    }
+
+    /// Returns the i-th value of a cell with one value per component. A single value applies to every component.
+    fn component_value(values: &[String], i: usize, default: &str) -> String {
+        match values.len() {
+            0 => default.to_string(),
+            1 => values[0].clone(),
+            _ => values.get(i).cloned().unwrap_or_else(|| default.to_string()),
+        }
+    }
+
+    /// Converts the name of a base type to its number.
+    fn base_type_num(base_type_name: &str) -> u8 {
+        match base_type_name {
+            "enum" | "bool" => 0x00,
+            "sint8" => 0x01,
+            "uint8" => 0x02,
+            "sint16" => 0x83,
+            "uint16" => 0x84,
+            "sint32" => 0x85,
+            "uint32" => 0x86,
+            "string" => 0x07,
+            "float32" => 0x88,
+            "float64" => 0x89,
+            "uint8z" => 0x0A,
+            "uint16z" => 0x8B,
+            "uint32z" => 0x8C,
+            "byte" => 0x0D,
+            "sint64" => 0x8E,
+            "uint64" => 0x8F,
+            "uint64z" => 0x90,
+            _ => panic!("Unknown base type {}", base_type_name),
+        }
+    }
+
+    /// A field row from the messages table.
+    struct ProfileRow {
+        field_num: u8,
+        name: String,
+        field_type: String,
+        components: Vec<String>,
+        scales: Vec<String>,
+        offsets: Vec<String>,
+        units: Vec<String>,
+        bits: Vec<String>,
+        accumulate: Vec<String>,
+        ref_field_names: Vec<String>, // Subfields only, the fields that select the subfield...
+        ref_field_values: Vec<String>, // ...and the values they must have
+        subfields: Vec<ProfileRow>,
+    }
+
+    /// Generates the Rust code for the components of a field.
+    fn generate_components(row: &ProfileRow, fields: &[ProfileRow]) -> String {
+        let mut code = String::new();
+
+        for (i, component) in row.components.iter().enumerate() {
+            let target = fields.iter().find(|field| field.name == *component).unwrap_or_else(|| panic!("Unknown component {}", component));
+            let scale: f64 = component_value(&row.scales, i, "1").parse().unwrap();
+            let offset: f64 = component_value(&row.offsets, i, "0").parse().unwrap();
+            let bits: u8 = component_value(&row.bits, i, "0").parse().unwrap();
+            let accumulate = component_value(&row.accumulate, i, "0") == "1";
+
+            code.push_str(&format!("ComponentProfile {{ field_num: {}, bits: {}, scale: {:?}, offset: {:?}, accumulate: {} }}, ", target.field_num, bits, scale, offset, accumulate));
+        }
+        code.trim_end_matches(", ").to_string()
+    }
+
+    /// Generates the Rust code for a field, or a subfield, and its components.
+    fn generate_field(field: &ProfileRow, fields: &[ProfileRow], types: &HashMap<String, (String, HashMap<String, u64>)>) -> String {
+        // The field's type is either a base type or one of the types from the types table.
+        let base_type = match types.get(&field.field_type) {
+            Some((base_type_name, _)) => base_type_num(base_type_name),
+            None => base_type_num(&field.field_type),
+        };
+
+        // Scale, offset and units only apply to the field itself when there's at most one component.
+        let (mut scale, mut offset, mut units) = ("1".to_string(), "0".to_string(), String::new());
+        if field.components.len() <= 1 {
+            scale = component_value(&field.scales, 0, "1");
+            offset = component_value(&field.offsets, 0, "0");
+            units = component_value(&field.units, 0, "");
+        }
+        let scale: f64 = scale.parse().unwrap();
+        let offset: f64 = offset.parse().unwrap();
+
+        format!("FieldProfile {{ field_num: {}, name: {:?}, field_type: {:?}, base_type: {:#04x}, scale: {:?}, offset: {:?}, units: {:?}, components: &[{}]",
+            field.field_num, field.name, field.field_type, base_type, scale, offset, units, generate_components(field, fields))
+    }
+
+    /// Generates the Rust code for the fields and values that select a subfield.
+    fn generate_references(subfield: &ProfileRow, fields: &[ProfileRow], types: &HashMap<String, (String, HashMap<String, u64>)>) -> String {
+        let mut code = String::new();
+
+        for (i, value_name) in subfield.ref_field_values.iter().enumerate() {
+            let ref_field_name = component_value(&subfield.ref_field_names, i, "");
+            let ref_field = fields.iter().find(|field| field.name == ref_field_name).unwrap_or_else(|| panic!("Unknown reference field {}", ref_field_name));
+            let (_, values) = types.get(&ref_field.field_type).unwrap_or_else(|| panic!("Unknown type {}", ref_field.field_type));
+            let value = values.get(value_name).unwrap_or_else(|| panic!("Unknown value {} of {}", value_name, ref_field.field_type));
+
+            code.push_str(&format!("SubfieldReference {{ field_num: {}, value: {} }}, ", ref_field.field_num, value));
+        }
+        code.trim_end_matches(", ").to_string()
+    }
+
+    /// Generates the tables at the end of fit_profile.rs from tests/Messages-Table.csv.
+    fn generate_profile() -> String {
+        let types = read_types_table();
+        let (_, mesg_nums) = types.get("mesg_num").unwrap();
+        let mut reader = csv::Reader::from_path("tests/Messages-Table.csv").unwrap();
+        let mut messages = Vec::<(u64, String, Vec<ProfileRow>)>::new();
+        let mut is_known_message = false;
+
+        for record in reader.records() {
+            let record = record.unwrap();
+
+            // First column is the message name.
+            if !record[0].is_empty() {
+                let global_message_num = mesg_nums.get(&record[0]);
+                if let Some(global_message_num) = global_message_num {
+                    messages.push((*global_message_num, record[0].to_string(), Vec::new()));
+                }
+                is_known_message = global_message_num.is_some();
+                continue;
+            }
+
+            // Skip section headings and fields of messages that don't have a number.
+            if record[2].is_empty() || !is_known_message {
+                continue;
+            }
+
+            let fields = &mut messages.last_mut().unwrap().2;
+            let mut row = ProfileRow {
+                field_num: 0,
+                name: record[2].to_string(),
+                field_type: record[3].to_string(),
+                components: split_cell(&record[5]),
+                scales: split_cell(&record[6]),
+                offsets: split_cell(&record[7]),
+                units: split_cell(&record[8]),
+                bits: split_cell(&record[9]),
+                accumulate: split_cell(&record[10]),
+                ref_field_names: split_cell(&record[11]),
+                ref_field_values: split_cell(&record[12]),
+                subfields: Vec::new(),
+            };
+
+            // Subfields don't have a field number, they belong to the field above them.
+            if record[1].is_empty() {
+                let parent = fields.last_mut().unwrap();
+                row.field_num = parent.field_num;
+                parent.subfields.push(row);
+            }
+            else {
+                row.field_num = record[1].parse().unwrap();
+                fields.push(row);
+            }
+        }
+
+        messages.sort_by_key(|(global_message_num, _, _)| *global_message_num);
+
+        let mut code = String::new();
+        code.push_str("pub static MESSAGE_PROFILES: &[MessageProfile] = &[\n");
+        for (global_message_num, name, fields) in messages.iter_mut() {
+            fields.sort_by_key(|field| field.field_num);
+            code.push_str(&format!("    MessageProfile {{ global_message_num: {}, name: {:?}, fields: &[\n", global_message_num, name));
+
+            for field in fields.iter() {
+                code.push_str(&format!("        {}, subfields: &[", generate_field(field, fields, &types)));
+                if field.subfields.is_empty() {
+                    code.push_str("] },\n");
+                    continue;
+                }
+
+                code.push('\n');
+                for subfield in field.subfields.iter() {
+                    code.push_str(&format!("            SubfieldProfile {{ references: &[{}], field: {}, subfields: &[] }} }},\n",
+                        generate_references(subfield, fields, &types), generate_field(subfield, fields, &types)));
+                }
+                code.push_str("        ] },\n");
+            }
+            code.push_str("    ] },\n");
+        }
+        code.push_str("];\n");
+        code
+    }
+
+    /// Makes sure fit_profile.rs matches the profile CSV files. Set FIT_UPDATE_PROFILE to rewrite it.
+    #[test]
+    fn create_profile() {
+        let file_path = "src/fit_profile.rs";
+        let begin_marker = "// BEGIN GENERATED CODE\n";
+        let end_marker = "// END GENERATED CODE\n";
+        let source = std::fs::read_to_string(file_path).unwrap();
+        let begin = source.find(begin_marker).unwrap() + begin_marker.len();
+        let end = source.find(end_marker).unwrap();
+        let generated = generate_profile();
+
+        if std::env::var("FIT_UPDATE_PROFILE").is_ok() {
+            let new_source = format!("{}{}{}", &source[..begin], generated, &source[end..]);
+            std::fs::write(file_path, new_source).unwrap();
+        }
+        else {
+            assert!(source[begin..end] == generated, "{} is out of date, rerun this test with FIT_UPDATE_PROFILE=1", file_path);
+        }
     }
 
     #[test]
     fn create_message_structs() {
         let file_path = "tests/Messages-Table.csv";
-        let file = match std::fs::File::open(&file_path) {
+        let file = match std::fs::File::open(file_path) {
             Err(why) => panic!("Couldn't open {} {}", file_path, why),
             Ok(file) => file,
         };
 
         let mut reader = csv::Reader::from_reader(file);
         let mut current_msg_name = String::new();
-        let mut field_map = HashMap::<String, (u8, String)>::new();
+        let mut field_map = HashMap::<String, (u8, String, bool)>::new();
 
         for record in reader.records() {
             let record = record.unwrap();
 
             // First column is the message name.
             let msg_name: String = record[0].parse().unwrap();
-            if msg_name.len() > 0 {
+            if !msg_name.is_empty() {
 
                 // Print the previous definition, if there is one.
-                if current_msg_name.len() > 0 {
+                if !current_msg_name.is_empty() {
                     print_message_struct(current_msg_name, &field_map);
                 }
 
-                current_msg_name = String::from(msg_name);
+                current_msg_name = msg_name;
                 field_map.clear();
             }
             else {
                 let field_id = &record[1];
 
-                if field_id.len() > 0 {
+                if !field_id.is_empty() {
                     let field_id_num: u8 = field_id.parse::<u8>().unwrap();
                     let field_name: String = record[2].parse().unwrap();
                     let mut field_type_str: String = record[3].parse().unwrap();
 
                     // Normalize the field type string.
-                    if field_type_str == "byte" {
-                        field_type_str = "u8".to_string();
-                    }
-                    else if field_type_str == "uint8" {
-                        field_type_str = "u8".to_string();
-                    }
-                    else if field_type_str == "uint8z" {
-                        field_type_str = "u8".to_string();
-                    }
-                    else if field_type_str == "uint16" {
-                        field_type_str = "u16".to_string();
-                    }
-                    else if field_type_str == "uint16z" {
-                        field_type_str = "u16".to_string();
-                    }
-                    else if field_type_str == "uint32" {
-                        field_type_str = "u32".to_string();
-                    }
-                    else if field_type_str == "uint32z" {
-                        field_type_str = "u32".to_string();
-                    }
-                    else if field_type_str == "sint8" {
-                        field_type_str = "i8".to_string();
-                    }
-                    else if field_type_str == "sint16" {
-                        field_type_str = "i16".to_string();
-                    }
-                    else if field_type_str == "sint32" {
-                        field_type_str = "i32".to_string();
-                    }
-                    else if field_type_str == "float32" {
-                        field_type_str = "f32".to_string();
-                    }
-                    else if field_type_str == "float64" {
-                        field_type_str = "f64".to_string();
-                    }
+                    field_type_str = match field_type_str.as_str() {
+                        "byte" | "uint8" | "uint8z" => "u8".to_string(),
+                        "uint16" | "uint16z" => "u16".to_string(),
+                        "uint32" | "uint32z" => "u32".to_string(),
+                        "sint8" => "i8".to_string(),
+                        "sint16" => "i16".to_string(),
+                        "sint32" => "i32".to_string(),
+                        "float32" => "f32".to_string(),
+                        "float64" => "f64".to_string(),
+                        _ => field_type_str,
+                    };
 
-                    field_map.insert(field_name, (field_id_num, field_type_str));
+                    // Array fields are marked with their size, i.e. [N] or [3].
+                    let is_array = !record[4].is_empty();
+
+                    field_map.insert(field_name, (field_id_num, field_type_str, is_array));
                 }
             }
         }
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod workout_tests {
     use std::{fs::File, io::BufReader};
 
@@ -436,12 +1742,12 @@ mod workout_tests {
         }
     }
 
-    fn callback(_timestamp: u32, global_message_num: u16, _local_msg_type: u8, message_index: u16, fields: Vec<crate::fit_file::FitFieldValue>, data: &mut Workout) {
+    fn callback(_timestamp: Option<crate::fit_file::FitDateTime>, global_message_num: u16, _local_msg_type: u8, message_index: fit_file::MessageIndex, _segment: usize, fields: Vec<crate::fit_file::FitFieldValue>, data: &mut Workout) {
         if global_message_num == fit_file::GLOBAL_MSG_NUM_WORKOUT_STEP {
-            let step = fit_file::FitWorkoutStepMsg::new(message_index, fields);
+            let step = fit_file::FitWorkoutStepMsg::new(message_index, &fields);
             data.steps.push(step);
         } else if global_message_num == fit_file::GLOBAL_MSG_NUM_WORKOUT {
-            let workout = fit_file::FitWorkoutMsg::new(fields);
+            let workout = fit_file::FitWorkoutMsg::new(&fields);
             data.workout_message = Some(workout);
         }
     }
@@ -688,7 +1994,7 @@ mod workout_tests {
                      message_index: 0,
                      step_name: Some("Warm up".into()),
                      duration_type: Some(fit_file::WORKOUT_STEP_DURATION_OPEN),
-                     duration_value: Some(u32::MAX),
+                     duration_value: None,
                      target_type: Some(fit_file::WORKOUT_STEP_TARGET_POWER),
                      target_value: Some(0),
                      custom_target_low: Some(1100), // 100W
@@ -696,10 +2002,10 @@ mod workout_tests {
                      intensity: Some(fit_file::INTENSITY_WARM_UP),
                      notes: None,
                      equipment: None,
-                     secondary_target_type: Some(u8::MAX),
-                     secondary_target_value: Some(u32::MAX),
-                     secondary_custom_target_low: Some(u32::MAX),
-                     secondary_custom_target_high: Some(u32::MAX),
+                     secondary_target_type: None,
+                     secondary_target_value: None,
+                     secondary_custom_target_low: None,
+                     secondary_custom_target_high: None,
                  },
                  FitWorkoutStepMsg {
                      message_index: 1,
@@ -730,27 +2036,27 @@ mod workout_tests {
                      intensity: Some(fit_file::INTENSITY_REST),
                      notes: None,
                      equipment: None,
-                     secondary_target_type: Some(u8::MAX),
-                     secondary_target_value: Some(u32::MAX),
-                     secondary_custom_target_low: Some(u32::MAX),
-                     secondary_custom_target_high: Some(u32::MAX),
+                     secondary_target_type: None,
+                     secondary_target_value: None,
+                     secondary_custom_target_low: None,
+                     secondary_custom_target_high: None,
                  },
                  FitWorkoutStepMsg {
                      message_index: 3,
-                     step_name: Some("".into()),
+                     step_name: None,
                      duration_type: Some(fit_file::WORKOUT_STEP_DURATION_REPEAT_UNTIL_STEPS_COMPLETE),
                      duration_value: Some(1), // step with message index 1
-                     target_type: Some(u8::MAX),
+                     target_type: None,
                      target_value: Some(4), // 4 repetitions
-                     custom_target_low: Some(u32::MAX),
-                     custom_target_high: Some(u32::MAX),
-                     intensity: Some(u8::MAX),
+                     custom_target_low: None,
+                     custom_target_high: None,
+                     intensity: None,
                      notes: None,
                      equipment: None,
-                     secondary_target_type: Some(u8::MAX),
-                     secondary_target_value: Some(u32::MAX),
-                     secondary_custom_target_low: Some(u32::MAX),
-                     secondary_custom_target_high: Some(u32::MAX),
+                     secondary_target_type: None,
+                     secondary_target_value: None,
+                     secondary_custom_target_low: None,
+                     secondary_custom_target_high: None,
                  },
                  FitWorkoutStepMsg {
                      message_index: 4,
@@ -764,27 +2070,27 @@ mod workout_tests {
                      intensity: Some(fit_file::INTENSITY_COOL_DOWN),
                      notes: None,
                      equipment: None,
-                     secondary_target_type: Some(u8::MAX),
-                     secondary_target_value: Some(u32::MAX),
-                     secondary_custom_target_low: Some(u32::MAX),
-                     secondary_custom_target_high: Some(u32::MAX),
+                     secondary_target_type: None,
+                     secondary_target_value: None,
+                     secondary_custom_target_low: None,
+                     secondary_custom_target_high: None,
                  },
                  FitWorkoutStepMsg {
                      message_index: 5,
-                     step_name: Some("".into()),
+                     step_name: None,
                      duration_type: Some(fit_file::WORKOUT_STEP_DURATION_OPEN),
-                     duration_value: Some(u32::MAX),
-                     target_type: Some(u8::MAX),
-                     target_value: Some(u32::MAX),
-                     custom_target_low: Some(u32::MAX),
-                     custom_target_high: Some(u32::MAX),
+                     duration_value: None,
+                     target_type: None,
+                     target_value: None,
+                     custom_target_low: None,
+                     custom_target_high: None,
                      intensity: Some(fit_file::INTENSITY_COOL_DOWN),
                      notes: None,
                      equipment: None,
-                     secondary_target_type: Some(u8::MAX),
-                     secondary_target_value: Some(u32::MAX),
-                     secondary_custom_target_low: Some(u32::MAX),
-                     secondary_custom_target_high: Some(u32::MAX),
+                     secondary_target_type: None,
+                     secondary_target_value: None,
+                     secondary_custom_target_low: None,
+                     secondary_custom_target_high: None,
                  },
              ],
         };
@@ -796,4 +2102,31 @@ mod workout_tests {
         }
         assert_eq!(wko.steps.len(), expected.steps.len());
     }
+    #[test]
+    fn it_resolves_workout_step_subfields() {
+        let mut wko = Workout::new();
+        let file = File::open("tests/WorkoutRepeatSteps.fit").unwrap();
+        let mut reader = BufReader::new(file);
+        fit_file::read(&mut reader, callback, &mut wko).unwrap();
+
+        assert_eq!(wko.steps[0].duration_time_s(), Some(60.0));
+        assert_eq!(wko.steps[0].duration_distance_m(), None);
+        assert_eq!(wko.steps[0].target_hr_zone(), Some(2));
+        assert_eq!(wko.steps[0].target_power_zone(), None);
+        assert_eq!(wko.steps[1].duration_distance_m(), Some(500.0));
+        assert_eq!(wko.steps[1].target_power_zone(), Some(5));
+        assert_eq!(wko.steps[3].duration_step(), Some(1));
+        assert_eq!(wko.steps[3].repeat_steps(), Some(3));
+        assert_eq!(wko.steps[3].target_hr_zone(), None);
+        assert_eq!(wko.steps[4].duration_hr(), Some(225));
+
+        let mut wko = Workout::new();
+        let file = File::open("tests/WorkoutCustomTargetValues.fit").unwrap();
+        let mut reader = BufReader::new(file);
+        fit_file::read(&mut reader, callback, &mut wko).unwrap();
+
+        assert_eq!(wko.steps[0].custom_target_heart_rate(), (Some(50), Some(60)));
+        assert_eq!(wko.steps[0].custom_target_power(), (None, None));
+        assert_eq!(wko.steps[1].custom_target_power(), (Some(1300), Some(1310)));
+    }
 }